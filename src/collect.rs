@@ -123,8 +123,17 @@ macro_rules! collect_hex8_iter {
 
 /// Collect a quoted header field value.
 ///
-/// Exit the collection loop upon finding an invalid byte.
+/// Exit the collection loop upon finding an invalid byte, or when `$stop` is `true`.
 macro_rules! collect_quoted_field {
+    ($context:expr, $stop:expr, $on_eos:expr) => ({
+        bs_collect!($context,
+            if !is_quoted_header_field($context.byte) || $stop {
+                break;
+            },
+            $on_eos
+        );
+    });
+
     ($context:expr, $on_eos:expr) => ({
         bs_collect!($context,
             if !is_quoted_header_field($context.byte) {
@@ -0,0 +1,98 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+//! Stream excerpts for error diagnostics.
+
+use std::fmt;
+
+/// Amount of bytes preceding, and following, the offending byte within an excerpt.
+const EXCERPT_RADIUS: usize = 16;
+
+/// Copy the bytes surrounding `index` within `stream`.
+///
+/// Returns the excerpt, along with the index of the offending byte within it.
+pub fn excerpt(stream: &[u8], index: usize) -> (Vec<u8>, usize) {
+    let index = if index > stream.len() { stream.len() } else { index };
+    let start = index.saturating_sub(EXCERPT_RADIUS);
+    let end   = if stream.len() - index > EXCERPT_RADIUS {
+        index + EXCERPT_RADIUS + 1
+    } else {
+        stream.len()
+    };
+
+    (stream[start..end].to_vec(), index - start)
+}
+
+/// Write `byte` in a printable form, and retrieve the amount of characters written.
+fn write_byte(formatter: &mut fmt::Formatter, byte: u8) -> Result<usize, fmt::Error> {
+    match byte {
+        b'\\' => {
+            formatter.write_str("\\\\")?;
+
+            Ok(2)
+        },
+        b'\r' => {
+            formatter.write_str("\\r")?;
+
+            Ok(2)
+        },
+        b'\n' => {
+            formatter.write_str("\\n")?;
+
+            Ok(2)
+        },
+        b'\t' => {
+            formatter.write_str("\\t")?;
+
+            Ok(2)
+        },
+        0x20..=0x7E => {
+            write!(formatter, "{}", byte as char)?;
+
+            Ok(1)
+        },
+        _ => {
+            write!(formatter, "\\x{:02X}", byte)?;
+
+            Ok(4)
+        }
+    }
+}
+
+/// Write `excerpt` on a line of its own, followed by a line with a caret beneath the byte at
+/// `index`.
+///
+/// Nothing is written when `excerpt` is empty.
+pub fn write_excerpt(formatter: &mut fmt::Formatter, excerpt: &[u8], index: usize)
+-> fmt::Result {
+    if excerpt.is_empty() {
+        return Ok(());
+    }
+
+    let mut column = 0;
+
+    formatter.write_str("\n")?;
+
+    for (n, byte) in excerpt.iter().enumerate() {
+        let width = write_byte(formatter, *byte)?;
+
+        if n < index {
+            column += width;
+        }
+    }
+
+    write!(formatter, "\n{:width$}^", "", width = column)
+}
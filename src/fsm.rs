@@ -18,11 +18,41 @@
 
 use std::fmt;
 
-/// Execute callback `$callback`. If it returns `true`, execute `$exec`. Otherwise exit with
-/// `Success::Callback`.
+/// Supply the handler with the absolute offset of the data, prior to a callback.
+///
+/// Data begins at the mark index of the stream that begins at `$parser.stream_offset`, unless
+/// `$offset` is specified.
+macro_rules! callback_offset {
+    ($parser:expr, $handler:expr, $context:expr) => ({
+        $handler.set_offset($parser.stream_offset + $context.mark_index);
+    });
+
+    ($parser:expr, $handler:expr, $context:expr, $offset:expr) => ({
+        $handler.set_offset($offset);
+    });
+}
+
+/// Execute callback `$callback`. If it returns `Action::Continue`, execute `$exec`. Otherwise exit
+/// with `Success::Callback`, or with `ErrorKind::Handler` when parsing has been aborted.
+///
+/// The offset of the data is supplied by `callback_offset!()` prior to the callback. `$offset` is
+/// the offset of data that does not begin at the mark index.
 macro_rules! callback {
+    ($parser:expr, $handler:expr, $context:expr, $callback:ident, $data:expr, $offset:expr,
+     $exec:expr) => ({
+        callback_offset!($parser, $handler, $context, $offset);
+
+        if proceed!($handler.$callback($data)) {
+            $exec
+        }
+
+        exit_callback!($parser, $context);
+    });
+
     ($parser:expr, $handler:expr, $context:expr, $callback:ident, $data:expr, $exec:expr) => ({
-        if $handler.$callback($data) {
+        callback_offset!($parser, $handler, $context);
+
+        if proceed!($handler.$callback($data)) {
             $exec
         }
 
@@ -31,7 +61,9 @@ macro_rules! callback {
 
     ($parser:expr, $handler:expr, $context:expr, $callback:ident, $exec:expr) => ({
         if bs_slice_length!($context) > 0 {
-            if $handler.$callback(bs_slice!($context)) {
+            callback_offset!($parser, $handler, $context);
+
+            if proceed!($handler.$callback(bs_slice!($context))) {
                 $exec
             }
 
@@ -51,8 +83,9 @@ macro_rules! callback_eos_expr {
     });
 }
 
-/// Execute callback `$callback` ignoring the last collected byte. If it returns `true`, transition
-/// to `$state`. Otherwise exit with `Success::Callback`.
+/// Execute callback `$callback` ignoring the last collected byte. If it returns
+/// `Action::Continue`, transition to `$state`. Otherwise exit with `Success::Callback`, or with
+/// `ErrorKind::Handler` when parsing has been aborted.
 macro_rules! callback_ignore_transition {
     ($parser:expr, $handler:expr, $context:expr, $callback:ident, $state:ident,
      $state_function:ident) => ({
@@ -60,7 +93,9 @@ macro_rules! callback_ignore_transition {
 
         // compare against 1 instead of 0 because we're ignoring the last slice byte
         if bs_slice_length!($context) > 1 {
-            if $handler.$callback(bs_slice_ignore!($context)) {
+            callback_offset!($parser, $handler, $context);
+
+            if proceed!($handler.$callback(bs_slice_ignore!($context))) {
                 transition!($parser, $context);
             }
 
@@ -71,12 +106,21 @@ macro_rules! callback_ignore_transition {
     });
 }
 
-/// Execute callback `$callback`. If it returns `true`, transition to `$state`. Otherwise exit
-/// with `Success::Callback`.
+/// Execute callback `$callback`. If it returns `Action::Continue`, transition to `$state`.
+/// Otherwise exit with `Success::Callback`, or with `ErrorKind::Handler` when parsing has been
+/// aborted.
 ///
 /// This macro exists to enforce the design decision that after each callback, state must either
 /// change, or the parser must exit with `Success::Callback`.
 macro_rules! callback_transition {
+    ($parser:expr, $handler:expr, $context:expr, $callback:ident, $data:expr, $offset:expr,
+     $state:ident, $state_function:ident) => ({
+        set_state!($parser, $state, $state_function);
+        callback!($parser, $handler, $context, $callback, $data, $offset, {
+            transition!($parser, $context);
+        });
+    });
+
     ($parser:expr, $handler:expr, $context:expr, $callback:ident, $data:expr, $state:ident,
      $state_function:ident) => ({
         set_state!($parser, $state, $state_function);
@@ -114,14 +158,14 @@ macro_rules! exit_eos {
     });
 }
 
-/// Exit parser with `ParserError`.
+/// Exit parser with `ErrorKind`.
 macro_rules! exit_error {
     ($error:ident, $byte:expr) => ({
-        return Err(ParserError::$error($byte));
+        return Err(ErrorKind::$error($byte));
     });
 
     ($error:ident) => ({
-        return Err(ParserError::$error);
+        return Err(ErrorKind::$error);
     });
 }
 
@@ -132,6 +176,13 @@ macro_rules! exit_finished {
     });
 }
 
+/// Exit parser with `Success::Upgrade`.
+macro_rules! exit_upgrade {
+    ($parser:expr, $context:expr) => ({
+        return Ok(ParserValue::Exit(Success::Upgrade($context.stream_index)));
+    });
+}
+
 /// If the stream is EOS, exit with `Success::Eos`. Otherwise do nothing.
 macro_rules! exit_if_eos {
     ($parser:expr, $context:expr) => ({
@@ -139,6 +190,18 @@ macro_rules! exit_if_eos {
     });
 }
 
+/// Evaluate the callback action `$action`, and indicate that parsing should continue. If parsing
+/// has been aborted, exit with `ErrorKind::Handler`.
+macro_rules! proceed {
+    ($action:expr) => ({
+        match $action {
+            Action::Abort(error) => return Err(ErrorKind::Handler(error)),
+            Action::Continue     => true,
+            Action::Pause        => false
+        }
+    });
+}
+
 /// Retrieve the state.
 macro_rules! get_state {
     ($parser:expr) => ({
@@ -184,6 +247,39 @@ macro_rules! transition_no_remark {
 
 // -------------------------------------------------------------------------------------------------
 
+/// Callback return values.
+///
+/// `true` and `false` convert into `Action::Continue` and `Action::Pause`, which allows callbacks
+/// to return `condition.into()`.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Action<E = ()> {
+    /// Stop parsing. The parser function exits with an `ErrorKind::Handler` error, and the parser
+    /// is dead.
+    ///
+    /// # Arguments
+    ///
+    /// **(1)**: The handler error.
+    Abort(E),
+
+    /// Continue parsing.
+    Continue,
+
+    /// Exit the parser function prematurely with `Success::Callback`. Parsing can be resumed.
+    Pause
+}
+
+impl<E> From<bool> for Action<E> {
+    fn from(proceed: bool) -> Action<E> {
+        if proceed {
+            Action::Continue
+        } else {
+            Action::Pause
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 /// Parsing function return values.
 pub enum ParserValue {
     /// Continue the parser loop.
@@ -198,7 +294,7 @@ pub enum ParserValue {
 /// Parsing function success return values.
 #[derive(Clone,Copy,PartialEq)]
 pub enum Success {
-    /// A callback returned `false` and the parser function exited prematurely. This can be
+    /// A callback returned `Action::Pause` and the parser function exited prematurely. This can be
     /// treated the same as `Success::Finished`.
     ///
     /// # Arguments
@@ -229,7 +325,17 @@ pub enum Success {
     ///          [Parser::parse_chunked()](../http1/struct.Parser.html#method.parse_chunked),
     ///          [Parser::parse_multipart()](../http1/struct.Parser.html#method.parse_multipart), or
     ///          [Parser::parse_url_encoded()](../http1/struct.Parser.html#method.parse_url_encoded).
-    Finished(usize)
+    Finished(usize),
+
+    /// The head has been parsed, and the connection switches to another protocol, either by way of
+    /// the `Upgrade` header, or a `CONNECT` request. Data following the head belongs to the other
+    /// protocol, and must not be handed to the parser.
+    ///
+    /// # Arguments
+    ///
+    /// **(1)**: The amount of `stream` bytes that were processed. This is the offset within
+    ///          `stream` where the data of the other protocol begins.
+    Upgrade(usize)
 }
 
 impl fmt::Debug for Success {
@@ -243,6 +349,9 @@ impl fmt::Debug for Success {
             },
             Success::Finished(length) => {
                 write!(formatter, "Success::Finished({})", length)
+            },
+            Success::Upgrade(length) => {
+                write!(formatter, "Success::Upgrade({})", length)
             }
         }
     }
@@ -259,6 +368,9 @@ impl fmt::Display for Success {
             },
             Success::Finished(length) => {
                 write!(formatter, "{}", length)
+            },
+            Success::Upgrade(length) => {
+                write!(formatter, "{}", length)
             }
         }
     }
@@ -0,0 +1,251 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+//! HTTP 1.x chunked transfer-encoding.
+
+use byte::{ is_quoted_header_field, is_token };
+use http1::framing::is_forbidden_trailer;
+use http1::writer::{ WriteError, check_header };
+
+use std::io::Write;
+
+/// Verify that `name` is a valid chunk extension name, and that `value` can be written as a token
+/// or as a quoted string.
+fn check_extension(name: &[u8], value: &[u8]) -> Result<(), WriteError> {
+    if name.is_empty() {
+        return Err(WriteError::EmptyChunkExtensionName);
+    }
+
+    if let Some(byte) = name.iter().find(|byte| !is_token(**byte)) {
+        return Err(WriteError::ChunkExtensionName(*byte));
+    }
+
+    if let Some(byte) = value.iter().find(|byte| {
+        !is_quoted_header_field(**byte) && **byte != b'"' && **byte != b'\\'
+    }) {
+        return Err(WriteError::ChunkExtensionValue(*byte));
+    }
+
+    Ok(())
+}
+
+/// HTTP 1.x chunked transfer-encoding encoder.
+///
+/// Wraps any `std::io::Write` implementation, and writes each chunk of data as a chunk size in
+/// hex, optional chunk extensions, and the data itself. Once all data has been written,
+/// `ChunkedEncoder::finish()` writes the last chunk, along with optional trailers. The encoded
+/// body can be parsed by a `Parser` that has been initialized with `Parser::init_chunked()`.
+///
+/// Chunk extension names must be tokens. Values that are tokens are written as-is, and other
+/// values are written as a quoted string, with `"` and `\` escaped. Nothing is written when
+/// verification fails.
+///
+/// # Examples
+///
+/// ```
+/// use http_box::http1::ChunkedEncoder;
+///
+/// let mut encoder = ChunkedEncoder::new(Vec::new());
+///
+/// encoder.chunk(b"Hello").unwrap();
+/// encoder.chunk_with_extensions(b", world", &[(b"name", b"a value")]).unwrap();
+/// encoder.finish(&[(b"Expires", b"never")]).unwrap();
+///
+/// assert_eq!(
+///     encoder.into_inner(),
+///     &b"5\r\nHello\r\n\
+///        7;name=\"a value\"\r\n, world\r\n\
+///        0\r\nExpires: never\r\n\r\n"[..]
+/// );
+/// ```
+pub struct ChunkedEncoder<W: Write> {
+    /// Indicates that the last chunk has been written.
+    finished: bool,
+
+    /// Underlying writer.
+    writer: W
+}
+
+impl<W: Write> ChunkedEncoder<W> {
+    /// Create a new `ChunkedEncoder`.
+    ///
+    /// # Arguments
+    ///
+    /// **`writer`**
+    ///
+    /// The underlying writer.
+    pub fn new(writer: W) -> ChunkedEncoder<W> {
+        ChunkedEncoder{
+            finished: false,
+            writer
+        }
+    }
+
+    /// Write a chunk.
+    ///
+    /// Nothing is written when `data` is empty, since a chunk size of zero indicates the last
+    /// chunk.
+    ///
+    /// # Arguments
+    ///
+    /// **`data`**
+    ///
+    /// The chunk data.
+    pub fn chunk(&mut self, data: &[u8]) -> Result<(), WriteError> {
+        self.chunk_with_extensions::<&[u8], &[u8]>(data, &[])
+    }
+
+    /// Write a chunk along with chunk extensions.
+    ///
+    /// Nothing is written when `data` is empty, since a chunk size of zero indicates the last
+    /// chunk.
+    ///
+    /// # Arguments
+    ///
+    /// **`data`**
+    ///
+    /// The chunk data.
+    ///
+    /// **`extensions`**
+    ///
+    /// The chunk extension name and value pairs. Names must be tokens, and values must not contain
+    /// control characters other than horizontal tab. An extension with an empty value is written
+    /// without a value.
+    pub fn chunk_with_extensions<N, V>(&mut self, data: &[u8], extensions: &[(N, V)])
+    -> Result<(), WriteError>
+    where N: AsRef<[u8]>, V: AsRef<[u8]> {
+        if self.finished {
+            return Err(WriteError::OutOfOrder);
+        }
+
+        for (name, value) in extensions {
+            check_extension(name.as_ref(), value.as_ref())?;
+        }
+
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        write!(self.writer, "{:X}", data.len())?;
+
+        for (name, value) in extensions {
+            self.write_extension(name.as_ref(), value.as_ref())?;
+        }
+
+        self.writer.write_all(b"\r\n")?;
+        self.writer.write_all(data)?;
+        self.writer.write_all(b"\r\n")?;
+
+        Ok(())
+    }
+
+    /// Write the last chunk, along with trailers.
+    ///
+    /// Nothing can be written once this has been called.
+    ///
+    /// # Arguments
+    ///
+    /// **`trailers`**
+    ///
+    /// The trailer name and value pairs, which are verified the same way as headers. Fields that
+    /// are needed before the body is processed, such as `Content-Length`, must not be sent as
+    /// trailers.
+    pub fn finish<N, V>(&mut self, trailers: &[(N, V)]) -> Result<(), WriteError>
+    where N: AsRef<[u8]>, V: AsRef<[u8]> {
+        if self.finished {
+            return Err(WriteError::OutOfOrder);
+        }
+
+        for (name, value) in trailers {
+            check_header(name.as_ref(), value.as_ref())?;
+
+            if is_forbidden_trailer(name.as_ref()) {
+                return Err(WriteError::ForbiddenTrailer);
+            }
+        }
+
+        self.writer.write_all(b"0\r\n")?;
+
+        for (name, value) in trailers {
+            self.writer.write_all(name.as_ref())?;
+            self.writer.write_all(b": ")?;
+            self.writer.write_all(value.as_ref())?;
+            self.writer.write_all(b"\r\n")?;
+        }
+
+        self.writer.write_all(b"\r\n")?;
+
+        self.finished = true;
+
+        Ok(())
+    }
+
+    /// Retrieve a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Retrieve a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consume this, and retrieve the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Indicates that the last chunk has been written.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Write a verified chunk extension.
+    fn write_extension(&mut self, name: &[u8], value: &[u8]) -> Result<(), WriteError> {
+        self.writer.write_all(b";")?;
+        self.writer.write_all(name)?;
+
+        if value.is_empty() {
+            return Ok(());
+        }
+
+        self.writer.write_all(b"=")?;
+
+        if value.iter().all(|byte| is_token(*byte)) {
+            self.writer.write_all(value)?;
+
+            return Ok(());
+        }
+
+        self.writer.write_all(b"\"")?;
+
+        let mut start = 0;
+
+        for (index, byte) in value.iter().enumerate() {
+            if *byte == b'"' || *byte == b'\\' {
+                self.writer.write_all(&value[start..index])?;
+                self.writer.write_all(&[b'\\', *byte])?;
+
+                start = index + 1;
+            }
+        }
+
+        self.writer.write_all(&value[start..])?;
+        self.writer.write_all(b"\"")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,736 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+//! HTTP 1.x message collection.
+
+use fsm::Action;
+use http1::http_handler::HttpHandler;
+
+use std::collections::VecDeque;
+use std::{ error, fmt, mem };
+use std::slice::Iter;
+
+/// Field that is currently being collected.
+#[derive(Clone,Copy,PartialEq)]
+enum Field {
+    /// Header name.
+    HeaderName,
+
+    /// Header value.
+    HeaderValue,
+
+    /// No field.
+    None,
+
+    /// Trailer name.
+    TrailerName,
+
+    /// Trailer value.
+    TrailerValue
+}
+
+/// Message that has been collected.
+#[derive(Clone,Debug,Default,PartialEq)]
+struct Message {
+    /// Body data.
+    body: Vec<u8>,
+
+    /// Collected body length, including multipart data and URL encoded fields.
+    body_length: usize,
+
+    /// URL encoded fields.
+    fields: Vec<(Vec<u8>, Vec<u8>)>,
+
+    /// Headers.
+    headers: HeaderMap,
+
+    /// Request method.
+    method: Vec<u8>,
+
+    /// Multipart parts.
+    parts: Vec<Part>,
+
+    /// Response status.
+    status: Vec<u8>,
+
+    /// Response status code.
+    status_code: Option<u16>,
+
+    /// Trailers.
+    trailers: HeaderMap,
+
+    /// Request URL.
+    url: Vec<u8>,
+
+    /// HTTP major and minor version.
+    version: (u16, u16)
+}
+
+impl Message {
+    /// Indicates that this is a request.
+    fn is_request(&self) -> bool {
+        !self.method.is_empty()
+    }
+
+    /// Indicates that this is a response.
+    fn is_response(&self) -> bool {
+        self.status_code.is_some()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Collector errors.
+///
+/// `Collector` aborts parsing with one of these, wrapped in `ErrorKind::Handler`, once a limit has
+/// been exceeded.
+#[derive(Clone,Copy,PartialEq)]
+pub enum CollectorError {
+    /// Maximum body length exceeded.
+    BodyTooLong,
+
+    /// Maximum URL encoded field count exceeded.
+    TooManyFields,
+
+    /// Maximum multipart part count exceeded.
+    TooManyParts
+}
+
+impl CollectorError {
+    /// Format this for debug and display purposes.
+    fn format(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CollectorError::BodyTooLong => {
+                write!(
+                    formatter,
+                    "<CollectorError::BodyTooLong: Maximum body length exceeded>"
+                )
+            },
+            CollectorError::TooManyFields => {
+                write!(
+                    formatter,
+                    "<CollectorError::TooManyFields: Maximum URL encoded field count exceeded>"
+                )
+            },
+            CollectorError::TooManyParts => {
+                write!(
+                    formatter,
+                    "<CollectorError::TooManyParts: Maximum multipart part count exceeded>"
+                )
+            }
+        }
+    }
+}
+
+impl fmt::Debug for CollectorError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.format(formatter)
+    }
+}
+
+impl fmt::Display for CollectorError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.format(formatter)
+    }
+}
+
+impl error::Error for CollectorError {
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Collector limits.
+///
+/// Each limit defaults to `usize::MAX`, which means no limit is enforced. Update the limits that
+/// apply, and pass them to `Collector::set_limits()`. Limits that apply to the head, such as the
+/// header count and the URL length, are enforced by `Parser::set_limits()`.
+///
+/// # Examples
+///
+/// ```
+/// use http_box::http1::CollectorLimits;
+///
+/// let limits = CollectorLimits{
+///     max_body_length: 1048576,
+///     ..CollectorLimits::default()
+/// };
+///
+/// assert_eq!(limits.max_body_length, 1048576);
+/// ```
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct CollectorLimits {
+    /// Maximum length of the collected body of a single message, in bytes.
+    ///
+    /// This includes body data, chunk data, multipart data, and URL encoded names and values.
+    pub max_body_length: usize,
+
+    /// Maximum URL encoded field count of a single message.
+    pub max_fields: usize,
+
+    /// Maximum multipart part count of a single message.
+    pub max_parts: usize
+}
+
+impl Default for CollectorLimits {
+    fn default() -> CollectorLimits {
+        CollectorLimits{
+            max_body_length: usize::MAX,
+            max_fields:      usize::MAX,
+            max_parts:       usize::MAX
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Multi-valued header collection.
+///
+/// Headers are kept in the order they were received. Names are compared case-insensitively, and
+/// headers that share a name each keep their own value.
+#[derive(Clone,Debug,Default,PartialEq)]
+pub struct HeaderMap {
+    /// Header name and value pairs.
+    headers: Vec<(Vec<u8>, Vec<u8>)>
+}
+
+impl HeaderMap {
+    /// Create a new `HeaderMap`.
+    pub fn new() -> HeaderMap {
+        HeaderMap{
+            headers: Vec::new()
+        }
+    }
+
+    /// Append a header.
+    pub fn append(&mut self, name: &[u8], value: &[u8]) {
+        self.headers.push((name.to_vec(), value.to_vec()));
+    }
+
+    /// Indicates that a header `name` exists.
+    pub fn contains(&self, name: &[u8]) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Retrieve the first value of the header `name`.
+    pub fn get(&self, name: &[u8]) -> Option<&[u8]> {
+        self.headers.iter()
+                    .find(|header| header.0.eq_ignore_ascii_case(name))
+                    .map(|header| &header.1[..])
+    }
+
+    /// Retrieve all values of the header `name`, in the order they were received.
+    pub fn get_all(&self, name: &[u8]) -> Vec<&[u8]> {
+        self.headers.iter()
+                    .filter(|header| header.0.eq_ignore_ascii_case(name))
+                    .map(|header| &header.1[..])
+                    .collect()
+    }
+
+    /// Indicates that there are no headers.
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+
+    /// Retrieve an iterator over the header name and value pairs.
+    pub fn iter(&self) -> Iter<'_, (Vec<u8>, Vec<u8>)> {
+        self.headers.iter()
+    }
+
+    /// Retrieve the header count.
+    pub fn len(&self) -> usize {
+        self.headers.len()
+    }
+
+    /// Collect a header name segment.
+    fn name(&mut self, name: &[u8], first: bool) {
+        if first {
+            self.headers.push((Vec::new(), Vec::new()));
+        }
+
+        if let Some(header) = self.headers.last_mut() {
+            header.0.extend_from_slice(name);
+        }
+    }
+
+    /// Collect a header value segment.
+    fn value(&mut self, value: &[u8]) {
+        if let Some(header) = self.headers.last_mut() {
+            header.1.extend_from_slice(value);
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Multipart part.
+#[derive(Clone,Debug,Default,PartialEq)]
+pub struct Part {
+    /// Part data.
+    data: Vec<u8>,
+
+    /// Part headers.
+    headers: HeaderMap
+}
+
+impl Part {
+    /// Retrieve the part data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Retrieve the part headers.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Body that has been collected by `Collector` without a head, such as during chunked
+/// transfer-encoded, multipart, and URL encoded parsing.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Body {
+    /// Collected message.
+    message: Message
+}
+
+impl Body {
+    /// Retrieve the body data.
+    ///
+    /// This contains content length, chunked transfer-encoded, and end of input delimited body
+    /// data. Multipart and URL encoded bodies are available from `parts()` and `fields()`.
+    pub fn data(&self) -> &[u8] {
+        &self.message.body
+    }
+
+    /// Retrieve the decoded URL encoded fields.
+    pub fn fields(&self) -> &[(Vec<u8>, Vec<u8>)] {
+        &self.message.fields
+    }
+
+    /// Retrieve the multipart parts.
+    pub fn parts(&self) -> &[Part] {
+        &self.message.parts
+    }
+
+    /// Retrieve the trailers.
+    pub fn trailers(&self) -> &HeaderMap {
+        &self.message.trailers
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Request that has been collected by `Collector`.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Request {
+    /// Collected message.
+    message: Message
+}
+
+impl Request {
+    /// Retrieve the body data.
+    ///
+    /// This contains content length, chunked transfer-encoded, and end of input delimited body
+    /// data. Multipart and URL encoded bodies are available from `parts()` and `fields()`.
+    pub fn body(&self) -> &[u8] {
+        &self.message.body
+    }
+
+    /// Retrieve the decoded URL encoded fields.
+    pub fn fields(&self) -> &[(Vec<u8>, Vec<u8>)] {
+        &self.message.fields
+    }
+
+    /// Retrieve the headers.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.message.headers
+    }
+
+    /// Retrieve the request method.
+    pub fn method(&self) -> &[u8] {
+        &self.message.method
+    }
+
+    /// Retrieve the multipart parts.
+    pub fn parts(&self) -> &[Part] {
+        &self.message.parts
+    }
+
+    /// Retrieve the trailers.
+    pub fn trailers(&self) -> &HeaderMap {
+        &self.message.trailers
+    }
+
+    /// Retrieve the request URL.
+    pub fn url(&self) -> &[u8] {
+        &self.message.url
+    }
+
+    /// Retrieve the HTTP major and minor version.
+    pub fn version(&self) -> (u16, u16) {
+        self.message.version
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Response that has been collected by `Collector`.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Response {
+    /// Collected message.
+    message: Message
+}
+
+impl Response {
+    /// Retrieve the body data.
+    ///
+    /// This contains content length, chunked transfer-encoded, and end of input delimited body
+    /// data. Multipart and URL encoded bodies are available from `parts()` and `fields()`.
+    pub fn body(&self) -> &[u8] {
+        &self.message.body
+    }
+
+    /// Retrieve the decoded URL encoded fields.
+    pub fn fields(&self) -> &[(Vec<u8>, Vec<u8>)] {
+        &self.message.fields
+    }
+
+    /// Retrieve the headers.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.message.headers
+    }
+
+    /// Retrieve the multipart parts.
+    pub fn parts(&self) -> &[Part] {
+        &self.message.parts
+    }
+
+    /// Retrieve the response status.
+    pub fn status(&self) -> &[u8] {
+        &self.message.status
+    }
+
+    /// Retrieve the response status code.
+    pub fn status_code(&self) -> u16 {
+        self.message.status_code.unwrap_or(0)
+    }
+
+    /// Retrieve the trailers.
+    pub fn trailers(&self) -> &HeaderMap {
+        &self.message.trailers
+    }
+
+    /// Retrieve the HTTP major and minor version.
+    pub fn version(&self) -> (u16, u16) {
+        self.message.version
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Handler that collects messages into owned `Request`, `Response`, and `Body` types.
+///
+/// `Collector` works with every `Parser` mode. During message parsing, each message is finished
+/// once `HttpHandler::on_message_finished()` or `HttpHandler::on_upgrade()` has been executed,
+/// which allows pipelined messages to be collected from a persistent connection. Other modes,
+/// such as head, chunked transfer-encoded, multipart, and URL encoded parsing, have no end of
+/// message, so `finish()` must be called once parsing has finished. Bodies that are collected
+/// without a head are retrieved with `take_body()`.
+///
+/// When a limit is exceeded, parsing stops with `ErrorKind::Handler` and a `CollectorError`.
+///
+/// # Examples
+///
+/// ```
+/// use http_box::http1::{ Collector, Parser };
+///
+/// let mut collector = Collector::new();
+/// let mut parser    = Parser::new();
+///
+/// parser.init_message();
+///
+/// parser.resume(
+///     &mut collector,
+///     b"POST /form HTTP/1.1\r\n\
+///       Content-Type: application/x-www-form-urlencoded\r\n\
+///       Content-Length: 7\r\n\
+///       \r\n\
+///       a=1&b=2"
+/// ).unwrap();
+///
+/// let request = collector.take_request().unwrap();
+///
+/// assert_eq!(request.method(), b"POST");
+/// assert_eq!(request.headers().get(b"content-length"), Some(&b"7"[..]));
+/// assert_eq!(request.fields()[1], (b"b".to_vec(), b"2".to_vec()));
+/// ```
+pub struct Collector {
+    /// Current field.
+    field: Field,
+
+    /// Collector limits.
+    limits: CollectorLimits,
+
+    /// Message being collected.
+    message: Message,
+
+    /// Finished messages.
+    messages: VecDeque<Message>
+}
+
+impl Collector {
+    /// Create a new `Collector`.
+    pub fn new() -> Collector {
+        Collector{
+            field:    Field::None,
+            limits:   CollectorLimits::default(),
+            message:  Message::default(),
+            messages: VecDeque::new()
+        }
+    }
+
+    /// Collect `length` bytes of body data.
+    ///
+    /// Returns `false` when the maximum body length would be exceeded.
+    fn body_length(&mut self, length: usize) -> bool {
+        if self.limits.max_body_length - self.message.body_length < length {
+            return false;
+        }
+
+        self.message.body_length += length;
+        true
+    }
+
+    /// Finish the message being collected.
+    ///
+    /// This must be called once parsing has finished in modes that have no end of message, such as
+    /// head, chunked transfer-encoded, multipart, and URL encoded parsing.
+    pub fn finish(&mut self) {
+        let message = mem::take(&mut self.message);
+
+        self.field = Field::None;
+        self.messages.push_back(message);
+    }
+
+    /// Retrieve the headers that are currently being collected.
+    fn headers(&mut self) -> &mut HeaderMap {
+        match self.message.parts.last_mut() {
+            Some(part) => &mut part.headers,
+            None       => &mut self.message.headers
+        }
+    }
+
+    /// Reset `Collector` to its initial state.
+    ///
+    /// The limits are retained, and finished messages that have not been taken are dropped.
+    pub fn reset(&mut self) {
+        self.field   = Field::None;
+        self.message = Message::default();
+
+        self.messages.clear();
+    }
+
+    /// Set the limits.
+    pub fn set_limits(&mut self, limits: CollectorLimits) {
+        self.limits = limits;
+    }
+
+    /// Retrieve the next finished message as a body that has been collected without a head.
+    ///
+    /// Returns `None` when there is no finished message, or when the next finished message is a
+    /// request or a response.
+    pub fn take_body(&mut self) -> Option<Body> {
+        match self.messages.front() {
+            Some(message) if !message.is_request() && !message.is_response() => {},
+            _ => return None
+        }
+
+        self.messages.pop_front().map(|message| Body{ message })
+    }
+
+    /// Retrieve the next finished message as a request.
+    ///
+    /// Returns `None` when there is no finished message, or when the next finished message is not
+    /// a request.
+    pub fn take_request(&mut self) -> Option<Request> {
+        match self.messages.front() {
+            Some(message) if message.is_request() => {},
+            _ => return None
+        }
+
+        self.messages.pop_front().map(|message| Request{ message })
+    }
+
+    /// Retrieve the next finished message as a response.
+    ///
+    /// Returns `None` when there is no finished message, or when the next finished message is not
+    /// a response.
+    pub fn take_response(&mut self) -> Option<Response> {
+        match self.messages.front() {
+            Some(message) if message.is_response() => {},
+            _ => return None
+        }
+
+        self.messages.pop_front().map(|message| Response{ message })
+    }
+}
+
+impl Default for Collector {
+    fn default() -> Collector {
+        Collector::new()
+    }
+}
+
+impl HttpHandler<CollectorError> for Collector {
+    fn on_body(&mut self, data: &[u8]) -> Action<CollectorError> {
+        if !self.body_length(data.len()) {
+            return Action::Abort(CollectorError::BodyTooLong);
+        }
+
+        self.message.body.extend_from_slice(data);
+        Action::Continue
+    }
+
+    fn on_chunk_data(&mut self, data: &[u8]) -> Action<CollectorError> {
+        self.on_body(data)
+    }
+
+    fn on_header_name(&mut self, name: &[u8]) -> Action<CollectorError> {
+        let first = self.field != Field::HeaderName;
+
+        self.field = Field::HeaderName;
+        self.headers().name(name, first);
+        Action::Continue
+    }
+
+    fn on_header_value(&mut self, value: &[u8]) -> Action<CollectorError> {
+        self.field = Field::HeaderValue;
+        self.headers().value(value);
+        Action::Continue
+    }
+
+    fn on_interim(&mut self, _code: u16) -> Action<CollectorError> {
+        // the final response replaces the interim response
+        self.field   = Field::None;
+        self.message = Message::default();
+        Action::Continue
+    }
+
+    fn on_message_finished(&mut self, _keep_alive: bool) -> Action<CollectorError> {
+        self.finish();
+        Action::Continue
+    }
+
+    fn on_method(&mut self, method: &[u8]) -> Action<CollectorError> {
+        self.message.method.extend_from_slice(method);
+        Action::Continue
+    }
+
+    fn on_multipart_begin(&mut self) -> Action<CollectorError> {
+        if self.message.parts.len() == self.limits.max_parts {
+            return Action::Abort(CollectorError::TooManyParts);
+        }
+
+        self.field = Field::None;
+        self.message.parts.push(Part::default());
+        Action::Continue
+    }
+
+    fn on_multipart_data(&mut self, data: &[u8]) -> Action<CollectorError> {
+        if !self.body_length(data.len()) {
+            return Action::Abort(CollectorError::BodyTooLong);
+        }
+
+        if let Some(part) = self.message.parts.last_mut() {
+            part.data.extend_from_slice(data);
+        }
+
+        Action::Continue
+    }
+
+    fn on_status(&mut self, status: &[u8]) -> Action<CollectorError> {
+        self.message.status.extend_from_slice(status);
+        Action::Continue
+    }
+
+    fn on_status_code(&mut self, code: u16) -> Action<CollectorError> {
+        self.message.status_code = Some(code);
+        Action::Continue
+    }
+
+    fn on_trailer_name(&mut self, name: &[u8]) -> Action<CollectorError> {
+        let first = self.field != Field::TrailerName;
+
+        self.field = Field::TrailerName;
+        self.message.trailers.name(name, first);
+        Action::Continue
+    }
+
+    fn on_trailer_value(&mut self, value: &[u8]) -> Action<CollectorError> {
+        self.field = Field::TrailerValue;
+        self.message.trailers.value(value);
+        Action::Continue
+    }
+
+    fn on_upgrade(&mut self) -> Action<CollectorError> {
+        self.finish();
+        Action::Continue
+    }
+
+    fn on_url(&mut self, url: &[u8]) -> Action<CollectorError> {
+        self.message.url.extend_from_slice(url);
+        Action::Continue
+    }
+
+    fn on_url_encoded_begin(&mut self) -> Action<CollectorError> {
+        if self.message.fields.len() == self.limits.max_fields {
+            return Action::Abort(CollectorError::TooManyFields);
+        }
+
+        self.message.fields.push((Vec::new(), Vec::new()));
+        Action::Continue
+    }
+
+    fn on_url_encoded_name(&mut self, name: &[u8]) -> Action<CollectorError> {
+        if !self.body_length(name.len()) {
+            return Action::Abort(CollectorError::BodyTooLong);
+        }
+
+        if let Some(field) = self.message.fields.last_mut() {
+            field.0.extend_from_slice(name);
+        }
+
+        Action::Continue
+    }
+
+    fn on_url_encoded_value(&mut self, value: &[u8]) -> Action<CollectorError> {
+        if !self.body_length(value.len()) {
+            return Action::Abort(CollectorError::BodyTooLong);
+        }
+
+        if let Some(field) = self.message.fields.last_mut() {
+            field.1.extend_from_slice(value);
+        }
+
+        Action::Continue
+    }
+
+    fn on_version(&mut self, major: u16, minor: u16) -> Action<CollectorError> {
+        self.message.version = (major, minor);
+        Action::Continue
+    }
+}
@@ -0,0 +1,797 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+//! HTTP 1.x message framing.
+
+use http1::parser_error::ErrorKind;
+use util::FieldIterator;
+
+/// Fields that must not be sent as trailers, because they're needed before the body is processed,
+/// as described by [RFC 9110 section 6.5.1](https://tools.ietf.org/html/rfc9110#section-6.5.1).
+const FORBIDDEN_TRAILERS: [&[u8]; 18] = [
+    b"authorization",
+    b"cache-control",
+    b"content-encoding",
+    b"content-length",
+    b"content-range",
+    b"content-type",
+    b"expect",
+    b"host",
+    b"max-forwards",
+    b"pragma",
+    b"proxy-authenticate",
+    b"proxy-authorization",
+    b"range",
+    b"set-cookie",
+    b"te",
+    b"trailer",
+    b"transfer-encoding",
+    b"www-authenticate"
+];
+
+/// `close` bit of the `Connection` header tokens.
+const CLOSE: u8 = 1;
+
+/// `keep-alive` bit of the `Connection` header tokens.
+const KEEP_ALIVE: u8 = 2;
+
+/// `upgrade` bit of the `Connection` header tokens.
+const UPGRADE: u8 = 4;
+
+/// `Connection` header tokens, in bit order.
+const CONNECTION_TOKENS: [&[u8]; 3] = [b"close", b"keep-alive", b"upgrade"];
+
+/// `chunked` bit of the `Transfer-Encoding` header tokens.
+const CHUNKED: u8 = 1;
+
+/// `Transfer-Encoding` header tokens, in bit order.
+const TRANSFER_CODINGS: [&[u8]; 1] = [b"chunked"];
+
+/// Longest request method that is stored without allocating.
+const MAX_METHOD_LENGTH: usize = 16;
+
+/// Longest framing header name.
+const MAX_NAME_LENGTH: usize = 17;
+
+/// Longest known token.
+const MAX_TOKEN_LENGTH: usize = 10;
+
+/// Framing header that is currently being collected.
+#[derive(Clone,Copy,PartialEq)]
+enum Header {
+    /// Connection header.
+    Connection,
+
+    /// Content-Length header.
+    ContentLength,
+
+    /// Content-Type header.
+    ContentType,
+
+    /// Any other header.
+    Other,
+
+    /// Trailer header.
+    Trailer,
+
+    /// Transfer-Encoding header.
+    TransferEncoding,
+
+    /// Upgrade header.
+    Upgrade
+}
+
+/// Content length error.
+#[derive(Clone,Copy)]
+enum LengthError {
+    /// Invalid content length on byte `u8`.
+    Byte(u8),
+
+    /// Content lengths differ.
+    Mismatch,
+
+    /// Content length overflow.
+    Overflow
+}
+
+/// Comma separated list of content lengths, which is parsed as it's collected.
+#[derive(Clone,Copy)]
+struct ContentLength {
+    /// First error.
+    error: Option<LengthError>,
+
+    /// Length of the current item, once a digit has been collected.
+    item: Option<usize>,
+
+    /// Length of the finished items.
+    length: Option<usize>,
+
+    /// Indicates that a value has been collected.
+    present: bool,
+
+    /// Linear white space that follows the digits of the current item.
+    space: Option<u8>
+}
+
+impl ContentLength {
+    /// Create a new `ContentLength`.
+    fn new() -> ContentLength {
+        ContentLength{
+            error:   None,
+            item:    None,
+            length:  None,
+            present: false,
+            space:   None
+        }
+    }
+
+    /// Collect a value segment.
+    fn collect(&mut self, value: &[u8]) {
+        self.present |= !value.is_empty();
+
+        for byte in value {
+            if self.error.is_some() {
+                return;
+            }
+
+            match *byte {
+                b',' => {
+                    self.item_finished();
+                },
+                b' ' | b'\t' => {
+                    if self.item.is_some() && self.space.is_none() {
+                        self.space = Some(*byte);
+                    }
+                },
+                byte => {
+                    self.error = if let Some(space) = self.space {
+                        Some(LengthError::Byte(space))
+                    } else if !is_digit!(byte) {
+                        Some(LengthError::Byte(byte))
+                    } else {
+                        match self.item.unwrap_or(0)
+                                       .checked_mul(10)
+                                       .and_then(|x| x.checked_add((byte - b'0') as usize)) {
+                            Some(item) => {
+                                self.item = Some(item);
+
+                                None
+                            },
+                            None => {
+                                Some(LengthError::Overflow)
+                            }
+                        }
+                    };
+                }
+            }
+        }
+    }
+
+    /// Current item has been collected.
+    fn item_finished(&mut self) {
+        match (self.item, self.length) {
+            (None, _) => {
+                self.error = Some(LengthError::Byte(b','));
+            },
+            (Some(item), Some(length)) if item != length => {
+                self.error = Some(LengthError::Mismatch);
+            },
+            (item, _) => {
+                self.length = item;
+            }
+        }
+
+        self.item  = None;
+        self.space = None;
+    }
+
+    /// Retrieve the content length.
+    fn length<E>(&self) -> Result<usize, ErrorKind<E>> {
+        let mut list = *self;
+
+        if list.error.is_none() {
+            list.item_finished();
+        }
+
+        match list.error {
+            Some(LengthError::Byte(byte)) => Err(ErrorKind::ContentLength(byte)),
+            Some(LengthError::Mismatch)   => Err(ErrorKind::ContentLengthMismatch),
+            Some(LengthError::Overflow)   => Err(ErrorKind::ContentLengthOverflow),
+            None                          => Ok(list.length.unwrap_or(0))
+        }
+    }
+}
+
+/// Comma separated list, which is scanned for known tokens as it's collected.
+#[derive(Clone,Copy)]
+struct TokenList {
+    /// Known tokens of the finished items, as a bit for each known token.
+    found: u8,
+
+    /// Current item storage.
+    item: [u8; MAX_TOKEN_LENGTH],
+
+    /// Current item length.
+    item_length: usize,
+
+    /// Known token of the last non-empty item, or `0` when it's not a known token.
+    last: u8,
+
+    /// Indicates that a value has been collected.
+    present: bool,
+
+    /// Known tokens that have been found more than once.
+    repeated: u8,
+
+    /// Indicates that linear white space follows the current item.
+    space: bool,
+
+    /// Known tokens.
+    tokens: &'static [&'static [u8]]
+}
+
+impl TokenList {
+    /// Create a new `TokenList`.
+    fn new(tokens: &'static [&'static [u8]]) -> TokenList {
+        TokenList{
+            found:       0,
+            item:        [0; MAX_TOKEN_LENGTH],
+            item_length: 0,
+            last:        0,
+            present:     false,
+            repeated:    0,
+            space:       false,
+            tokens
+        }
+    }
+
+    /// Collect a value segment.
+    fn collect(&mut self, value: &[u8]) {
+        self.present |= !value.is_empty();
+
+        for byte in value {
+            match *byte {
+                b',' => {
+                    self.item_finished();
+                },
+                b' ' | b'\t' => {
+                    self.space = self.item_length > 0;
+                },
+                byte => {
+                    if self.space {
+                        // known tokens never contain linear white space
+                        self.item_length = MAX_TOKEN_LENGTH + 1;
+                        self.space       = false;
+                    }
+
+                    if self.item_length < MAX_TOKEN_LENGTH {
+                        self.item[self.item_length] = byte;
+                    }
+
+                    self.item_length += 1;
+                }
+            }
+        }
+    }
+
+    /// Indicates that the list contains the known `token`.
+    fn contains(&self, token: u8) -> bool {
+        let mut list = *self;
+
+        list.item_finished();
+        list.found & token == token
+    }
+
+    /// Indicates that the known `token` is the final item, and that it's found only once.
+    fn ends_with_once(&self, token: u8) -> bool {
+        let mut list = *self;
+
+        list.item_finished();
+        list.last == token && list.repeated & token == 0
+    }
+
+    /// Current item has been collected.
+    fn item_finished(&mut self) {
+        if self.item_length > 0 {
+            let token = self.tokens
+                            .iter()
+                            .position(|token| {
+                                token.len() == self.item_length
+                                && token.eq_ignore_ascii_case(&self.item[..self.item_length])
+                            })
+                            .map_or(0, |n| 1 << n);
+
+            self.repeated |= self.found & token;
+            self.found    |= token;
+            self.last      = token;
+        }
+
+        self.item_length = 0;
+        self.space       = false;
+    }
+}
+
+/// Message body, as determined by the framing headers.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Body {
+    /// Chunk transfer encoded body.
+    Chunked,
+
+    /// Body that is delimited by the end of input.
+    Eof,
+
+    /// Body of the given content length.
+    Length(usize),
+
+    /// Multipart body of the given content length.
+    Multipart(usize),
+
+    /// No body.
+    None,
+
+    /// URL encoded body of the given content length.
+    UrlEncoded(usize)
+}
+
+/// Framing header collection.
+///
+/// Collects the `Connection`, `Content-Length`, `Transfer-Encoding`, and `Upgrade` header values,
+/// along with the request method and response status code, while the head is being parsed. The
+/// `Content-Type` and `Trailer` header values are also collected when the body is parsed along with
+/// the head. Once the head has finished, these determine whether the connection is upgraded, and
+/// how the body is detected.
+///
+/// Header values that are needed by the head alone are scanned as they're collected, rather than
+/// stored, so that parsing only the head does not allocate.
+pub struct Framing {
+    /// Multipart boundary.
+    boundary: Vec<u8>,
+
+    /// Connection header values.
+    connection: TokenList,
+
+    /// Content-Length header values.
+    content_length: ContentLength,
+
+    /// Content-Type header values.
+    content_type: Vec<u8>,
+
+    /// Current header.
+    header: Header,
+
+    /// Request method storage, once the request method is longer than `MAX_METHOD_LENGTH`.
+    long_method: Vec<u8>,
+
+    /// Request method storage.
+    method: [u8; MAX_METHOD_LENGTH],
+
+    /// Request method length.
+    method_length: usize,
+
+    /// Header name storage.
+    name: [u8; MAX_NAME_LENGTH],
+
+    /// Header name length.
+    name_length: usize,
+
+    /// Response status code.
+    status_code: Option<u16>,
+
+    /// Trailer header values.
+    trailer: Vec<u8>,
+
+    /// Trailer name storage.
+    trailer_name: Vec<u8>,
+
+    /// Transfer-Encoding header values.
+    transfer_encoding: TokenList,
+
+    /// Indicates that an Upgrade header value has been collected.
+    upgrade: bool,
+
+    /// HTTP major and minor version.
+    version: (u16, u16)
+}
+
+impl Framing {
+    /// Create a new `Framing`.
+    pub fn new() -> Framing {
+        Framing{
+            boundary:          Vec::new(),
+            connection:        TokenList::new(&CONNECTION_TOKENS),
+            content_length:    ContentLength::new(),
+            content_type:      Vec::new(),
+            header:            Header::Other,
+            long_method:       Vec::new(),
+            method:            [0; MAX_METHOD_LENGTH],
+            method_length:     0,
+            name:              [0; MAX_NAME_LENGTH],
+            name_length:       0,
+            status_code:       None,
+            trailer:           Vec::new(),
+            trailer_name:      Vec::new(),
+            transfer_encoding: TokenList::new(&TRANSFER_CODINGS),
+            upgrade:           false,
+            version:           (0, 0)
+        }
+    }
+
+    /// Detect the message body.
+    ///
+    /// This follows [RFC 9112 section 6.3](https://tools.ietf.org/html/rfc9112#section-6.3).
+    /// Framing headers that could be interpreted differently by another recipient, such as both
+    /// `Transfer-Encoding` and `Content-Length`, are rejected rather than resolved by precedence.
+    /// See [`validate()`](#method.validate).
+    ///
+    /// # Arguments
+    ///
+    /// **`head`**
+    ///
+    /// Indicates that the response being parsed answers a `HEAD` request.
+    pub fn body<E>(&mut self, head: bool) -> Result<Body, ErrorKind<E>> {
+        if !self.has_body(head) {
+            return Ok(Body::None);
+        }
+
+        self.validate(head)?;
+
+        if self.transfer_encoding.present {
+            return if self.is_chunked() {
+                Ok(Body::Chunked)
+            } else {
+                // response whose final transfer coding is not chunked is delimited by the
+                // connection closing
+                Ok(Body::Eof)
+            };
+        }
+
+        if !self.content_length.present {
+            return if self.status_code.is_some() {
+                // response without framing headers is delimited by the connection closing
+                Ok(Body::Eof)
+            } else {
+                Ok(Body::None)
+            };
+        }
+
+        let length = self.content_length.length()?;
+
+        if length == 0 {
+            Ok(Body::None)
+        } else if self.is_url_encoded() {
+            Ok(Body::UrlEncoded(length))
+        } else if self.find_boundary() {
+            Ok(Body::Multipart(length))
+        } else {
+            Ok(Body::Length(length))
+        }
+    }
+
+    /// Retrieve the multipart boundary.
+    pub fn boundary(&self) -> &[u8] {
+        &self.boundary
+    }
+
+    /// Locate the multipart boundary within the content type.
+    fn find_boundary(&mut self) -> bool {
+        let mut is_multipart = false;
+
+        for (n, (name, value)) in FieldIterator::new(&self.content_type, b';', true).enumerate() {
+            if n == 0 {
+                if !name.starts_with("multipart/") {
+                    return false;
+                }
+
+                is_multipart = true;
+            } else if name == "boundary" {
+                if let Some(value) = value {
+                    self.boundary.clear();
+                    self.boundary.extend_from_slice(value.as_bytes());
+                }
+            }
+        }
+
+        is_multipart && !self.boundary.is_empty()
+    }
+
+    /// Indicates that the message may have a body.
+    fn has_body(&self, head: bool) -> bool {
+        match self.status_code {
+            Some(status_code) => {
+                // response to a HEAD request, 1xx, 204 and 304 responses never have a body
+                !(head || status_code < 200 || status_code == 204 || status_code == 304)
+            },
+            None => {
+                true
+            }
+        }
+    }
+
+    /// Indicates that a value has been collected for the current header.
+    fn has_value(&self) -> bool {
+        match self.header {
+            Header::Connection       => self.connection.present,
+            Header::ContentLength    => self.content_length.present,
+            Header::ContentType      => !self.content_type.is_empty(),
+            Header::Other            => false,
+            Header::Trailer          => !self.trailer.is_empty(),
+            Header::TransferEncoding => self.transfer_encoding.present,
+            Header::Upgrade          => self.upgrade
+        }
+    }
+
+    /// Indicates that `chunked` is the final transfer coding, and that it has been applied only
+    /// once.
+    fn is_chunked(&self) -> bool {
+        self.transfer_encoding.ends_with_once(CHUNKED)
+    }
+
+    /// Retrieve the status code of an interim response, which is followed by the final response.
+    ///
+    /// `101 Switching Protocols` is not an interim response.
+    pub fn interim(&self) -> Option<u16> {
+        match self.status_code {
+            Some(status_code) if status_code > 99 && status_code < 200 && status_code != 101 => {
+                Some(status_code)
+            },
+            _ => {
+                None
+            }
+        }
+    }
+
+    /// Indicates that the connection persists once the message has finished.
+    ///
+    /// HTTP/1.1 connections persist unless the `Connection` header contains `close`, and HTTP/1.0
+    /// connections persist only when the `Connection` header contains `keep-alive`.
+    pub fn is_keep_alive(&self) -> bool {
+        if self.connection.contains(CLOSE) {
+            false
+        } else if self.version >= (1, 1) {
+            true
+        } else {
+            self.connection.contains(KEEP_ALIVE)
+        }
+    }
+
+    /// Indicates that the connection switches to another protocol once the head has finished.
+    ///
+    /// # Arguments
+    ///
+    /// **`connect`**
+    ///
+    /// Indicates that the response being parsed answers a `CONNECT` request.
+    pub fn is_upgrade(&self, connect: bool) -> bool {
+        match self.status_code {
+            Some(status_code) => {
+                status_code == 101 || (connect && status_code > 199 && status_code < 300)
+            },
+            None => {
+                self.request_method() == b"CONNECT"
+                || (self.upgrade && self.connection.contains(UPGRADE))
+            }
+        }
+    }
+
+    /// Indicates that the content type is URL encoded.
+    fn is_url_encoded(&self) -> bool {
+        match FieldIterator::new(&self.content_type, b';', true).next() {
+            Some((name, _)) => name == "application/x-www-form-urlencoded",
+            None => false
+        }
+    }
+
+    /// Collect a request method segment.
+    #[inline]
+    pub fn method(&mut self, method: &[u8]) {
+        if self.method_length + method.len() <= MAX_METHOD_LENGTH {
+            self.method[self.method_length..self.method_length + method.len()]
+                .copy_from_slice(method);
+        } else {
+            if self.method_length <= MAX_METHOD_LENGTH {
+                // request method no longer fits within the fixed storage
+                self.long_method.extend_from_slice(&self.method[..self.method_length]);
+            }
+
+            self.long_method.extend_from_slice(method);
+        }
+
+        self.method_length += method.len();
+    }
+
+    /// Collect a header name segment.
+    #[inline]
+    pub fn name(&mut self, name: &[u8]) {
+        if self.name_length + name.len() <= MAX_NAME_LENGTH {
+            self.name[self.name_length..self.name_length + name.len()].copy_from_slice(name);
+        }
+
+        self.name_length += name.len();
+    }
+
+    /// Header name has been collected.
+    ///
+    /// # Arguments
+    ///
+    /// **`message`**
+    ///
+    /// Indicates that the body is parsed along with the head. The `Content-Type` and `Trailer`
+    /// headers are only needed by the body, and are collected only when this is set.
+    #[inline]
+    pub fn name_finished(&mut self, message: bool) {
+        self.header = if self.name_length > MAX_NAME_LENGTH {
+            Header::Other
+        } else {
+            // header names are mixed-case when the parser preserves header name case
+            self.name[..self.name_length].make_ascii_lowercase();
+
+            match &self.name[..self.name_length] {
+                b"connection"                   => Header::Connection,
+                b"content-length"               => Header::ContentLength,
+                b"content-type"      if message => Header::ContentType,
+                b"trailer"           if message => Header::Trailer,
+                b"transfer-encoding"            => Header::TransferEncoding,
+                b"upgrade"                      => Header::Upgrade,
+                _                               => Header::Other
+            }
+        };
+
+        self.name_length = 0;
+
+        // multiple headers of the same name are combined into a comma separated list
+        if self.has_value() {
+            self.value(b",");
+        }
+    }
+
+    /// Retrieve the request method.
+    pub fn request_method(&self) -> &[u8] {
+        if self.method_length > MAX_METHOD_LENGTH {
+            &self.long_method
+        } else {
+            &self.method[..self.method_length]
+        }
+    }
+
+    /// Reset `Framing` to its initial state.
+    pub fn reset(&mut self) {
+        self.boundary.clear();
+        self.content_type.clear();
+        self.long_method.clear();
+        self.trailer.clear();
+        self.trailer_name.clear();
+
+        self.connection        = TokenList::new(&CONNECTION_TOKENS);
+        self.content_length    = ContentLength::new();
+        self.header            = Header::Other;
+        self.method_length     = 0;
+        self.name_length       = 0;
+        self.status_code       = None;
+        self.transfer_encoding = TokenList::new(&TRANSFER_CODINGS);
+        self.upgrade           = false;
+        self.version           = (0, 0);
+    }
+
+    /// Set the response status code.
+    #[inline]
+    pub fn status_code(&mut self, status_code: u16) {
+        self.status_code = Some(status_code);
+    }
+
+    /// Collect a trailer name segment.
+    #[inline]
+    pub fn trailer_name(&mut self, name: &[u8]) {
+        self.trailer_name.extend_from_slice(name);
+    }
+
+    /// Trailer name has been collected.
+    ///
+    /// Trailers must have been announced by the `Trailer` header, and must not be a field that is
+    /// forbidden from being sent as a trailer.
+    pub fn trailer_name_finished<E>(&mut self) -> Result<(), ErrorKind<E>> {
+        let result = if is_forbidden_trailer(&self.trailer_name) {
+            Err(ErrorKind::ForbiddenTrailer)
+        } else if !has_token(&self.trailer, &self.trailer_name) {
+            Err(ErrorKind::UnannouncedTrailer)
+        } else {
+            Ok(())
+        };
+
+        self.trailer_name.clear();
+
+        result
+    }
+
+    /// Collect a header value segment.
+    #[inline]
+    pub fn value(&mut self, value: &[u8]) {
+        match self.header {
+            Header::Connection       => self.connection.collect(value),
+            Header::ContentLength    => self.content_length.collect(value),
+            Header::ContentType      => self.content_type.extend_from_slice(value),
+            Header::Other            => {},
+            Header::Trailer          => self.trailer.extend_from_slice(value),
+            Header::TransferEncoding => self.transfer_encoding.collect(value),
+            Header::Upgrade          => self.upgrade |= !value.is_empty()
+        }
+    }
+
+    /// Validate the framing headers.
+    ///
+    /// Framing headers that could be interpreted differently by another recipient are rejected:
+    /// `Transfer-Encoding` alongside `Content-Length`, a request whose final transfer coding is not
+    /// `chunked`, and an invalid or inconsistent `Content-Length`. Framing headers of a response
+    /// that never has a body are not validated.
+    ///
+    /// # Arguments
+    ///
+    /// **`head`**
+    ///
+    /// Indicates that the response being parsed answers a `HEAD` request.
+    pub fn validate<E>(&self, head: bool) -> Result<(), ErrorKind<E>> {
+        if !self.has_body(head) {
+            return Ok(());
+        }
+
+        if self.transfer_encoding.present {
+            if self.content_length.present {
+                return Err(ErrorKind::TransferEncodingWithContentLength);
+            }
+
+            if self.status_code.is_none() && !self.is_chunked() {
+                return Err(ErrorKind::ChunkedNotLast);
+            }
+        } else if self.content_length.present {
+            self.content_length.length()?;
+        }
+
+        Ok(())
+    }
+
+    /// Set the HTTP version.
+    #[inline]
+    pub fn version(&mut self, major: u16, minor: u16) {
+        self.version = (major, minor);
+    }
+}
+
+/// Indicates that the comma separated `list` contains `token`, compared case-insensitively.
+fn has_token(list: &[u8], token: &[u8]) -> bool {
+    list.split(|byte| *byte == b',')
+        .map(trim)
+        .any(|item| item.eq_ignore_ascii_case(token))
+}
+
+/// Indicates that the field `name` must not be sent as a trailer, compared case-insensitively.
+pub fn is_forbidden_trailer(name: &[u8]) -> bool {
+    FORBIDDEN_TRAILERS.iter().any(|forbidden| forbidden.eq_ignore_ascii_case(name))
+}
+
+/// Trim linear white space from both sides of `bytes`.
+fn trim(bytes: &[u8]) -> &[u8] {
+    let mut start = 0;
+    let mut end   = bytes.len();
+
+    while start < end && (bytes[start] == b' ' || bytes[start] == b'\t') {
+        start += 1;
+    }
+
+    while end > start && (bytes[end - 1] == b' ' || bytes[end - 1] == b'\t') {
+        end -= 1;
+    }
+
+    &bytes[start..end]
+}
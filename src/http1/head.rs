@@ -0,0 +1,415 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+//! One-shot HTTP 1.x head parsing.
+
+use fsm::{ Action, Success };
+use http1::http_handler::HttpHandler;
+use http1::limits::Limits;
+use http1::parser::Parser;
+use http1::parser_error::ParserError;
+
+/// Maximum header count of a `RequestHead` or `ResponseHead`.
+pub const MAX_HEADERS: usize = 64;
+
+/// Result of a one-shot head parse.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Parsed<T> {
+    /// The entire head has been parsed.
+    Complete(T),
+
+    /// The head is incomplete, and must be parsed again once more data has arrived.
+    Partial
+}
+
+impl<T> Parsed<T> {
+    /// Indicates that the entire head has been parsed.
+    pub fn is_complete(&self) -> bool {
+        match *self {
+            Parsed::Complete(_) => true,
+            Parsed::Partial     => false
+        }
+    }
+
+    /// Indicates that the head is incomplete.
+    pub fn is_partial(&self) -> bool {
+        !self.is_complete()
+    }
+}
+
+/// Field that is currently being collected.
+#[derive(Clone,Copy,PartialEq)]
+enum Field {
+    /// Header name.
+    HeaderName,
+
+    /// Header value.
+    HeaderValue,
+
+    /// Request method.
+    Method,
+
+    /// No field.
+    None,
+
+    /// Response status.
+    Status,
+
+    /// Request URL.
+    Url
+}
+
+/// Head that has been collected as slices of the stream being parsed.
+struct Head<'a> {
+    /// Header count.
+    header_count: usize,
+
+    /// Header name and value pairs.
+    headers: [(&'a [u8], &'a [u8]); MAX_HEADERS],
+
+    /// Head length.
+    length: usize,
+
+    /// Request method, or response status.
+    method_or_status: &'a [u8],
+
+    /// Response status code.
+    status_code: u16,
+
+    /// Request URL.
+    url: &'a [u8],
+
+    /// HTTP major and minor version.
+    version: (u16, u16)
+}
+
+/// Handler that collects a head as slices of the stream being parsed.
+struct HeadHandler<'a> {
+    /// Current field.
+    field: Field,
+
+    /// Head being collected.
+    head: Head<'a>,
+
+    /// Absolute offset of the data supplied to the next callback.
+    offset: usize,
+
+    /// Indicates that a request is expected, rather than a response.
+    request: bool,
+
+    /// Offset of the current field.
+    start: usize,
+
+    /// Stream being parsed.
+    stream: &'a [u8]
+}
+
+impl<'a> HeadHandler<'a> {
+    /// Create a new `HeadHandler`.
+    fn new(stream: &'a [u8], request: bool) -> HeadHandler<'a> {
+        HeadHandler{
+            field:    Field::None,
+            head:     Head{
+                header_count:     0,
+                headers:          [(b"", b""); MAX_HEADERS],
+                length:           0,
+                method_or_status: b"",
+                status_code:      0,
+                url:              b"",
+                version:          (0, 0)
+            },
+            offset:   0,
+            request,
+            start:    0,
+            stream
+        }
+    }
+
+    /// Collect `data` as a segment of `field`, and retrieve the entire field collected so far.
+    ///
+    /// The segments of a field are contiguous within the stream, so the field spans from the
+    /// offset of its first segment through the end of `data`.
+    fn collect(&mut self, field: Field, data: &[u8]) -> &'a [u8] {
+        if self.field != field {
+            self.field = field;
+            self.start = self.offset;
+        }
+
+        let stream = self.stream;
+
+        &stream[self.start..self.offset + data.len()]
+    }
+
+    /// Parse the stream, and retrieve the head.
+    fn parse(mut self) -> Result<Parsed<Head<'a>>, ParserError> {
+        let stream = self.stream;
+        let mut parser = Parser::new();
+
+        parser.set_expected_head(Some(self.request));
+        parser.set_header_case_preservation(true);
+        parser.set_limits(Limits{
+            max_headers: MAX_HEADERS,
+            ..Limits::default()
+        });
+
+        parser.init_head();
+
+        match parser.resume(&mut self, stream) {
+            Ok(Success::Finished(length))
+            | Ok(Success::Upgrade(length)) => {
+                self.head.length = length;
+
+                Ok(Parsed::Complete(self.head))
+            },
+            Ok(Success::Callback(_))
+            | Ok(Success::Eos(_)) => {
+                // callbacks never exit the parser prematurely, so the entire stream has been parsed
+                Ok(Parsed::Partial)
+            },
+            Err(error) => {
+                Err(error)
+            }
+        }
+    }
+}
+
+impl<'a> HttpHandler for HeadHandler<'a> {
+    fn on_header_name(&mut self, name: &[u8]) -> Action {
+        if self.field != Field::HeaderName {
+            self.head.header_count += 1;
+        }
+
+        let name  = self.collect(Field::HeaderName, name);
+        let index = self.head.header_count - 1;
+
+        self.head.headers[index] = (name, b"");
+        Action::Continue
+    }
+
+    fn on_header_value(&mut self, value: &[u8]) -> Action {
+        let value = self.collect(Field::HeaderValue, value);
+        let index = self.head.header_count - 1;
+
+        self.head.headers[index].1 = value;
+        Action::Continue
+    }
+
+    fn on_method(&mut self, method: &[u8]) -> Action {
+        self.head.method_or_status = self.collect(Field::Method, method);
+        Action::Continue
+    }
+
+    fn on_status(&mut self, status: &[u8]) -> Action {
+        self.head.method_or_status = self.collect(Field::Status, status);
+        Action::Continue
+    }
+
+    fn on_status_code(&mut self, code: u16) -> Action {
+        self.head.status_code = code;
+        Action::Continue
+    }
+
+    fn on_url(&mut self, url: &[u8]) -> Action {
+        self.head.url = self.collect(Field::Url, url);
+        Action::Continue
+    }
+
+    fn on_version(&mut self, major: u16, minor: u16) -> Action {
+        self.head.version = (major, minor);
+        Action::Continue
+    }
+
+    fn set_offset(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+}
+
+/// Find the value of the header `name`, compared case-insensitively.
+fn find_header<'a>(headers: &[(&'a [u8], &'a [u8])], name: &[u8]) -> Option<&'a [u8]> {
+    headers.iter()
+           .find(|&&(header, _)| header.eq_ignore_ascii_case(name))
+           .map(|&(_, value)| value)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Request head that has been parsed in one shot, as slices of the parsed stream.
+///
+/// Header names are supplied as they were received, and header values are supplied as they appear
+/// within the stream. Values that span multiple lines due to obsolete line folding contain the
+/// line folding.
+pub struct RequestHead<'a> {
+    /// Parsed head.
+    head: Head<'a>
+}
+
+impl<'a> RequestHead<'a> {
+    /// Parse a complete request head from `stream`.
+    ///
+    /// Returns `Parsed::Partial` when `stream` does not contain the entire head, in which case
+    /// parsing may be retried from the start once more data has arrived.
+    ///
+    /// # Errors
+    ///
+    /// An `ErrorKind::TooManyHeaders` error is returned when the head contains more than
+    /// `MAX_HEADERS` headers, and an `ErrorKind::UnexpectedResponse` error is returned when
+    /// `stream` contains a response. Framing headers are validated, as they are during message
+    /// parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_box::http1::{ Parsed, RequestHead };
+    ///
+    /// let stream = b"GET /index.html HTTP/1.1\r\nHost: localhost\r\n\r\n";
+    ///
+    /// match RequestHead::parse(stream) {
+    ///     Ok(Parsed::Complete(head)) => {
+    ///         assert_eq!(head.method(), b"GET");
+    ///         assert_eq!(head.url(), b"/index.html");
+    ///         assert_eq!(head.header(b"host"), Some(&b"localhost"[..]));
+    ///         assert_eq!(head.length(), stream.len());
+    ///     },
+    ///     _ => panic!()
+    /// }
+    ///
+    /// assert!(RequestHead::parse(&stream[..20]).unwrap().is_partial());
+    /// ```
+    pub fn parse(stream: &'a [u8]) -> Result<Parsed<RequestHead<'a>>, ParserError> {
+        match HeadHandler::new(stream, true).parse()? {
+            Parsed::Complete(head) => Ok(Parsed::Complete(RequestHead{ head })),
+            Parsed::Partial        => Ok(Parsed::Partial)
+        }
+    }
+
+    /// Retrieve the value of the header `name`, compared case-insensitively.
+    ///
+    /// When multiple headers have the same name, the first value is retrieved.
+    pub fn header(&self, name: &[u8]) -> Option<&'a [u8]> {
+        find_header(self.headers(), name)
+    }
+
+    /// Retrieve the header name and value pairs, in the order they were received.
+    pub fn headers(&self) -> &[(&'a [u8], &'a [u8])] {
+        &self.head.headers[..self.head.header_count]
+    }
+
+    /// Retrieve the head length, including the empty line that ends it.
+    ///
+    /// Any data that follows is the body, or data for the upgraded protocol.
+    pub fn length(&self) -> usize {
+        self.head.length
+    }
+
+    /// Retrieve the request method.
+    pub fn method(&self) -> &'a [u8] {
+        self.head.method_or_status
+    }
+
+    /// Retrieve the request URL.
+    pub fn url(&self) -> &'a [u8] {
+        self.head.url
+    }
+
+    /// Retrieve the HTTP major and minor version.
+    pub fn version(&self) -> (u16, u16) {
+        self.head.version
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Response head that has been parsed in one shot, as slices of the parsed stream.
+///
+/// Header names are supplied as they were received, and header values are supplied as they appear
+/// within the stream. Values that span multiple lines due to obsolete line folding contain the
+/// line folding.
+pub struct ResponseHead<'a> {
+    /// Parsed head.
+    head: Head<'a>
+}
+
+impl<'a> ResponseHead<'a> {
+    /// Parse a complete response head from `stream`.
+    ///
+    /// Returns `Parsed::Partial` when `stream` does not contain the entire head, in which case
+    /// parsing may be retried from the start once more data has arrived.
+    ///
+    /// # Errors
+    ///
+    /// An `ErrorKind::TooManyHeaders` error is returned when the head contains more than
+    /// `MAX_HEADERS` headers, and an `ErrorKind::UnexpectedRequest` error is returned when
+    /// `stream` contains a request. Framing headers are validated, as they are during message
+    /// parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_box::http1::{ Parsed, ResponseHead };
+    ///
+    /// let stream = b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nHello";
+    ///
+    /// match ResponseHead::parse(stream) {
+    ///     Ok(Parsed::Complete(head)) => {
+    ///         assert_eq!(head.status_code(), 200);
+    ///         assert_eq!(head.status(), b"OK");
+    ///         assert_eq!(head.header(b"content-length"), Some(&b"5"[..]));
+    ///         assert_eq!(&stream[head.length()..], b"Hello");
+    ///     },
+    ///     _ => panic!()
+    /// }
+    /// ```
+    pub fn parse(stream: &'a [u8]) -> Result<Parsed<ResponseHead<'a>>, ParserError> {
+        match HeadHandler::new(stream, false).parse()? {
+            Parsed::Complete(head) => Ok(Parsed::Complete(ResponseHead{ head })),
+            Parsed::Partial        => Ok(Parsed::Partial)
+        }
+    }
+
+    /// Retrieve the value of the header `name`, compared case-insensitively.
+    ///
+    /// When multiple headers have the same name, the first value is retrieved.
+    pub fn header(&self, name: &[u8]) -> Option<&'a [u8]> {
+        find_header(self.headers(), name)
+    }
+
+    /// Retrieve the header name and value pairs, in the order they were received.
+    pub fn headers(&self) -> &[(&'a [u8], &'a [u8])] {
+        &self.head.headers[..self.head.header_count]
+    }
+
+    /// Retrieve the head length, including the empty line that ends it.
+    ///
+    /// Any data that follows is the body, or data for the upgraded protocol.
+    pub fn length(&self) -> usize {
+        self.head.length
+    }
+
+    /// Retrieve the response status.
+    pub fn status(&self) -> &'a [u8] {
+        self.head.method_or_status
+    }
+
+    /// Retrieve the response status code.
+    pub fn status_code(&self) -> u16 {
+        self.head.status_code
+    }
+
+    /// Retrieve the HTTP major and minor version.
+    pub fn version(&self) -> (u16, u16) {
+        self.head.version
+    }
+}
@@ -0,0 +1,859 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+//! HTTP 1.x header names.
+
+/// Length of the longest known header name.
+pub const MAX_HEADER_NAME_LENGTH: usize = 40;
+
+/// Known header name.
+///
+/// This covers the permanent header names of the
+/// [IANA HTTP Field Name Registry](https://www.iana.org/assignments/http-fields), along with a
+/// handful of common non-standard header names.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum HeaderName {
+    /// `accept` header.
+    Accept,
+
+    /// `accept-ch` header.
+    AcceptCh,
+
+    /// `accept-charset` header.
+    AcceptCharset,
+
+    /// `accept-encoding` header.
+    AcceptEncoding,
+
+    /// `accept-language` header.
+    AcceptLanguage,
+
+    /// `accept-patch` header.
+    AcceptPatch,
+
+    /// `accept-post` header.
+    AcceptPost,
+
+    /// `accept-ranges` header.
+    AcceptRanges,
+
+    /// `access-control-allow-credentials` header.
+    AccessControlAllowCredentials,
+
+    /// `access-control-allow-headers` header.
+    AccessControlAllowHeaders,
+
+    /// `access-control-allow-methods` header.
+    AccessControlAllowMethods,
+
+    /// `access-control-allow-origin` header.
+    AccessControlAllowOrigin,
+
+    /// `access-control-expose-headers` header.
+    AccessControlExposeHeaders,
+
+    /// `access-control-max-age` header.
+    AccessControlMaxAge,
+
+    /// `access-control-request-headers` header.
+    AccessControlRequestHeaders,
+
+    /// `access-control-request-method` header.
+    AccessControlRequestMethod,
+
+    /// `age` header.
+    Age,
+
+    /// `allow` header.
+    Allow,
+
+    /// `alpn` header.
+    Alpn,
+
+    /// `alt-svc` header.
+    AltSvc,
+
+    /// `alt-used` header.
+    AltUsed,
+
+    /// `authentication-control` header.
+    AuthenticationControl,
+
+    /// `authentication-info` header.
+    AuthenticationInfo,
+
+    /// `authorization` header.
+    Authorization,
+
+    /// `cache-control` header.
+    CacheControl,
+
+    /// `cache-status` header.
+    CacheStatus,
+
+    /// `cal-managed-id` header.
+    CalManagedId,
+
+    /// `caldav-timezones` header.
+    CaldavTimezones,
+
+    /// `cdn-cache-control` header.
+    CdnCacheControl,
+
+    /// `cdn-loop` header.
+    CdnLoop,
+
+    /// `cert-not-after` header.
+    CertNotAfter,
+
+    /// `cert-not-before` header.
+    CertNotBefore,
+
+    /// `clear-site-data` header.
+    ClearSiteData,
+
+    /// `close` header.
+    Close,
+
+    /// `connection` header.
+    Connection,
+
+    /// `content-digest` header.
+    ContentDigest,
+
+    /// `content-disposition` header.
+    ContentDisposition,
+
+    /// `content-encoding` header.
+    ContentEncoding,
+
+    /// `content-language` header.
+    ContentLanguage,
+
+    /// `content-length` header.
+    ContentLength,
+
+    /// `content-location` header.
+    ContentLocation,
+
+    /// `content-range` header.
+    ContentRange,
+
+    /// `content-security-policy` header.
+    ContentSecurityPolicy,
+
+    /// `content-security-policy-report-only` header.
+    ContentSecurityPolicyReportOnly,
+
+    /// `content-type` header.
+    ContentType,
+
+    /// `cookie` header.
+    Cookie,
+
+    /// `cross-origin-embedder-policy` header.
+    CrossOriginEmbedderPolicy,
+
+    /// `cross-origin-embedder-policy-report-only` header.
+    CrossOriginEmbedderPolicyReportOnly,
+
+    /// `cross-origin-opener-policy` header.
+    CrossOriginOpenerPolicy,
+
+    /// `cross-origin-opener-policy-report-only` header.
+    CrossOriginOpenerPolicyReportOnly,
+
+    /// `cross-origin-resource-policy` header.
+    CrossOriginResourcePolicy,
+
+    /// `dasl` header.
+    Dasl,
+
+    /// `date` header.
+    Date,
+
+    /// `dav` header.
+    Dav,
+
+    /// `delta-base` header.
+    DeltaBase,
+
+    /// `depth` header.
+    Depth,
+
+    /// `destination` header.
+    Destination,
+
+    /// `early-data` header.
+    EarlyData,
+
+    /// `etag` header.
+    Etag,
+
+    /// `expect` header.
+    Expect,
+
+    /// `expires` header.
+    Expires,
+
+    /// `forwarded` header.
+    Forwarded,
+
+    /// `from` header.
+    From,
+
+    /// `hobareg` header.
+    Hobareg,
+
+    /// `host` header.
+    Host,
+
+    /// `if` header.
+    If,
+
+    /// `if-match` header.
+    IfMatch,
+
+    /// `if-modified-since` header.
+    IfModifiedSince,
+
+    /// `if-none-match` header.
+    IfNoneMatch,
+
+    /// `if-range` header.
+    IfRange,
+
+    /// `if-schedule-tag-match` header.
+    IfScheduleTagMatch,
+
+    /// `if-unmodified-since` header.
+    IfUnmodifiedSince,
+
+    /// `im` header.
+    Im,
+
+    /// `include-referred-token-binding-id` header.
+    IncludeReferredTokenBindingId,
+
+    /// `keep-alive` header.
+    KeepAlive,
+
+    /// `label` header.
+    Label,
+
+    /// `last-event-id` header.
+    LastEventId,
+
+    /// `last-modified` header.
+    LastModified,
+
+    /// `link` header.
+    Link,
+
+    /// `location` header.
+    Location,
+
+    /// `lock-token` header.
+    LockToken,
+
+    /// `max-forwards` header.
+    MaxForwards,
+
+    /// `memento-datetime` header.
+    MementoDatetime,
+
+    /// `meter` header.
+    Meter,
+
+    /// `mime-version` header.
+    MimeVersion,
+
+    /// `negotiate` header.
+    Negotiate,
+
+    /// `odata-entityid` header.
+    OdataEntityid,
+
+    /// `odata-isolation` header.
+    OdataIsolation,
+
+    /// `odata-maxversion` header.
+    OdataMaxversion,
+
+    /// `odata-version` header.
+    OdataVersion,
+
+    /// `optional-www-authenticate` header.
+    OptionalWwwAuthenticate,
+
+    /// `ordering-type` header.
+    OrderingType,
+
+    /// `origin` header.
+    Origin,
+
+    /// `origin-agent-cluster` header.
+    OriginAgentCluster,
+
+    /// `oscore` header.
+    Oscore,
+
+    /// `oslc-core-version` header.
+    OslcCoreVersion,
+
+    /// `overwrite` header.
+    Overwrite,
+
+    /// `ping-from` header.
+    PingFrom,
+
+    /// `ping-to` header.
+    PingTo,
+
+    /// `position` header.
+    Position,
+
+    /// `pragma` header.
+    Pragma,
+
+    /// `prefer` header.
+    Prefer,
+
+    /// `preference-applied` header.
+    PreferenceApplied,
+
+    /// `priority` header.
+    Priority,
+
+    /// `proxy-authenticate` header.
+    ProxyAuthenticate,
+
+    /// `proxy-authentication-info` header.
+    ProxyAuthenticationInfo,
+
+    /// `proxy-authorization` header.
+    ProxyAuthorization,
+
+    /// `proxy-status` header.
+    ProxyStatus,
+
+    /// `public-key-pins` header.
+    PublicKeyPins,
+
+    /// `public-key-pins-report-only` header.
+    PublicKeyPinsReportOnly,
+
+    /// `range` header.
+    Range,
+
+    /// `redirect-ref` header.
+    RedirectRef,
+
+    /// `referer` header.
+    Referer,
+
+    /// `refresh` header.
+    Refresh,
+
+    /// `replay-nonce` header.
+    ReplayNonce,
+
+    /// `repr-digest` header.
+    ReprDigest,
+
+    /// `retry-after` header.
+    RetryAfter,
+
+    /// `schedule-reply` header.
+    ScheduleReply,
+
+    /// `schedule-tag` header.
+    ScheduleTag,
+
+    /// `sec-purpose` header.
+    SecPurpose,
+
+    /// `sec-token-binding` header.
+    SecTokenBinding,
+
+    /// `sec-websocket-accept` header.
+    SecWebsocketAccept,
+
+    /// `sec-websocket-extensions` header.
+    SecWebsocketExtensions,
+
+    /// `sec-websocket-key` header.
+    SecWebsocketKey,
+
+    /// `sec-websocket-protocol` header.
+    SecWebsocketProtocol,
+
+    /// `sec-websocket-version` header.
+    SecWebsocketVersion,
+
+    /// `server` header.
+    Server,
+
+    /// `server-timing` header.
+    ServerTiming,
+
+    /// `set-cookie` header.
+    SetCookie,
+
+    /// `slug` header.
+    Slug,
+
+    /// `strict-transport-security` header.
+    StrictTransportSecurity,
+
+    /// `sunset` header.
+    Sunset,
+
+    /// `surrogate-capability` header.
+    SurrogateCapability,
+
+    /// `surrogate-control` header.
+    SurrogateControl,
+
+    /// `tcn` header.
+    Tcn,
+
+    /// `te` header.
+    Te,
+
+    /// `timeout` header.
+    Timeout,
+
+    /// `topic` header.
+    Topic,
+
+    /// `traceparent` header.
+    Traceparent,
+
+    /// `tracestate` header.
+    Tracestate,
+
+    /// `trailer` header.
+    Trailer,
+
+    /// `transfer-encoding` header.
+    TransferEncoding,
+
+    /// `ttl` header.
+    Ttl,
+
+    /// `upgrade` header.
+    Upgrade,
+
+    /// `urgency` header.
+    Urgency,
+
+    /// `user-agent` header.
+    UserAgent,
+
+    /// `variant-vary` header.
+    VariantVary,
+
+    /// `vary` header.
+    Vary,
+
+    /// `via` header.
+    Via,
+
+    /// `want-content-digest` header.
+    WantContentDigest,
+
+    /// `want-repr-digest` header.
+    WantReprDigest,
+
+    /// `warning` header.
+    Warning,
+
+    /// `www-authenticate` header.
+    WwwAuthenticate,
+
+    /// `x-content-type-options` header.
+    XContentTypeOptions,
+
+    /// `x-forwarded-for` header, which is non-standard.
+    XForwardedFor,
+
+    /// `x-forwarded-host` header, which is non-standard.
+    XForwardedHost,
+
+    /// `x-frame-options` header.
+    XFrameOptions,
+
+    /// `x-powered-by` header, which is non-standard.
+    XPoweredBy,
+
+    /// `x-webkit-csp` header, which is non-standard.
+    XWebkitCsp,
+
+    /// `x-xss-protection` header, which is non-standard.
+    XXssProtection
+}
+
+impl HeaderName {
+    /// Create a new `HeaderName` from a lower-cased header name.
+    ///
+    /// Returns `None` when the header name is not known.
+    ///
+    /// # Arguments
+    ///
+    /// **`name`**
+    ///
+    /// The lower-cased header name.
+    pub fn from_bytes(name: &[u8]) -> Option<HeaderName> {
+        Some(match name {
+            b"accept"                              => HeaderName::Accept,
+            b"accept-ch"                           => HeaderName::AcceptCh,
+            b"accept-charset"                      => HeaderName::AcceptCharset,
+            b"accept-encoding"                     => HeaderName::AcceptEncoding,
+            b"accept-language"                     => HeaderName::AcceptLanguage,
+            b"accept-patch"                        => HeaderName::AcceptPatch,
+            b"accept-post"                         => HeaderName::AcceptPost,
+            b"accept-ranges"                       => HeaderName::AcceptRanges,
+            b"access-control-allow-credentials"    => HeaderName::AccessControlAllowCredentials,
+            b"access-control-allow-headers"        => HeaderName::AccessControlAllowHeaders,
+            b"access-control-allow-methods"        => HeaderName::AccessControlAllowMethods,
+            b"access-control-allow-origin"         => HeaderName::AccessControlAllowOrigin,
+            b"access-control-expose-headers"       => HeaderName::AccessControlExposeHeaders,
+            b"access-control-max-age"              => HeaderName::AccessControlMaxAge,
+            b"access-control-request-headers"      => HeaderName::AccessControlRequestHeaders,
+            b"access-control-request-method"       => HeaderName::AccessControlRequestMethod,
+            b"age"                                 => HeaderName::Age,
+            b"allow"                               => HeaderName::Allow,
+            b"alpn"                                => HeaderName::Alpn,
+            b"alt-svc"                             => HeaderName::AltSvc,
+            b"alt-used"                            => HeaderName::AltUsed,
+            b"authentication-control"              => HeaderName::AuthenticationControl,
+            b"authentication-info"                 => HeaderName::AuthenticationInfo,
+            b"authorization"                       => HeaderName::Authorization,
+            b"cache-control"                       => HeaderName::CacheControl,
+            b"cache-status"                        => HeaderName::CacheStatus,
+            b"cal-managed-id"                      => HeaderName::CalManagedId,
+            b"caldav-timezones"                    => HeaderName::CaldavTimezones,
+            b"cdn-cache-control"                   => HeaderName::CdnCacheControl,
+            b"cdn-loop"                            => HeaderName::CdnLoop,
+            b"cert-not-after"                      => HeaderName::CertNotAfter,
+            b"cert-not-before"                     => HeaderName::CertNotBefore,
+            b"clear-site-data"                     => HeaderName::ClearSiteData,
+            b"close"                               => HeaderName::Close,
+            b"connection"                          => HeaderName::Connection,
+            b"content-digest"                      => HeaderName::ContentDigest,
+            b"content-disposition"                 => HeaderName::ContentDisposition,
+            b"content-encoding"                    => HeaderName::ContentEncoding,
+            b"content-language"                    => HeaderName::ContentLanguage,
+            b"content-length"                      => HeaderName::ContentLength,
+            b"content-location"                    => HeaderName::ContentLocation,
+            b"content-range"                       => HeaderName::ContentRange,
+            b"content-security-policy"             => HeaderName::ContentSecurityPolicy,
+            b"content-security-policy-report-only" => HeaderName::ContentSecurityPolicyReportOnly,
+            b"content-type"                        => HeaderName::ContentType,
+            b"cookie"                              => HeaderName::Cookie,
+            b"cross-origin-embedder-policy"        => HeaderName::CrossOriginEmbedderPolicy,
+            b"cross-origin-embedder-policy-report-only" => {
+                HeaderName::CrossOriginEmbedderPolicyReportOnly
+            },
+            b"cross-origin-opener-policy"          => HeaderName::CrossOriginOpenerPolicy,
+            b"cross-origin-opener-policy-report-only" => {
+                HeaderName::CrossOriginOpenerPolicyReportOnly
+            },
+            b"cross-origin-resource-policy"        => HeaderName::CrossOriginResourcePolicy,
+            b"dasl"                                => HeaderName::Dasl,
+            b"date"                                => HeaderName::Date,
+            b"dav"                                 => HeaderName::Dav,
+            b"delta-base"                          => HeaderName::DeltaBase,
+            b"depth"                               => HeaderName::Depth,
+            b"destination"                         => HeaderName::Destination,
+            b"early-data"                          => HeaderName::EarlyData,
+            b"etag"                                => HeaderName::Etag,
+            b"expect"                              => HeaderName::Expect,
+            b"expires"                             => HeaderName::Expires,
+            b"forwarded"                           => HeaderName::Forwarded,
+            b"from"                                => HeaderName::From,
+            b"hobareg"                             => HeaderName::Hobareg,
+            b"host"                                => HeaderName::Host,
+            b"if"                                  => HeaderName::If,
+            b"if-match"                            => HeaderName::IfMatch,
+            b"if-modified-since"                   => HeaderName::IfModifiedSince,
+            b"if-none-match"                       => HeaderName::IfNoneMatch,
+            b"if-range"                            => HeaderName::IfRange,
+            b"if-schedule-tag-match"               => HeaderName::IfScheduleTagMatch,
+            b"if-unmodified-since"                 => HeaderName::IfUnmodifiedSince,
+            b"im"                                  => HeaderName::Im,
+            b"include-referred-token-binding-id"   => HeaderName::IncludeReferredTokenBindingId,
+            b"keep-alive"                          => HeaderName::KeepAlive,
+            b"label"                               => HeaderName::Label,
+            b"last-event-id"                       => HeaderName::LastEventId,
+            b"last-modified"                       => HeaderName::LastModified,
+            b"link"                                => HeaderName::Link,
+            b"location"                            => HeaderName::Location,
+            b"lock-token"                          => HeaderName::LockToken,
+            b"max-forwards"                        => HeaderName::MaxForwards,
+            b"memento-datetime"                    => HeaderName::MementoDatetime,
+            b"meter"                               => HeaderName::Meter,
+            b"mime-version"                        => HeaderName::MimeVersion,
+            b"negotiate"                           => HeaderName::Negotiate,
+            b"odata-entityid"                      => HeaderName::OdataEntityid,
+            b"odata-isolation"                     => HeaderName::OdataIsolation,
+            b"odata-maxversion"                    => HeaderName::OdataMaxversion,
+            b"odata-version"                       => HeaderName::OdataVersion,
+            b"optional-www-authenticate"           => HeaderName::OptionalWwwAuthenticate,
+            b"ordering-type"                       => HeaderName::OrderingType,
+            b"origin"                              => HeaderName::Origin,
+            b"origin-agent-cluster"                => HeaderName::OriginAgentCluster,
+            b"oscore"                              => HeaderName::Oscore,
+            b"oslc-core-version"                   => HeaderName::OslcCoreVersion,
+            b"overwrite"                           => HeaderName::Overwrite,
+            b"ping-from"                           => HeaderName::PingFrom,
+            b"ping-to"                             => HeaderName::PingTo,
+            b"position"                            => HeaderName::Position,
+            b"pragma"                              => HeaderName::Pragma,
+            b"prefer"                              => HeaderName::Prefer,
+            b"preference-applied"                  => HeaderName::PreferenceApplied,
+            b"priority"                            => HeaderName::Priority,
+            b"proxy-authenticate"                  => HeaderName::ProxyAuthenticate,
+            b"proxy-authentication-info"           => HeaderName::ProxyAuthenticationInfo,
+            b"proxy-authorization"                 => HeaderName::ProxyAuthorization,
+            b"proxy-status"                        => HeaderName::ProxyStatus,
+            b"public-key-pins"                     => HeaderName::PublicKeyPins,
+            b"public-key-pins-report-only"         => HeaderName::PublicKeyPinsReportOnly,
+            b"range"                               => HeaderName::Range,
+            b"redirect-ref"                        => HeaderName::RedirectRef,
+            b"referer"                             => HeaderName::Referer,
+            b"refresh"                             => HeaderName::Refresh,
+            b"replay-nonce"                        => HeaderName::ReplayNonce,
+            b"repr-digest"                         => HeaderName::ReprDigest,
+            b"retry-after"                         => HeaderName::RetryAfter,
+            b"schedule-reply"                      => HeaderName::ScheduleReply,
+            b"schedule-tag"                        => HeaderName::ScheduleTag,
+            b"sec-purpose"                         => HeaderName::SecPurpose,
+            b"sec-token-binding"                   => HeaderName::SecTokenBinding,
+            b"sec-websocket-accept"                => HeaderName::SecWebsocketAccept,
+            b"sec-websocket-extensions"            => HeaderName::SecWebsocketExtensions,
+            b"sec-websocket-key"                   => HeaderName::SecWebsocketKey,
+            b"sec-websocket-protocol"              => HeaderName::SecWebsocketProtocol,
+            b"sec-websocket-version"               => HeaderName::SecWebsocketVersion,
+            b"server"                              => HeaderName::Server,
+            b"server-timing"                       => HeaderName::ServerTiming,
+            b"set-cookie"                          => HeaderName::SetCookie,
+            b"slug"                                => HeaderName::Slug,
+            b"strict-transport-security"           => HeaderName::StrictTransportSecurity,
+            b"sunset"                              => HeaderName::Sunset,
+            b"surrogate-capability"                => HeaderName::SurrogateCapability,
+            b"surrogate-control"                   => HeaderName::SurrogateControl,
+            b"tcn"                                 => HeaderName::Tcn,
+            b"te"                                  => HeaderName::Te,
+            b"timeout"                             => HeaderName::Timeout,
+            b"topic"                               => HeaderName::Topic,
+            b"traceparent"                         => HeaderName::Traceparent,
+            b"tracestate"                          => HeaderName::Tracestate,
+            b"trailer"                             => HeaderName::Trailer,
+            b"transfer-encoding"                   => HeaderName::TransferEncoding,
+            b"ttl"                                 => HeaderName::Ttl,
+            b"upgrade"                             => HeaderName::Upgrade,
+            b"urgency"                             => HeaderName::Urgency,
+            b"user-agent"                          => HeaderName::UserAgent,
+            b"variant-vary"                        => HeaderName::VariantVary,
+            b"vary"                                => HeaderName::Vary,
+            b"via"                                 => HeaderName::Via,
+            b"want-content-digest"                 => HeaderName::WantContentDigest,
+            b"want-repr-digest"                    => HeaderName::WantReprDigest,
+            b"warning"                             => HeaderName::Warning,
+            b"www-authenticate"                    => HeaderName::WwwAuthenticate,
+            b"x-content-type-options"              => HeaderName::XContentTypeOptions,
+            b"x-forwarded-for"                     => HeaderName::XForwardedFor,
+            b"x-forwarded-host"                    => HeaderName::XForwardedHost,
+            b"x-frame-options"                     => HeaderName::XFrameOptions,
+            b"x-powered-by"                        => HeaderName::XPoweredBy,
+            b"x-webkit-csp"                        => HeaderName::XWebkitCsp,
+            b"x-xss-protection"                    => HeaderName::XXssProtection,
+            _                                      => return None
+        })
+    }
+
+    /// Retrieve the lower-cased header name.
+    pub fn as_bytes(&self) -> &'static [u8] {
+        match *self {
+            HeaderName::Accept                          => b"accept",
+            HeaderName::AcceptCh                        => b"accept-ch",
+            HeaderName::AcceptCharset                   => b"accept-charset",
+            HeaderName::AcceptEncoding                  => b"accept-encoding",
+            HeaderName::AcceptLanguage                  => b"accept-language",
+            HeaderName::AcceptPatch                     => b"accept-patch",
+            HeaderName::AcceptPost                      => b"accept-post",
+            HeaderName::AcceptRanges                    => b"accept-ranges",
+            HeaderName::AccessControlAllowCredentials   => b"access-control-allow-credentials",
+            HeaderName::AccessControlAllowHeaders       => b"access-control-allow-headers",
+            HeaderName::AccessControlAllowMethods       => b"access-control-allow-methods",
+            HeaderName::AccessControlAllowOrigin        => b"access-control-allow-origin",
+            HeaderName::AccessControlExposeHeaders      => b"access-control-expose-headers",
+            HeaderName::AccessControlMaxAge             => b"access-control-max-age",
+            HeaderName::AccessControlRequestHeaders     => b"access-control-request-headers",
+            HeaderName::AccessControlRequestMethod      => b"access-control-request-method",
+            HeaderName::Age                             => b"age",
+            HeaderName::Allow                           => b"allow",
+            HeaderName::Alpn                            => b"alpn",
+            HeaderName::AltSvc                          => b"alt-svc",
+            HeaderName::AltUsed                         => b"alt-used",
+            HeaderName::AuthenticationControl           => b"authentication-control",
+            HeaderName::AuthenticationInfo              => b"authentication-info",
+            HeaderName::Authorization                   => b"authorization",
+            HeaderName::CacheControl                    => b"cache-control",
+            HeaderName::CacheStatus                     => b"cache-status",
+            HeaderName::CalManagedId                    => b"cal-managed-id",
+            HeaderName::CaldavTimezones                 => b"caldav-timezones",
+            HeaderName::CdnCacheControl                 => b"cdn-cache-control",
+            HeaderName::CdnLoop                         => b"cdn-loop",
+            HeaderName::CertNotAfter                    => b"cert-not-after",
+            HeaderName::CertNotBefore                   => b"cert-not-before",
+            HeaderName::ClearSiteData                   => b"clear-site-data",
+            HeaderName::Close                           => b"close",
+            HeaderName::Connection                      => b"connection",
+            HeaderName::ContentDigest                   => b"content-digest",
+            HeaderName::ContentDisposition              => b"content-disposition",
+            HeaderName::ContentEncoding                 => b"content-encoding",
+            HeaderName::ContentLanguage                 => b"content-language",
+            HeaderName::ContentLength                   => b"content-length",
+            HeaderName::ContentLocation                 => b"content-location",
+            HeaderName::ContentRange                    => b"content-range",
+            HeaderName::ContentSecurityPolicy           => b"content-security-policy",
+            HeaderName::ContentSecurityPolicyReportOnly => b"content-security-policy-report-only",
+            HeaderName::ContentType                     => b"content-type",
+            HeaderName::Cookie                          => b"cookie",
+            HeaderName::CrossOriginEmbedderPolicy       => b"cross-origin-embedder-policy",
+            HeaderName::CrossOriginEmbedderPolicyReportOnly => {
+                b"cross-origin-embedder-policy-report-only"
+            },
+            HeaderName::CrossOriginOpenerPolicy         => b"cross-origin-opener-policy",
+            HeaderName::CrossOriginOpenerPolicyReportOnly => {
+                b"cross-origin-opener-policy-report-only"
+            },
+            HeaderName::CrossOriginResourcePolicy       => b"cross-origin-resource-policy",
+            HeaderName::Dasl                            => b"dasl",
+            HeaderName::Date                            => b"date",
+            HeaderName::Dav                             => b"dav",
+            HeaderName::DeltaBase                       => b"delta-base",
+            HeaderName::Depth                           => b"depth",
+            HeaderName::Destination                     => b"destination",
+            HeaderName::EarlyData                       => b"early-data",
+            HeaderName::Etag                            => b"etag",
+            HeaderName::Expect                          => b"expect",
+            HeaderName::Expires                         => b"expires",
+            HeaderName::Forwarded                       => b"forwarded",
+            HeaderName::From                            => b"from",
+            HeaderName::Hobareg                         => b"hobareg",
+            HeaderName::Host                            => b"host",
+            HeaderName::If                              => b"if",
+            HeaderName::IfMatch                         => b"if-match",
+            HeaderName::IfModifiedSince                 => b"if-modified-since",
+            HeaderName::IfNoneMatch                     => b"if-none-match",
+            HeaderName::IfRange                         => b"if-range",
+            HeaderName::IfScheduleTagMatch              => b"if-schedule-tag-match",
+            HeaderName::IfUnmodifiedSince               => b"if-unmodified-since",
+            HeaderName::Im                              => b"im",
+            HeaderName::IncludeReferredTokenBindingId   => b"include-referred-token-binding-id",
+            HeaderName::KeepAlive                       => b"keep-alive",
+            HeaderName::Label                           => b"label",
+            HeaderName::LastEventId                     => b"last-event-id",
+            HeaderName::LastModified                    => b"last-modified",
+            HeaderName::Link                            => b"link",
+            HeaderName::Location                        => b"location",
+            HeaderName::LockToken                       => b"lock-token",
+            HeaderName::MaxForwards                     => b"max-forwards",
+            HeaderName::MementoDatetime                 => b"memento-datetime",
+            HeaderName::Meter                           => b"meter",
+            HeaderName::MimeVersion                     => b"mime-version",
+            HeaderName::Negotiate                       => b"negotiate",
+            HeaderName::OdataEntityid                   => b"odata-entityid",
+            HeaderName::OdataIsolation                  => b"odata-isolation",
+            HeaderName::OdataMaxversion                 => b"odata-maxversion",
+            HeaderName::OdataVersion                    => b"odata-version",
+            HeaderName::OptionalWwwAuthenticate         => b"optional-www-authenticate",
+            HeaderName::OrderingType                    => b"ordering-type",
+            HeaderName::Origin                          => b"origin",
+            HeaderName::OriginAgentCluster              => b"origin-agent-cluster",
+            HeaderName::Oscore                          => b"oscore",
+            HeaderName::OslcCoreVersion                 => b"oslc-core-version",
+            HeaderName::Overwrite                       => b"overwrite",
+            HeaderName::PingFrom                        => b"ping-from",
+            HeaderName::PingTo                          => b"ping-to",
+            HeaderName::Position                        => b"position",
+            HeaderName::Pragma                          => b"pragma",
+            HeaderName::Prefer                          => b"prefer",
+            HeaderName::PreferenceApplied               => b"preference-applied",
+            HeaderName::Priority                        => b"priority",
+            HeaderName::ProxyAuthenticate               => b"proxy-authenticate",
+            HeaderName::ProxyAuthenticationInfo         => b"proxy-authentication-info",
+            HeaderName::ProxyAuthorization              => b"proxy-authorization",
+            HeaderName::ProxyStatus                     => b"proxy-status",
+            HeaderName::PublicKeyPins                   => b"public-key-pins",
+            HeaderName::PublicKeyPinsReportOnly         => b"public-key-pins-report-only",
+            HeaderName::Range                           => b"range",
+            HeaderName::RedirectRef                     => b"redirect-ref",
+            HeaderName::Referer                         => b"referer",
+            HeaderName::Refresh                         => b"refresh",
+            HeaderName::ReplayNonce                     => b"replay-nonce",
+            HeaderName::ReprDigest                      => b"repr-digest",
+            HeaderName::RetryAfter                      => b"retry-after",
+            HeaderName::ScheduleReply                   => b"schedule-reply",
+            HeaderName::ScheduleTag                     => b"schedule-tag",
+            HeaderName::SecPurpose                      => b"sec-purpose",
+            HeaderName::SecTokenBinding                 => b"sec-token-binding",
+            HeaderName::SecWebsocketAccept              => b"sec-websocket-accept",
+            HeaderName::SecWebsocketExtensions          => b"sec-websocket-extensions",
+            HeaderName::SecWebsocketKey                 => b"sec-websocket-key",
+            HeaderName::SecWebsocketProtocol            => b"sec-websocket-protocol",
+            HeaderName::SecWebsocketVersion             => b"sec-websocket-version",
+            HeaderName::Server                          => b"server",
+            HeaderName::ServerTiming                    => b"server-timing",
+            HeaderName::SetCookie                       => b"set-cookie",
+            HeaderName::Slug                            => b"slug",
+            HeaderName::StrictTransportSecurity         => b"strict-transport-security",
+            HeaderName::Sunset                          => b"sunset",
+            HeaderName::SurrogateCapability             => b"surrogate-capability",
+            HeaderName::SurrogateControl                => b"surrogate-control",
+            HeaderName::Tcn                             => b"tcn",
+            HeaderName::Te                              => b"te",
+            HeaderName::Timeout                         => b"timeout",
+            HeaderName::Topic                           => b"topic",
+            HeaderName::Traceparent                     => b"traceparent",
+            HeaderName::Tracestate                      => b"tracestate",
+            HeaderName::Trailer                         => b"trailer",
+            HeaderName::TransferEncoding                => b"transfer-encoding",
+            HeaderName::Ttl                             => b"ttl",
+            HeaderName::Upgrade                         => b"upgrade",
+            HeaderName::Urgency                         => b"urgency",
+            HeaderName::UserAgent                       => b"user-agent",
+            HeaderName::VariantVary                     => b"variant-vary",
+            HeaderName::Vary                            => b"vary",
+            HeaderName::Via                             => b"via",
+            HeaderName::WantContentDigest               => b"want-content-digest",
+            HeaderName::WantReprDigest                  => b"want-repr-digest",
+            HeaderName::Warning                         => b"warning",
+            HeaderName::WwwAuthenticate                 => b"www-authenticate",
+            HeaderName::XContentTypeOptions             => b"x-content-type-options",
+            HeaderName::XForwardedFor                   => b"x-forwarded-for",
+            HeaderName::XForwardedHost                  => b"x-forwarded-host",
+            HeaderName::XFrameOptions                   => b"x-frame-options",
+            HeaderName::XPoweredBy                      => b"x-powered-by",
+            HeaderName::XWebkitCsp                      => b"x-webkit-csp",
+            HeaderName::XXssProtection                  => b"x-xss-protection"
+        }
+    }
+}
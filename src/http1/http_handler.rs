@@ -14,9 +14,16 @@
 // | limitations under the License.                                                                |
 // +-----------------------------------------------------------------------------------------------+
 
+use fsm::Action;
+use http1::header_name::HeaderName;
+use http1::method::Method;
+
 /// Type that handles HTTP/1.x parser events.
+///
+/// `E` is the error type that callbacks abort parsing with, which is surfaced as
+/// `ParserError::Handler`.
 #[allow(unused_variables)]
-pub trait HttpHandler {
+pub trait HttpHandler<E = ()> {
     /// Retrieve the content length.
     ///
     /// **Called When::**
@@ -26,14 +33,32 @@ pub trait HttpHandler {
         None
     }
 
+    /// Callback that is executed when body data has been located.
+    ///
+    /// *Note:* This may be executed multiple times in order to supply the entire segment.
+    ///
+    /// **Returns:**
+    ///
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    ///
+    /// **Called When:**
+    ///
+    /// During content length parsing, and end of input delimited parsing.
+    fn on_body(&mut self, data: &[u8]) -> Action<E> {
+        Action::Continue
+    }
+
     /// Callback that is executed when body parsing has completed successfully.
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_body_finished(&mut self) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_body_finished(&mut self) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a new chunk section has been located. This is executed
@@ -41,10 +66,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_chunk_begin(&mut self) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_chunk_begin(&mut self) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when chunk encoded data has been located.
@@ -53,10 +79,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_chunk_data(&mut self, data: &[u8]) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_chunk_data(&mut self, data: &[u8]) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when parsing an individual chunk extension name/value pair has
@@ -64,10 +91,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_chunk_extension_finished(&mut self) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_chunk_extension_finished(&mut self) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a chunk extension name has been located.
@@ -76,10 +104,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_chunk_extension_name(&mut self, name: &[u8]) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_chunk_extension_name(&mut self, name: &[u8]) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a chunk extension value has been located.
@@ -88,30 +117,33 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_chunk_extension_value(&mut self, value: &[u8]) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_chunk_extension_value(&mut self, value: &[u8]) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when parsing all chunk extensions has completed successfully.
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_chunk_extensions_finished(&mut self) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_chunk_extensions_finished(&mut self) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a chunk length has been located.
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_chunk_length(&mut self, size: usize) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_chunk_length(&mut self, size: u64) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a header name has been located.
@@ -120,15 +152,16 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
     ///
     /// **Called When:**
     ///
     /// During head parsing, multipart parsing of headers for each piece of data, or at the end of
     /// chunk encoded data when trailers are present.
-    fn on_header_name(&mut self, name: &[u8]) -> bool {
-        true
+    fn on_header_name(&mut self, name: &[u8]) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a header value has been located.
@@ -137,30 +170,32 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
     ///
     /// **Called When:**
     ///
     /// During head parsing, multipart parsing of headers for each piece of data, or at the end of
     /// chunk encoded data when trailers are present.
-    fn on_header_value(&mut self, value: &[u8]) -> bool {
-        true
+    fn on_header_value(&mut self, value: &[u8]) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when header parsing has completed successfully.
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
     ///
     /// **Called When:**
     ///
     /// During head parsing, multipart parsing of headers for each piece of data, or at the end of
     /// chunk encoded data when trailers are present.
-    fn on_headers_finished(&mut self) -> bool {
-        true
+    fn on_headers_finished(&mut self) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when parsing the initial request/response line has completed
@@ -168,10 +203,96 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_initial_finished(&mut self) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_initial_finished(&mut self) -> Action<E> {
+        Action::Continue
+    }
+
+    /// Callback that is executed when an interim response has been parsed.
+    ///
+    /// **Arguments:**
+    ///
+    /// **`code`**
+    ///
+    /// The status code of the interim response, such as `100 Continue` or `103 Early Hints`.
+    ///
+    /// **Returns:**
+    ///
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    ///
+    /// **Called When:**
+    ///
+    /// During message parsing, after the headers of a `1xx` response other than
+    /// `101 Switching Protocols`. Parsing then continues with the final response.
+    fn on_interim(&mut self, code: u16) -> Action<E> {
+        Action::Continue
+    }
+
+    /// Callback that is executed when a header name has been parsed, and it is a known header
+    /// name.
+    ///
+    /// **Arguments:**
+    ///
+    /// **`name`**
+    ///
+    /// The known header name.
+    ///
+    /// **Returns:**
+    ///
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    ///
+    /// **Called When:**
+    ///
+    /// After the entire header name has been supplied to `on_header_name()`, or to
+    /// `on_trailer_name()` while trailers are being parsed, and prior to the header value. This is
+    /// also executed for multipart headers. Header names that are split across multiple calls to
+    /// `Parser::resume()` are recognized as well.
+    fn on_known_header(&mut self, name: HeaderName) -> Action<E> {
+        Action::Continue
+    }
+
+    /// Callback that is executed when a new message has been located.
+    ///
+    /// **Returns:**
+    ///
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    ///
+    /// **Called When:**
+    ///
+    /// During message parsing, prior to the request or response line of each message.
+    fn on_message_begin(&mut self) -> Action<E> {
+        Action::Continue
+    }
+
+    /// Callback that is executed when a message has been parsed, including the body.
+    ///
+    /// **Arguments:**
+    ///
+    /// **`keep_alive`**
+    ///
+    /// Indicates that the connection persists, as determined by the HTTP version and the
+    /// `Connection` header.
+    ///
+    /// **Returns:**
+    ///
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    ///
+    /// **Called When:**
+    ///
+    /// During message parsing, after the body of each message has been parsed, or after the head
+    /// when the message has no body.
+    fn on_message_finished(&mut self, keep_alive: bool) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a request method has been located.
@@ -180,10 +301,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_method(&mut self, method: &[u8]) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_method(&mut self, method: &[u8]) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a new multipart section has been located. This is executed
@@ -191,10 +313,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_multipart_begin(&mut self) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_multipart_begin(&mut self) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when multipart data has been located.
@@ -203,10 +326,62 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_multipart_data(&mut self, data: &[u8]) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_multipart_data(&mut self, data: &[u8]) -> Action<E> {
+        Action::Continue
+    }
+
+    /// Callback that is executed when data has been processed, and raw data passthrough has been
+    /// enabled with `Parser::set_raw_passthrough()`.
+    ///
+    /// *Note:* This may be executed multiple times in order to supply the entire segment.
+    ///
+    /// **Arguments:**
+    ///
+    /// **`data`**
+    ///
+    /// The data exactly as it was received, including line endings, white space, and the original
+    /// case of header names.
+    ///
+    /// **Returns:**
+    ///
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    ///
+    /// **Called When:**
+    ///
+    /// After the callbacks for the data being supplied have been executed. Each byte is supplied
+    /// exactly once, and in order, so the complete line of each header has been supplied before
+    /// the name of the next header.
+    fn on_raw(&mut self, data: &[u8]) -> Action<E> {
+        Action::Continue
+    }
+
+    /// Callback that is executed when the entire request method has been parsed.
+    ///
+    /// **Arguments:**
+    ///
+    /// **`method`**
+    ///
+    /// The request method. Methods that are not recognized are supplied as
+    /// `Method::Extension`.
+    ///
+    /// **Returns:**
+    ///
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    ///
+    /// **Called When:**
+    ///
+    /// After the request method has been parsed, and prior to the request URL. When allowed
+    /// methods have been set with `Parser::set_allowed_methods()`, this is only executed for
+    /// allowed methods.
+    fn on_request_method(&mut self, method: Method) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a response status has been located.
@@ -215,20 +390,96 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_status(&mut self, status: &[u8]) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_status(&mut self, status: &[u8]) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a response status code has been located.
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_status_code(&mut self, code: u16) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_status_code(&mut self, code: u16) -> Action<E> {
+        Action::Continue
+    }
+
+    /// Callback that is executed when a trailer name has been located.
+    ///
+    /// By default, this executes `on_header_name()`.
+    ///
+    /// *Note:* This may be executed multiple times in order to supply the entire segment.
+    ///
+    /// **Returns:**
+    ///
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    ///
+    /// **Called When:**
+    ///
+    /// At the end of chunk encoded data when trailers are present.
+    fn on_trailer_name(&mut self, name: &[u8]) -> Action<E> {
+        self.on_header_name(name)
+    }
+
+    /// Callback that is executed when a trailer value has been located.
+    ///
+    /// By default, this executes `on_header_value()`.
+    ///
+    /// *Note:* This may be executed multiple times in order to supply the entire segment.
+    ///
+    /// **Returns:**
+    ///
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    ///
+    /// **Called When:**
+    ///
+    /// At the end of chunk encoded data when trailers are present.
+    fn on_trailer_value(&mut self, value: &[u8]) -> Action<E> {
+        self.on_header_value(value)
+    }
+
+    /// Callback that is executed when trailer parsing has completed successfully.
+    ///
+    /// By default, this executes `on_headers_finished()`.
+    ///
+    /// **Returns:**
+    ///
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    ///
+    /// **Called When:**
+    ///
+    /// At the end of chunk encoded data, whether or not trailers are present.
+    fn on_trailers_finished(&mut self) -> Action<E> {
+        self.on_headers_finished()
+    }
+
+    /// Callback that is executed when the head has been parsed, and the connection switches to
+    /// another protocol.
+    ///
+    /// **Returns:**
+    ///
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    ///
+    /// **Called When:**
+    ///
+    /// After `on_headers_finished()`, when a request contains `Connection: upgrade` along with an
+    /// `Upgrade` header, when a request method is `CONNECT`, when a response status code is `101`,
+    /// or when a response to a `CONNECT` request has a `2xx` status code. Parsing then exits with
+    /// [`Success::Upgrade`](../fsm/enum.Success.html#variant.Upgrade).
+    fn on_upgrade(&mut self) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a request URL has been located.
@@ -237,20 +488,22 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_url(&mut self, url: &[u8]) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_url(&mut self, url: &[u8]) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a new URL encoded name/value pair has been located.
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_url_encoded_begin(&mut self) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_url_encoded_begin(&mut self) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a URL encoded name has been located.
@@ -259,10 +512,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_url_encoded_name(&mut self, name: &[u8]) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_url_encoded_name(&mut self, name: &[u8]) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a URL encoded value has been located.
@@ -271,10 +525,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_url_encoded_value(&mut self, value: &[u8]) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_url_encoded_value(&mut self, value: &[u8]) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when the HTTP version has been located during the initial request
@@ -282,9 +537,30 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_version(&mut self, major: u16, minor: u16) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_version(&mut self, major: u16, minor: u16) -> Action<E> {
+        Action::Continue
+    }
+
+    /// Set the absolute offset of the data that is supplied to the next callback.
+    ///
+    /// The offset is the amount of bytes processed by `Parser` prior to the data, which allows
+    /// handlers that retain the entire input to store the position of the data instead of copying
+    /// it.
+    ///
+    /// **Arguments:**
+    ///
+    /// **`offset`**
+    ///
+    /// The absolute offset. Data that does not appear verbatim in the stream, such as lower-cased
+    /// header names and decoded URL encoded data, is given the offset of the bytes it was produced
+    /// from.
+    ///
+    /// **Called When:**
+    ///
+    /// Prior to each callback that supplies data.
+    fn set_offset(&mut self, offset: usize) {
     }
 }
@@ -0,0 +1,103 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+//! HTTP 1.x parser limits.
+
+/// Parser limits.
+///
+/// Limits protect against malicious or malformed data that would otherwise be parsed
+/// indefinitely. Each limit defaults to `usize::MAX`, which means no limit is enforced. Update the
+/// limits that apply, and pass them to `Parser::set_limits()`.
+///
+/// # Examples
+///
+/// ```
+/// use http_box::http1::Limits;
+///
+/// let limits = Limits{
+///     max_headers:    100,
+///     max_url_length: 8192,
+///     ..Limits::default()
+/// };
+///
+/// assert_eq!(limits.max_headers, 100);
+/// ```
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct Limits {
+    /// Maximum length of all chunk extensions of a single chunk, in bytes.
+    ///
+    /// The length is measured from the byte following the first `;` delimiter, through the CR that
+    /// ends the chunk length line.
+    /// Exceeding this returns `ErrorKind::ChunkExtensionTooLong`.
+    pub max_chunk_extension_length: usize,
+
+    /// Maximum size of a single chunk, in bytes.
+    ///
+    /// Exceeding this returns `ErrorKind::ChunkTooLarge`.
+    pub max_chunk_size: u64,
+
+    /// Maximum total length of the head, in bytes.
+    ///
+    /// This includes the initial line, the headers, and the trailing CRLF sequence that ends the
+    /// head. Exceeding this returns `ErrorKind::HeadTooLarge`.
+    pub max_head_size: usize,
+
+    /// Maximum length of a single header name, in bytes.
+    ///
+    /// Exceeding this returns `ErrorKind::HeaderNameTooLong`.
+    pub max_header_name_length: usize,
+
+    /// Maximum length of a single header value, in bytes.
+    ///
+    /// Exceeding this returns `ErrorKind::HeaderValueTooLong`.
+    pub max_header_value_length: usize,
+
+    /// Maximum header count of the head, or of the trailers.
+    ///
+    /// Exceeding this returns `ErrorKind::TooManyHeaders`.
+    pub max_headers: usize,
+
+    /// Maximum length of the request method, in bytes.
+    ///
+    /// Exceeding this returns `ErrorKind::MethodTooLong`.
+    pub max_method_length: usize,
+
+    /// Maximum header count of a single multipart part.
+    ///
+    /// Exceeding this returns `ErrorKind::TooManyHeaders`.
+    pub max_multipart_headers: usize,
+
+    /// Maximum length of the request URL, in bytes.
+    ///
+    /// Exceeding this returns `ErrorKind::UrlTooLong`.
+    pub max_url_length: usize
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits{
+            max_chunk_extension_length: usize::MAX,
+            max_chunk_size:             u64::MAX,
+            max_head_size:              usize::MAX,
+            max_header_name_length:     usize::MAX,
+            max_header_value_length:    usize::MAX,
+            max_headers:                usize::MAX,
+            max_method_length:          usize::MAX,
+            max_multipart_headers:      usize::MAX,
+            max_url_length:             usize::MAX
+        }
+    }
+}
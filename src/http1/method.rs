@@ -0,0 +1,134 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+//! HTTP 1.x request methods.
+
+/// Request method.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Method<'a> {
+    /// `CONNECT` method.
+    Connect,
+
+    /// `COPY` method, defined by WebDAV.
+    Copy,
+
+    /// `DELETE` method.
+    Delete,
+
+    /// Extension method that is not otherwise recognized.
+    Extension(&'a [u8]),
+
+    /// `GET` method.
+    Get,
+
+    /// `HEAD` method.
+    Head,
+
+    /// `LOCK` method, defined by WebDAV.
+    Lock,
+
+    /// `MKCOL` method, defined by WebDAV.
+    Mkcol,
+
+    /// `MOVE` method, defined by WebDAV.
+    Move,
+
+    /// `OPTIONS` method.
+    Options,
+
+    /// `PATCH` method.
+    Patch,
+
+    /// `POST` method.
+    Post,
+
+    /// `PRI` method, which begins the HTTP/2 connection preface.
+    Pri,
+
+    /// `PROPFIND` method, defined by WebDAV.
+    Propfind,
+
+    /// `PUT` method.
+    Put,
+
+    /// `QUERY` method.
+    Query,
+
+    /// `TRACE` method.
+    Trace,
+
+    /// `UNLOCK` method, defined by WebDAV.
+    Unlock
+}
+
+impl<'a> Method<'a> {
+    /// Create a new `Method` from its name.
+    ///
+    /// Method names are case-sensitive, and names that are not recognized are returned as
+    /// `Method::Extension`.
+    ///
+    /// # Arguments
+    ///
+    /// **`method`**
+    ///
+    /// The method name.
+    pub fn from_bytes(method: &'a [u8]) -> Method<'a> {
+        match method {
+            b"CONNECT"  => Method::Connect,
+            b"COPY"     => Method::Copy,
+            b"DELETE"   => Method::Delete,
+            b"GET"      => Method::Get,
+            b"HEAD"     => Method::Head,
+            b"LOCK"     => Method::Lock,
+            b"MKCOL"    => Method::Mkcol,
+            b"MOVE"     => Method::Move,
+            b"OPTIONS"  => Method::Options,
+            b"PATCH"    => Method::Patch,
+            b"POST"     => Method::Post,
+            b"PRI"      => Method::Pri,
+            b"PROPFIND" => Method::Propfind,
+            b"PUT"      => Method::Put,
+            b"QUERY"    => Method::Query,
+            b"TRACE"    => Method::Trace,
+            b"UNLOCK"   => Method::Unlock,
+            _           => Method::Extension(method)
+        }
+    }
+
+    /// Retrieve the method name.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        match *self {
+            Method::Connect           => b"CONNECT",
+            Method::Copy              => b"COPY",
+            Method::Delete            => b"DELETE",
+            Method::Extension(method) => method,
+            Method::Get               => b"GET",
+            Method::Head              => b"HEAD",
+            Method::Lock              => b"LOCK",
+            Method::Mkcol             => b"MKCOL",
+            Method::Move              => b"MOVE",
+            Method::Options           => b"OPTIONS",
+            Method::Patch             => b"PATCH",
+            Method::Post              => b"POST",
+            Method::Pri               => b"PRI",
+            Method::Propfind          => b"PROPFIND",
+            Method::Put               => b"PUT",
+            Method::Query             => b"QUERY",
+            Method::Trace             => b"TRACE",
+            Method::Unlock            => b"UNLOCK"
+        }
+    }
+}
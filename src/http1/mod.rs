@@ -16,17 +16,43 @@
 
 //! HTTP/1.x parser, errors, traits, and types.
 
+mod chunked_encoder;
+mod collector;
+mod framing;
+mod head;
+mod header_name;
 mod http_handler;
+mod limits;
+mod method;
+mod multipart_encoder;
 mod parser;
 mod parser_error;
+mod parser_mode;
 mod parser_state;
 mod parser_type;
+mod writer;
 
 #[cfg(test)]
 mod test;
 
+pub use http1::chunked_encoder::ChunkedEncoder;
+pub use http1::collector::{ Body,
+                            Collector,
+                            CollectorError,
+                            CollectorLimits,
+                            HeaderMap,
+                            Part,
+                            Request,
+                            Response };
+pub use http1::head::{ MAX_HEADERS, Parsed, RequestHead, ResponseHead };
+pub use http1::header_name::HeaderName;
 pub use http1::http_handler::HttpHandler;
+pub use http1::limits::Limits;
+pub use http1::method::Method;
+pub use http1::multipart_encoder::MultipartEncoder;
 pub use http1::parser::Parser;
-pub use http1::parser_error::ParserError;
+pub use http1::parser_error::{ ErrorKind, ParserError };
+pub use http1::parser_mode::ParserMode;
 pub use http1::parser_state::{ ParserState, State };
 pub use http1::parser_type::ParserType;
+pub use http1::writer::{ WriteError, Writer };
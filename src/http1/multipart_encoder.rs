@@ -0,0 +1,419 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+//! HTTP 1.x multipart/form-data encoding.
+
+use byte::is_quoted_header_field;
+use http1::writer::{ WriteError, check_header };
+
+use std::collections::hash_map::RandomState;
+use std::hash::{ BuildHasher, Hasher };
+use std::io::{ self, Read, Write };
+use std::time::{ SystemTime, UNIX_EPOCH };
+
+/// Bytes that random boundaries are made of.
+const BOUNDARY_CHARS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Boundary prefix.
+const BOUNDARY_PREFIX: &[u8] = b"HttpBoxBoundary";
+
+/// Part of the body that is written next.
+#[derive(Clone,Copy,Debug,PartialEq)]
+enum Position {
+    /// Part data.
+    Data,
+
+    /// Nothing has been written.
+    Start,
+
+    /// Nothing, since the body has been finished.
+    Finished
+}
+
+/// Generate a random boundary.
+///
+/// Each boundary contains 32 random alphanumeric bytes, which makes it very unlikely to be found
+/// within the data.
+fn random_boundary() -> Vec<u8> {
+    let mut boundary = BOUNDARY_PREFIX.to_vec();
+    let state        = RandomState::new();
+    let nanos        = SystemTime::now().duration_since(UNIX_EPOCH)
+                                        .map(|duration| duration.subsec_nanos())
+                                        .unwrap_or(0);
+
+    for n in 0..4 {
+        let mut hasher = state.build_hasher();
+
+        hasher.write_u32(nanos);
+        hasher.write_u32(n);
+
+        let mut value = hasher.finish();
+
+        for _ in 0..8 {
+            boundary.push(BOUNDARY_CHARS[(value % BOUNDARY_CHARS.len() as u64) as usize]);
+
+            value /= BOUNDARY_CHARS.len() as u64;
+        }
+    }
+
+    boundary
+}
+
+/// Write `value` as a quoted string, with `"`, CR, and LF percent-encoded, and `\` escaped.
+///
+/// This is the encoding that RFC 7578 and the WHATWG HTML standard use for form data names and
+/// file names, except that `\` is escaped as a quoted pair, because recipients such as
+/// `FieldIterator` treat it as the start of one.
+fn write_quoted<W: Write>(writer: &mut W, value: &[u8]) -> io::Result<()> {
+    let mut start = 0;
+
+    writer.write_all(b"\"")?;
+
+    for (index, byte) in value.iter().enumerate() {
+        let encoded: &[u8] = match *byte {
+            b'"'  => b"%22",
+            b'\\' => b"\\\\",
+            b'\r' => b"%0D",
+            b'\n' => b"%0A",
+            _     => continue
+        };
+
+        writer.write_all(&value[start..index])?;
+        writer.write_all(encoded)?;
+
+        start = index + 1;
+    }
+
+    writer.write_all(&value[start..])?;
+    writer.write_all(b"\"")
+}
+
+/// HTTP 1.x multipart/form-data encoder.
+///
+/// Wraps any `std::io::Write` implementation, and writes a multipart/form-data body, as described
+/// by [RFC 7578](https://tools.ietf.org/html/rfc7578). Each part begins with a
+/// `Content-Disposition` header that contains the field name, and optionally a file name, along
+/// with an optional `Content-Type` header. Part data can be written in segments, or streamed from
+/// any `std::io::Read` implementation. The encoded body can be parsed by a `Parser` that has been
+/// initialized with `Parser::init_multipart()`.
+///
+/// A random boundary is generated for each encoder, and `MultipartEncoder::content_type()`
+/// supplies the matching `Content-Type` header value. Part data is compared against the boundary
+/// as it's written, and `WriteError::BoundaryInData` is returned in the unlikely event that it
+/// contains the boundary.
+///
+/// # Examples
+///
+/// ```
+/// use http_box::http1::MultipartEncoder;
+///
+/// let mut encoder = MultipartEncoder::new(Vec::new());
+///
+/// encoder.field(b"name", b"Bob").unwrap();
+/// encoder.file(b"upload", b"hello.txt", b"text/plain", &mut &b"Hello, world!"[..]).unwrap();
+/// encoder.finish().unwrap();
+///
+/// assert!(encoder.content_type().starts_with(b"multipart/form-data; boundary="));
+/// ```
+pub struct MultipartEncoder<W: Write> {
+    /// Boundary.
+    boundary: Vec<u8>,
+
+    /// Delimiter that precedes each part, which is a CRLF, two hyphens, and the boundary.
+    delimiter: Vec<u8>,
+
+    /// Part of the body that is written next.
+    position: Position,
+
+    /// Trailing bytes of the current part, which are compared against the delimiter along with
+    /// the next data.
+    tail: Vec<u8>,
+
+    /// Underlying writer.
+    writer: W
+}
+
+impl<W: Write> MultipartEncoder<W> {
+    /// Create a new `MultipartEncoder` with a random boundary.
+    ///
+    /// # Arguments
+    ///
+    /// **`writer`**
+    ///
+    /// The underlying writer.
+    pub fn new(writer: W) -> MultipartEncoder<W> {
+        let boundary      = random_boundary();
+        let mut delimiter = b"\r\n--".to_vec();
+
+        delimiter.extend_from_slice(&boundary);
+
+        MultipartEncoder{
+            boundary,
+            delimiter,
+            position: Position::Start,
+            tail:     Vec::new(),
+            writer
+        }
+    }
+
+    /// Retrieve the boundary.
+    pub fn boundary(&self) -> &[u8] {
+        &self.boundary
+    }
+
+    /// Retrieve the `Content-Type` header value, including the boundary.
+    pub fn content_type(&self) -> Vec<u8> {
+        let mut content_type = b"multipart/form-data; boundary=".to_vec();
+
+        content_type.extend_from_slice(&self.boundary);
+        content_type
+    }
+
+    /// Stream part data from `reader` until it has been exhausted.
+    ///
+    /// Returns the amount of bytes that have been written.
+    ///
+    /// # Arguments
+    ///
+    /// **`reader`**
+    ///
+    /// The reader.
+    pub fn copy_from<R: Read>(&mut self, reader: &mut R) -> Result<u64, WriteError> {
+        let mut buffer = [0; 8192];
+        let mut length = 0;
+
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => {
+                    return Ok(length);
+                },
+                Ok(count) => {
+                    self.data(&buffer[..count])?;
+
+                    length += count as u64;
+                },
+                Err(ref error) if error.kind() == io::ErrorKind::Interrupted => {
+                },
+                Err(error) => {
+                    return Err(WriteError::Io(error));
+                }
+            }
+        }
+    }
+
+    /// Write part data.
+    ///
+    /// This can be called multiple times.
+    ///
+    /// # Arguments
+    ///
+    /// **`data`**
+    ///
+    /// The part data.
+    pub fn data(&mut self, data: &[u8]) -> Result<(), WriteError> {
+        if self.position != Position::Data {
+            return Err(WriteError::OutOfOrder);
+        }
+
+        // the delimiter either begins within data, or it begins within the tail and ends within
+        // the first bytes of data
+        let keep     = self.delimiter.len() - 1;
+        let mut tail = self.tail.clone();
+
+        tail.extend_from_slice(&data[..data.len().min(keep)]);
+
+        if self.contains_delimiter(&tail) || self.contains_delimiter(data) {
+            return Err(WriteError::BoundaryInData);
+        }
+
+        self.writer.write_all(data)?;
+
+        // the tail is only updated once data has been written
+        if data.len() >= keep {
+            tail.clear();
+            tail.extend_from_slice(&data[data.len() - keep..]);
+        } else if tail.len() > keep {
+            let excess = tail.len() - keep;
+
+            tail.drain(..excess);
+        }
+
+        self.tail = tail;
+
+        Ok(())
+    }
+
+    /// Write a field part.
+    ///
+    /// # Arguments
+    ///
+    /// **`name`**
+    ///
+    /// The field name.
+    ///
+    /// **`value`**
+    ///
+    /// The field value.
+    pub fn field(&mut self, name: &[u8], value: &[u8]) -> Result<(), WriteError> {
+        self.part(name, None, None)?;
+        self.data(value)
+    }
+
+    /// Write a file part, and stream its data from `reader`.
+    ///
+    /// Returns the amount of data bytes that have been written.
+    ///
+    /// # Arguments
+    ///
+    /// **`name`**
+    ///
+    /// The field name.
+    ///
+    /// **`filename`**
+    ///
+    /// The file name.
+    ///
+    /// **`content_type`**
+    ///
+    /// The content type of the file.
+    ///
+    /// **`reader`**
+    ///
+    /// The reader.
+    pub fn file<R: Read>(&mut self, name: &[u8], filename: &[u8], content_type: &[u8],
+                         reader: &mut R)
+    -> Result<u64, WriteError> {
+        self.part(name, Some(filename), Some(content_type))?;
+        self.copy_from(reader)
+    }
+
+    /// Finish the body by writing the final boundary.
+    ///
+    /// Nothing can be written once this has been called.
+    pub fn finish(&mut self) -> Result<(), WriteError> {
+        match self.position {
+            Position::Data => {
+                self.writer.write_all(&self.delimiter)?;
+            },
+            Position::Start => {
+                self.writer.write_all(&self.delimiter[2..])?;
+            },
+            Position::Finished => {
+                return Err(WriteError::OutOfOrder);
+            }
+        }
+
+        self.writer.write_all(b"--")?;
+
+        self.position = Position::Finished;
+
+        Ok(())
+    }
+
+    /// Retrieve a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Retrieve a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consume this, and retrieve the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Indicates that the body has been finished.
+    pub fn is_finished(&self) -> bool {
+        self.position == Position::Finished
+    }
+
+    /// Begin a part by writing the boundary and the part headers.
+    ///
+    /// Part data is written afterwards with `MultipartEncoder::data()` or
+    /// `MultipartEncoder::copy_from()`.
+    ///
+    /// # Arguments
+    ///
+    /// **`name`**
+    ///
+    /// The field name, which must not contain control characters other than horizontal tab, CR,
+    /// and LF. The `"`, CR, and LF bytes are percent-encoded, and `\` is escaped.
+    ///
+    /// **`filename`**
+    ///
+    /// The optional file name, which must not contain control characters other than horizontal
+    /// tab, CR, and LF. The `"`, CR, and LF bytes are percent-encoded, and `\` is escaped.
+    ///
+    /// **`content_type`**
+    ///
+    /// The optional content type, which is verified the same way as a header value.
+    pub fn part(&mut self, name: &[u8], filename: Option<&[u8]>, content_type: Option<&[u8]>)
+    -> Result<(), WriteError> {
+        if self.position == Position::Finished {
+            return Err(WriteError::OutOfOrder);
+        }
+
+        for byte in name.iter().chain(filename.unwrap_or(b"")) {
+            if !is_quoted_header_field(*byte) && *byte != b'"' && *byte != b'\\'
+               && *byte != b'\r' && *byte != b'\n' {
+                return Err(WriteError::HeaderValue(*byte));
+            }
+        }
+
+        if let Some(content_type) = content_type {
+            check_header(b"Content-Type", content_type)?;
+        }
+
+        if self.position == Position::Start {
+            self.writer.write_all(&self.delimiter[2..])?;
+        } else {
+            self.writer.write_all(&self.delimiter)?;
+        }
+
+        self.writer.write_all(b"\r\nContent-Disposition: form-data; name=")?;
+
+        write_quoted(&mut self.writer, name)?;
+
+        if let Some(filename) = filename {
+            self.writer.write_all(b"; filename=")?;
+
+            write_quoted(&mut self.writer, filename)?;
+        }
+
+        if let Some(content_type) = content_type {
+            self.writer.write_all(b"\r\nContent-Type: ")?;
+            self.writer.write_all(content_type)?;
+        }
+
+        self.writer.write_all(b"\r\n\r\n")?;
+
+        // data that begins with the boundary would follow a CRLF
+        self.tail.clear();
+        self.tail.extend_from_slice(b"\r\n");
+
+        self.position = Position::Data;
+
+        Ok(())
+    }
+
+    /// Indicates that `data` contains the delimiter.
+    fn contains_delimiter(&self, data: &[u8]) -> bool {
+        data.windows(self.delimiter.len()).any(|window| window == &self.delimiter[..])
+    }
+}
@@ -19,9 +19,14 @@
 #![allow(dead_code)]
 
 use byte::{ is_header_field, is_quoted_header_field, is_token };
-use fsm::{ ParserValue, Success };
+use fsm::{ Action, ParserValue, Success };
+use http1::framing::{ Body, Framing };
+use http1::header_name::{ HeaderName, MAX_HEADER_NAME_LENGTH };
 use http1::http_handler::HttpHandler;
-use http1::parser_error::ParserError;
+use http1::limits::Limits;
+use http1::method::Method;
+use http1::parser_error::{ ErrorKind, ParserError };
+use http1::parser_mode::ParserMode;
 use http1::parser_state::ParserState;
 use http1::parser_type::ParserType;
 
@@ -49,10 +54,125 @@ const UPPER14_MASK: u32 = 0x3FFF;
 /// Upper 14 bits shift.
 const UPPER14_SHIFT: u8 = 18;
 
+// -------------------------------------------------------------------------------------------------
+// FLAGS
+// -------------------------------------------------------------------------------------------------
+
+/// Message parsing, where the body parser type is detected after the head has been parsed.
+const F_MESSAGE: u16 = 1;
+
+/// Lenient mode.
+const F_LENIENT: u16 = 2;
+
+/// Strict mode.
+const F_STRICT: u16 = 4;
+
+/// Response to a `CONNECT` request.
+const F_CONNECT_REQUEST: u16 = 8;
+
+/// Persistent connection, where parsing continues with the next message once a message has
+/// finished.
+const F_PERSISTENT: u16 = 16;
+
+/// Response to a `HEAD` request.
+const F_HEAD_REQUEST: u16 = 32;
+
+/// Trailer validation.
+const F_VALIDATE_TRAILERS: u16 = 64;
+
+/// Header names are supplied as they were received, instead of being lower-cased.
+const F_PRESERVE_CASE: u16 = 128;
+
+/// Raw data passthrough.
+const F_RAW: u16 = 256;
+
+/// Only requests are expected.
+const F_REQUEST_ONLY: u16 = 512;
+
+/// Only responses are expected.
+const F_RESPONSE_ONLY: u16 = 1024;
+
+/// Multipart body is limited to the content length.
+const F_MULTIPART_LENGTH: u16 = 2048;
+
 // -------------------------------------------------------------------------------------------------
 // MACROS
 // -------------------------------------------------------------------------------------------------
 
+/// Collect a header name segment for message framing and upgrade detection, or a trailer name
+/// segment for trailer validation.
+macro_rules! framing_name {
+    ($parser:expr, $name:expr) => ({
+        if $parser.parser_type == ParserType::Head {
+            $parser.framing.name($name);
+        } else if $parser.parser_type == ParserType::Chunked
+               && $parser.flags & F_VALIDATE_TRAILERS == F_VALIDATE_TRAILERS {
+            $parser.framing.trailer_name($name);
+        }
+    });
+}
+
+/// Header name has been collected for message framing and upgrade detection, or a trailer name
+/// has been collected and must be validated.
+macro_rules! framing_name_finished {
+    ($parser:expr) => ({
+        if $parser.parser_type == ParserType::Head {
+            $parser.framing.name_finished($parser.flags & F_MESSAGE == F_MESSAGE);
+        } else if $parser.parser_type == ParserType::Chunked
+               && $parser.flags & F_VALIDATE_TRAILERS == F_VALIDATE_TRAILERS {
+            $parser.framing.trailer_name_finished()?;
+        }
+    });
+}
+
+/// Collect a header name segment for known header detection.
+macro_rules! known_name {
+    ($parser:expr, $name:expr) => ({
+        let name = $name;
+
+        if $parser.name_length + name.len() <= MAX_HEADER_NAME_LENGTH {
+            $parser.name[$parser.name_length..$parser.name_length + name.len()]
+                .copy_from_slice(name);
+        }
+
+        $parser.name_length += name.len();
+    });
+}
+
+/// Collect a header value segment for message framing and upgrade detection.
+macro_rules! framing_value {
+    ($parser:expr, $value:expr) => ({
+        if $parser.parser_type == ParserType::Head {
+            $parser.framing.value($value);
+        }
+    });
+}
+
+/// Execute callback macro `$macro` with the `$trailer` callback while chunk trailers are being
+/// parsed. Otherwise execute it with the `$header` callback.
+macro_rules! header_callback {
+    ($parser:expr, $handler:expr, $context:expr, $macro:ident, $header:ident, $trailer:ident
+     $($argument:tt)*) => ({
+        if $parser.parser_type == ParserType::Chunked {
+            $macro!($parser, $handler, $context, $trailer $($argument)*);
+        }
+
+        $macro!($parser, $handler, $context, $header $($argument)*);
+    });
+}
+
+/// Add `$length` to the length of the field being parsed, and exit with `$error` when the
+/// `$limit` has been exceeded.
+macro_rules! field_limit {
+    ($parser:expr, $length:expr, $limit:ident, $error:ident) => ({
+        $parser.field_length += $length;
+
+        if $parser.field_length > $parser.limits.$limit {
+            exit_error!($error);
+        }
+    });
+}
+
 /// Retrieve the lower 14 bits.
 macro_rules! get_lower14 {
     ($parser:expr) => ({
@@ -127,8 +247,26 @@ macro_rules! set_upper14 {
 
 // -------------------------------------------------------------------------------------------------
 
+/// Add `length` to the amount of processed bytes within `result`.
+fn offset<E>(result: Result<Success, ParserError<E>>, length: usize)
+-> Result<Success, ParserError<E>> {
+    match result {
+        Ok(Success::Callback(length_)) => Ok(Success::Callback(length + length_)),
+        Ok(Success::Eos(length_))      => Ok(Success::Eos(length + length_)),
+        Ok(Success::Finished(length_)) => Ok(Success::Finished(length + length_)),
+        Ok(Success::Upgrade(length_))  => Ok(Success::Upgrade(length + length_)),
+        error                          => error
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// State function.
+type StateFunction<'a, T, E> = fn(&mut Parser<'a, T, E>, &mut T, &mut ByteStream)
+                               -> Result<ParserValue, ErrorKind<E>>;
+
 /// HTTP 1.x parser.
-pub struct Parser<'a, T: HttpHandler + 'a> {
+pub struct Parser<'a, T: HttpHandler<E> + 'a, E = ()> {
     /// Bit data that stores parser state details, along with HTTP major/minor versions.
     bit_data: u32,
 
@@ -138,43 +276,143 @@ pub struct Parser<'a, T: HttpHandler + 'a> {
     /// Total byte count processed.
     byte_count: usize,
 
+    /// Length of the field being parsed.
+    field_length: usize,
+
+    /// Parser flags.
+    flags: u16,
+
+    /// Message framing header collection.
+    framing: Framing,
+
+    /// Header count.
+    header_count: usize,
+
     /// Length storage.
-    length: usize,
+    length: u64,
+
+    /// Parser limits.
+    limits: Limits,
+
+    /// Allowed request methods, or an empty list when all methods are allowed.
+    methods: &'a [Method<'a>],
+
+    /// Header name storage for known header detection.
+    name: [u8; MAX_HEADER_NAME_LENGTH],
+
+    /// Header name length.
+    name_length: usize,
+
+    /// Remaining length of a multipart body that is limited to the content length.
+    multipart_length: u64,
 
     /// Parser type.
     parser_type: ParserType,
 
+    /// Length of the limited region being parsed, which is either the head, or the chunk
+    /// extensions.
+    region_length: usize,
+
     /// Current state.
     state: ParserState,
 
+    /// Absolute offset of the stream being parsed.
+    stream_offset: usize,
+
     /// Current state function.
-    state_function: fn(&mut Parser<'a, T>, &mut T, &mut ByteStream)
-                    -> Result<ParserValue, ParserError>
+    state_function: StateFunction<'a, T, E>
 }
 
-impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
+impl<'a, E, T: HttpHandler<E> + 'a> Parser<'a, T, E> {
     /// Create a new `Parser` and initialize it for head parsing.
-    pub fn new() -> Parser<'a, T> {
+    pub fn new() -> Parser<'a, T, E> {
          Parser{
-            bit_data:       0,
-            boundary:       None,
-            byte_count:     0,
-            length:         0,
-            parser_type:    ParserType::Head,
-            state:          ParserState::StripDetect,
-            state_function: Parser::detect1
+            bit_data:         0,
+            boundary:         None,
+            byte_count:       0,
+            field_length:     0,
+            flags:            0,
+            framing:          Framing::new(),
+            header_count:     0,
+            length:           0,
+            limits:           Limits::default(),
+            methods:          &[],
+            multipart_length: 0,
+            name:             [0; MAX_HEADER_NAME_LENGTH],
+            name_length:      0,
+            parser_type:      ParserType::Head,
+            region_length:    0,
+            state:            ParserState::StripDetect,
+            stream_offset:    0,
+            state_function:   Parser::detect1
         }
     }
 
     /// Initialize this `Parser` for chunked transfer encoding parsing.
     pub fn init_chunked(&mut self) {
+        self.flags      &= !(F_MESSAGE | F_PERSISTENT);
         self.parser_type = ParserType::Chunked;
 
         self.reset();
     }
 
+    /// Initialize this `Parser` for end of input delimited parsing.
+    ///
+    /// All data is considered part of the body until `finish()` is called.
+    pub fn init_eof(&mut self) {
+        self.flags      &= !(F_MESSAGE | F_PERSISTENT);
+        self.parser_type = ParserType::Eof;
+
+        self.reset();
+    }
+
     /// Initialize this `Parser` for head parsing.
     pub fn init_head(&mut self) {
+        self.flags      &= !(F_MESSAGE | F_PERSISTENT);
+        self.parser_type = ParserType::Head;
+
+        self.reset();
+    }
+
+    /// Initialize this `Parser` for content length parsing.
+    ///
+    /// # Arguments
+    ///
+    /// **`length`**
+    ///
+    /// The body length, usually retrieved from the `Content-Length` header.
+    pub fn init_length(&mut self, length: usize) {
+        self.flags      &= !(F_MESSAGE | F_PERSISTENT);
+        self.parser_type = ParserType::Length;
+
+        self.reset();
+
+        self.length = length as u64;
+    }
+
+    /// Initialize this `Parser` for message parsing.
+    ///
+    /// After the head has been parsed, the body parser type is detected from the
+    /// `Transfer-Encoding`, `Content-Length`, and `Content-Type` headers, and parsing continues
+    /// with the body as part of the same `resume()` loop. Messages without a body finish
+    /// directly after the head.
+    pub fn init_message(&mut self) {
+        self.flags      &= !F_PERSISTENT;
+        self.flags      |= F_MESSAGE;
+        self.parser_type = ParserType::Head;
+
+        self.reset();
+    }
+
+    /// Initialize this `Parser` for message parsing on a persistent connection.
+    ///
+    /// This is the same as `init_message()`, except that once a message has finished, and the
+    /// connection persists according to the HTTP version and the `Connection` header, `Parser` is
+    /// reset and parsing continues with the next message as part of the same `resume()` loop.
+    /// This allows pipelined messages to be parsed from a single stream. When the connection does
+    /// not persist, parsing finishes after the message.
+    pub fn init_persistent(&mut self) {
+        self.flags      |= F_MESSAGE | F_PERSISTENT;
         self.parser_type = ParserType::Head;
 
         self.reset();
@@ -182,6 +420,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     /// Initialize this `Parser` for multipart parsing.
     pub fn init_multipart(&mut self) {
+        self.flags      &= !(F_MESSAGE | F_PERSISTENT);
         self.parser_type = ParserType::Multipart;
 
         self.reset();
@@ -189,6 +428,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     /// Initialize this `Parser` for URL encoded parsing.
     pub fn init_url_encoded(&mut self) {
+        self.flags      &= !(F_MESSAGE | F_PERSISTENT);
         self.parser_type = ParserType::UrlEncoded;
 
         self.reset();
@@ -196,14 +436,84 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     /// Retrieve the total byte count processed since the instantiation of `Parser`.
     ///
-    /// The byte count is updated when `resume()` completes. This means that if a
-    /// call to `byte_count()` is executed from within a callback, it will be accurate within
-    /// `stream.len()` bytes. For precise accuracy, the best time to retrieve the byte count is
-    /// outside of all callbacks.
+    /// The byte count is updated as each state is processed, and is exact once `resume()`
+    /// returns, including when a callback has exited the parser prematurely. It cannot be read
+    /// from within callbacks, since `Parser` is borrowed during `resume()`. Instead, the absolute
+    /// offset of the data being supplied to a callback is given to `HttpHandler::set_offset()`
+    /// prior to the callback.
     pub fn byte_count(&self) -> usize {
         self.byte_count
     }
 
+    /// Signal the end of input, such as when the connection has been closed.
+    ///
+    /// A body that is delimited by the end of input finishes, and `Success::Finished` is returned.
+    /// When no message has begun, or parsing has already finished, the end of input is expected as
+    /// well. Otherwise the message is incomplete, such as when the input ends within the head,
+    /// within a chunk, or short of the content length, and an `ErrorKind::UnexpectedEof` error is
+    /// returned.
+    ///
+    /// # Arguments
+    ///
+    /// **`handler`**
+    ///
+    /// The handler implementation.
+    pub fn finish(&mut self, handler: &mut T) -> Result<Success, ParserError<E>> {
+        loop {
+            match self.state {
+                ParserState::BodyByEof => {
+                    // end of input delimits the body
+                    self.state          = ParserState::BodyFinished;
+                    self.state_function = Parser::body_finished;
+                },
+                ParserState::StripDetect => {
+                    // message has not begun
+                    self.state          = ParserState::Finished;
+                    self.state_function = Parser::finished;
+                },
+                ParserState::BodyFinished
+                | ParserState::Dead
+                | ParserState::DetectBody
+                | ParserState::Finished
+                | ParserState::Interim
+                | ParserState::MessageFinished
+                | ParserState::Upgrade
+                | ParserState::Upgraded => {
+                },
+                _ => {
+                    let error = ParserError::new(
+                        ErrorKind::UnexpectedEof,
+                        self.state,
+                        &[],
+                        0,
+                        self.byte_count
+                    );
+
+                    self.state          = ParserState::Dead;
+                    self.state_function = Parser::dead;
+
+                    return Err(error);
+                }
+            }
+
+            match self.resume(handler, &[]) {
+                Ok(Success::Eos(_)) => {
+                    // state has changed, and must be checked again
+                },
+                other => {
+                    return other;
+                }
+            }
+        }
+    }
+
+    /// Indicates that the body is limited to the content length, and that the stream must be
+    /// handed off by `resume()` before it reaches the state functions.
+    fn is_length_limited(&self) -> bool {
+        self.parser_type == ParserType::UrlEncoded
+        || self.flags & F_MULTIPART_LENGTH == F_MULTIPART_LENGTH
+    }
+
     /// Main parser loop.
     ///
     /// # Arguments
@@ -212,25 +522,88 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
     ///
     /// The handler implementation.
     #[inline]
-    fn parse(&mut self, mut handler: &mut T, mut context: &mut ByteStream)
-    -> Result<Success, ParserError> {
+    fn parse(&mut self, handler: &mut T, context: &mut ByteStream)
+    -> Result<Success, ParserError<E>> {
+        let stream = context.stream;
+
+        // absolute offset of the stream, which may have been partially parsed already
+        self.stream_offset = self.byte_count - context.stream_index;
+
         loop {
-            match (self.state_function)(self, &mut handler, &mut context) {
+            let index = context.stream_index;
+            let limit = self.region_limit();
+
+            if let Some((limit, _)) = limit {
+                let available = limit.saturating_sub(self.region_length);
+
+                if stream.len() - index > available {
+                    // hide the bytes that exceed the limit from the state function
+                    context.stream = &stream[..index + available];
+                }
+            }
+
+            let mut value = (self.state_function)(self, handler, context);
+
+            self.byte_count = self.stream_offset + context.stream_index;
+
+            if let Some((_, error)) = limit {
+                self.region_length += context.stream_index - index;
+
+                if context.stream.len() < stream.len() {
+                    context.stream = stream;
+
+                    if let Ok(ParserValue::Exit(Success::Eos(_))) = value {
+                        // the region continues beyond the limit
+                        value = Err(error);
+                    }
+                }
+            }
+
+            if self.flags & F_RAW == F_RAW && context.stream_index > index && value.is_ok() {
+                handler.set_offset(self.stream_offset + index);
+
+                match handler.on_raw(&stream[index..context.stream_index]) {
+                    Action::Abort(error) => {
+                        value = Err(ErrorKind::Handler(error));
+                    },
+                    Action::Continue => {
+                    },
+                    Action::Pause => {
+                        if let Ok(ParserValue::Continue)
+                             | Ok(ParserValue::Exit(Success::Eos(_))) = value {
+                            value = Ok(ParserValue::Exit(Success::Callback(context.stream_index)));
+                        }
+                    }
+                }
+            }
+
+            match value {
                 Ok(ParserValue::Continue) => {
                 },
                 Ok(ParserValue::Exit(success)) => {
-                    self.byte_count += context.stream_index;
-
                     if let Success::Finished(_) = success {
                         self.state = ParserState::Finished;
                     }
 
                     return Ok(success);
                 },
-                Err(error) => {
-                    self.byte_count     += context.stream_index;
-                    self.state           = ParserState::Dead;
-                    self.state_function  = Parser::dead;
+                Err(kind) => {
+                    // errors that occur on a byte point to the byte that has been processed last
+                    let index = match kind.byte() {
+                        Some(_) if context.stream_index > 0 => context.stream_index - 1,
+                        _                                   => context.stream_index
+                    };
+
+                    let error = ParserError::new(
+                        kind,
+                        self.state,
+                        stream,
+                        index,
+                        self.stream_offset
+                    );
+
+                    self.state          = ParserState::Dead;
+                    self.state_function = Parser::dead;
 
                     return Err(error);
                 }
@@ -238,24 +611,80 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
         }
     }
 
+    /// Retrieve the limit of the region being parsed, along with the error that is returned when
+    /// the limit has been exceeded.
+    ///
+    /// The head, and the chunk extensions of each chunk, are limited regions.
+    #[inline]
+    fn region_limit(&self) -> Option<(usize, ErrorKind<E>)> {
+        match self.state {
+            ParserState::StripChunkExtensionName
+            | ParserState::LowerChunkExtensionName
+            | ParserState::UpperChunkExtensionName
+            | ParserState::StripChunkExtensionValue
+            | ParserState::ChunkExtensionValue
+            | ParserState::ChunkExtensionQuotedValue
+            | ParserState::ChunkExtensionEscapedValue
+            | ParserState::ChunkExtensionFinished => {
+                Some((self.limits.max_chunk_extension_length, ErrorKind::ChunkExtensionTooLong))
+            },
+            ParserState::Dead
+            | ParserState::DetectBody
+            | ParserState::Finished
+            | ParserState::Interim
+            | ParserState::MessageFinished
+            | ParserState::Upgrade
+            | ParserState::Upgraded => {
+                None
+            },
+            _ if self.parser_type == ParserType::Head => {
+                Some((self.limits.max_head_size, ErrorKind::HeadTooLarge))
+            },
+            _ => {
+                None
+            }
+        }
+    }
+
     /// Reset `Parser` to its initial state.
     ///
     /// After each call to `reset()`, don't forget to also set the multipart boundary, or URL
     /// encoded data length using `set_boundary()` or `set_length()`.
     pub fn reset(&mut self) {
-        self.bit_data = 0;
-        self.boundary = None;
-        self.length   = 0;
+        self.bit_data         = 0;
+        self.boundary         = None;
+        self.field_length     = 0;
+        self.header_count     = 0;
+        self.length           = 0;
+        self.multipart_length = 0;
+        self.name_length      = 0;
+        self.region_length    = 0;
+        self.flags           &= !(F_CONNECT_REQUEST | F_HEAD_REQUEST | F_MULTIPART_LENGTH);
+
+        self.framing.reset();
+
+        if self.flags & F_MESSAGE == F_MESSAGE {
+            // body parser type is detected again after the next head
+            self.parser_type = ParserType::Head;
+        }
 
         match self.parser_type {
             ParserType::Chunked => {
                 self.state          = ParserState::ChunkLength1;
                 self.state_function = Parser::chunk_length1;
             },
+            ParserType::Eof => {
+                self.state          = ParserState::BodyByEof;
+                self.state_function = Parser::body_by_eof;
+            },
             ParserType::Head => {
                 self.state          = ParserState::StripDetect;
                 self.state_function = Parser::strip_detect;
             },
+            ParserType::Length => {
+                self.state          = ParserState::BodyByLength;
+                self.state_function = Parser::body_by_length;
+            },
             ParserType::Multipart => {
                 self.state          = ParserState::MultipartHyphen1;
                 self.state_function = Parser::multipart_hyphen1;
@@ -282,31 +711,53 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
     ///
     /// The stream of data to be parsed.
     #[inline]
-    pub fn resume(&mut self, mut handler: &mut T, mut stream: &[u8])
-    -> Result<Success, ParserError> {
+    pub fn resume(&mut self, handler: &mut T, stream: &[u8])
+    -> Result<Success, ParserError<E>> {
+        let result = if self.is_length_limited() {
+            self.resume_length_limited(handler, stream)
+        } else {
+            self.parse(handler, &mut ByteStream::new(stream))
+        };
+
+        match result {
+            Ok(Success::Eos(length)) if length < stream.len() => {
+                // either the message head has been parsed and the body is limited to the content
+                // length, or the body has finished and the next message on a persistent
+                // connection follows
+                offset(self.resume(handler, &stream[length..]), length)
+            },
+            other => {
+                other
+            }
+        }
+    }
+
+    /// Resume parsing a URL encoded or multipart body that is limited to the content length.
+    fn resume_length_limited(&mut self, handler: &mut T, mut stream: &[u8])
+    -> Result<Success, ParserError<E>> {
         if let ParserType::UrlEncoded = self.parser_type {
-            if self.length < stream.len() {
+            if self.length < stream.len() as u64 {
                 // amount of data to process is less than the stream length
-                stream = &stream[0..self.length];
+                stream = &stream[0..self.length as usize];
             }
 
             let mut context = ByteStream::new(stream);
 
-            match self.parse(&mut handler, &mut context) {
+            match self.parse(handler, &mut context) {
                 Ok(Success::Eos(length)) => {
-                    if self.length - length == 0 {
+                    if self.length == length as u64 {
                         self.state          = ParserState::BodyFinished;
                         self.state_function = Parser::body_finished;
 
-                        self.parse(&mut handler, &mut context)
+                        self.parse(handler, &mut context)
                     } else {
-                        self.length -= length;
+                        self.length -= length as u64;
 
                         Ok(Success::Eos(length))
                     }
                 },
                 Ok(Success::Callback(length)) => {
-                    self.length -= length;
+                    self.length -= length as u64;
 
                     Ok(Success::Callback(length))
                 },
@@ -315,18 +766,185 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                 }
             }
         } else {
-            self.parse(&mut handler, &mut ByteStream::new(stream))
+            if self.multipart_length < stream.len() as u64 {
+                // amount of data to process is less than the stream length
+                stream = &stream[0..self.multipart_length as usize];
+            }
+
+            let mut context = ByteStream::new(stream);
+
+            match self.parse(handler, &mut context) {
+                Ok(Success::Eos(length)) => {
+                    self.multipart_length -= length as u64;
+
+                    if self.multipart_length > 0 {
+                        return Ok(Success::Eos(length));
+                    }
+
+                    if self.state != ParserState::MultipartEpilogue {
+                        // the last boundary has not been found within the content length
+                        let error = ParserError::new(
+                            ErrorKind::MultipartLength,
+                            self.state,
+                            stream,
+                            length,
+                            self.byte_count - length
+                        );
+
+                        self.state          = ParserState::Dead;
+                        self.state_function = Parser::dead;
+
+                        return Err(error);
+                    }
+
+                    self.flags         &= !F_MULTIPART_LENGTH;
+                    self.state          = ParserState::BodyFinished;
+                    self.state_function = Parser::body_finished;
+
+                    self.parse(handler, &mut context)
+                },
+                Ok(Success::Callback(length)) => {
+                    self.multipart_length -= length as u64;
+
+                    Ok(Success::Callback(length))
+                },
+                other => {
+                    other
+                }
+            }
         }
     }
 
+    /// Set the allowed request methods.
+    ///
+    /// When the method of a request is not allowed, parsing stops with `ErrorKind::Method` once
+    /// the method has been parsed. An empty list allows all methods, which is the default.
+    ///
+    /// Allowed methods are retained across calls to `reset()`.
+    ///
+    /// # Arguments
+    ///
+    /// **`methods`**
+    ///
+    /// The allowed methods.
+    pub fn set_allowed_methods(&mut self, methods: &'a [Method<'a>]) {
+        self.methods = methods;
+    }
+
     /// Set the multipart boundary.
     pub fn set_boundary(&mut self, boundary: &'a [u8]) {
         self.boundary = Some(boundary);
     }
 
+    /// Set the type of head that is expected.
+    ///
+    /// When `request` is `Some(true)`, parsing stops with `ErrorKind::UnexpectedResponse` once a
+    /// response has been found, and when it is `Some(false)`, parsing stops with
+    /// `ErrorKind::UnexpectedRequest` once a request has been found. Both are expected when
+    /// `request` is `None`, which is the default.
+    ///
+    /// The expected head type is retained across calls to `reset()`.
+    pub(crate) fn set_expected_head(&mut self, request: Option<bool>) {
+        self.flags &= !(F_REQUEST_ONLY | F_RESPONSE_ONLY);
+
+        match request {
+            Some(true)  => self.flags |= F_REQUEST_ONLY,
+            Some(false) => self.flags |= F_RESPONSE_ONLY,
+            None        => {}
+        }
+    }
+
+    /// Set header name case preservation.
+    ///
+    /// When enabled, header names are supplied to `HttpHandler::on_header_name()` and
+    /// `HttpHandler::on_trailer_name()` byte-for-byte as they were received. Otherwise they are
+    /// lower-cased, which is the default. Known header detection, message framing, and trailer
+    /// validation are case-insensitive either way.
+    ///
+    /// Header name case preservation is retained across calls to `reset()`.
+    pub fn set_header_case_preservation(&mut self, preserve: bool) {
+        if preserve {
+            self.flags |= F_PRESERVE_CASE;
+        } else {
+            self.flags &= !F_PRESERVE_CASE;
+        }
+    }
+
+    /// Set raw data passthrough.
+    ///
+    /// When enabled, every byte that is processed is also supplied to `HttpHandler::on_raw()`,
+    /// in the order it was received. This allows a message to be forwarded unchanged, while the
+    /// remaining callbacks are used to inspect it.
+    ///
+    /// Raw data passthrough is retained across calls to `reset()`.
+    pub fn set_raw_passthrough(&mut self, passthrough: bool) {
+        if passthrough {
+            self.flags |= F_RAW;
+        } else {
+            self.flags &= !F_RAW;
+        }
+    }
+
+    /// Set the method of the request that the response being parsed answers.
+    ///
+    /// A successful `2xx` response to a `CONNECT` request switches the connection to a tunnel, and
+    /// parsing exits with `Success::Upgrade` once the head has been parsed. During message
+    /// parsing, a response to a `HEAD` request never has a body, regardless of its framing
+    /// headers.
+    ///
+    /// This must be set again after each call to `reset()`. On a persistent connection, return
+    /// `Action::Pause` from `HttpHandler::on_message_finished()` in order to set the method of the
+    /// request that the next response answers.
+    pub fn set_request_method(&mut self, method: &[u8]) {
+        self.flags &= !(F_CONNECT_REQUEST | F_HEAD_REQUEST);
+
+        if method == b"CONNECT" {
+            self.flags |= F_CONNECT_REQUEST;
+        } else if method == b"HEAD" {
+            self.flags |= F_HEAD_REQUEST;
+        }
+    }
+
+    /// Set trailer validation.
+    ///
+    /// When enabled, each trailer of a chunk encoded message must have been announced by the
+    /// `Trailer` header, and must not be a field that is forbidden from being sent as a trailer,
+    /// such as `content-length` or `transfer-encoding`. Otherwise parsing stops with
+    /// `ErrorKind::UnannouncedTrailer` or `ErrorKind::ForbiddenTrailer`. Trailers can only be
+    /// announced during message parsing, where the head is parsed by the same `Parser`.
+    ///
+    /// Trailer validation is retained across calls to `reset()`.
+    pub fn set_trailer_validation(&mut self, validate: bool) {
+        if validate {
+            self.flags |= F_VALIDATE_TRAILERS;
+        } else {
+            self.flags &= !F_VALIDATE_TRAILERS;
+        }
+    }
+
+    /// Set the conformance mode.
+    ///
+    /// The mode is retained across calls to `reset()`.
+    pub fn set_mode(&mut self, mode: ParserMode) {
+        self.flags &= !(F_LENIENT | F_STRICT);
+
+        match mode {
+            ParserMode::Lenient => self.flags |= F_LENIENT,
+            ParserMode::Normal  => {},
+            ParserMode::Strict  => self.flags |= F_STRICT
+        }
+    }
+
+    /// Set the parser limits.
+    ///
+    /// Limits are retained across calls to `reset()`.
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
     /// Set the URL encoded length.
     pub fn set_length(&mut self, length: usize) {
-        self.length = length;
+        self.length = length as u64;
     }
 
     /// Retrieve the current state.
@@ -415,20 +1033,26 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
     // ---------------------------------------------------------------------------------------------
 
     #[inline]
-    fn strip_detect(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    fn strip_detect(&mut self, handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        // strict mode only allows empty lines
+        macro_rules! is_stripped {
+            () => (
+                context.byte == b'\r' || context.byte == b'\n'
+                || (self.flags & F_STRICT == 0 && (context.byte == b'\t' || context.byte == b' '))
+            );
+        }
+
         bs_available!(context) > 0 || exit_eos!(self, context);
         bs_next!(context);
 
-        if context.byte == b'\r' || context.byte == b'\n'
-        || context.byte == b'\t' || context.byte == b' ' {
+        if is_stripped!() {
             loop {
                 bs_available!(context) > 0 || exit_eos!(self, context);
 
                 bs_next!(context);
 
-                if context.byte == b'\r' || context.byte == b'\n'
-                || context.byte == b'\t' || context.byte == b' ' {
+                if is_stripped!() {
                     continue;
                 }
 
@@ -438,6 +1062,16 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
         bs_replay!(context);
 
+        if self.flags & F_MESSAGE == F_MESSAGE {
+            set_state!(self, Detect1, detect1);
+
+            if proceed!(handler.on_message_begin()) {
+                transition!(self, context);
+            }
+
+            exit_callback!(self, context);
+        }
+
         transition!(
             self,
             context,
@@ -448,19 +1082,23 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn detect1(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         macro_rules! method {
             ($method:expr, $length:expr) => (
                 bs_jump!(context, $length);
 
+                field_limit!(self, $length - 1, max_method_length, MethodTooLong);
+
+                self.framing.method($method);
+
                 callback_transition!(
                     self,
                     handler,
                     context,
                     on_method,
                     $method,
-                    RequestUrl1,
-                    request_url1
+                    RequestMethodFinished,
+                    request_method_finished
                 );
             );
         }
@@ -474,7 +1112,9 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                     response_status_code1
                 );
 
-                if handler.on_version($major, $minor) {
+                self.framing.version($major, $minor);
+
+                if proceed!(handler.on_version($major, $minor)) {
                     transition!(self, context);
                 }
 
@@ -482,6 +1122,10 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             });
         }
 
+        // an interim response is directly followed by the final response, which may not have
+        // arrived yet
+        exit_if_eos!(self, context);
+
         unsafe {
             if bs_starts_with1!(context, b"H") {
                 if bs_has_bytes!(context, 9) {
@@ -523,6 +1167,10 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                         method!(b"POST", 5);
                     } else if bs_starts_with4!(context, b"PUT ") {
                         method!(b"PUT", 4);
+                    } else if bs_starts_with6!(context, b"PATCH ") {
+                        method!(b"PATCH", 6);
+                    } else if bs_has_bytes!(context, 9) && bs_starts_with9!(context, b"PROPFIND ") {
+                        method!(b"PROPFIND", 9);
                     }
                 } else if bs_starts_with7!(context, b"DELETE ") {
                     method!(b"DELETE", 7);
@@ -536,6 +1184,18 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                     method!(b"TRACE", 6);
                 } else if bs_starts_with4!(context, b"PRI ") {
                     method!(b"PRI", 4);
+                } else if bs_starts_with6!(context, b"QUERY ") {
+                    method!(b"QUERY", 6);
+                } else if bs_starts_with5!(context, b"COPY ") {
+                    method!(b"COPY", 5);
+                } else if bs_starts_with5!(context, b"MOVE ") {
+                    method!(b"MOVE", 5);
+                } else if bs_starts_with6!(context, b"MKCOL ") {
+                    method!(b"MKCOL", 6);
+                } else if bs_starts_with5!(context, b"LOCK ") {
+                    method!(b"LOCK", 5);
+                } else if bs_starts_with7!(context, b"UNLOCK ") {
+                    method!(b"UNLOCK", 7);
                 }
             }
         }
@@ -554,12 +1214,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Method(context.byte))
+        Err(ErrorKind::Method(context.byte))
     }
 
     #[inline]
     fn detect2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -582,12 +1242,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Method(context.byte))
+        Err(ErrorKind::Method(context.byte))
     }
 
     #[inline]
     fn detect3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -610,12 +1270,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Method(context.byte))
+        Err(ErrorKind::Method(context.byte))
     }
 
     #[inline]
     fn detect4(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -638,12 +1298,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Method(context.byte))
+        Err(ErrorKind::Method(context.byte))
     }
 
     #[inline]
     fn detect5(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -666,7 +1326,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Method(context.byte))
+        Err(ErrorKind::Method(context.byte))
     }
 
     // ---------------------------------------------------------------------------------------------
@@ -675,7 +1335,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn request_method(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         bs_collect!(
             context,
 
@@ -685,26 +1345,59 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             },
 
             // on end-of-stream
-            callback_eos_expr!(self, handler, context, on_method)
+            {
+                field_limit!(self, bs_slice_length!(context), max_method_length, MethodTooLong);
+
+                self.framing.method(bs_slice!(context));
+
+                callback_eos_expr!(self, handler, context, on_method)
+            }
         );
 
         if context.byte == b' ' {
+            field_limit!(self, bs_slice_length!(context) - 1, max_method_length, MethodTooLong);
+
+            self.framing.method(bs_slice_ignore!(context));
+
             callback_ignore_transition!(
                 self,
                 handler,
                 context,
                 on_method,
-                RequestUrl1,
-                request_url1
+                RequestMethodFinished,
+                request_method_finished
             );
         }
 
-        Err(ParserError::Method(context.byte))
+        Err(ErrorKind::Method(context.byte))
+    }
+
+    #[inline]
+    fn request_method_finished(&mut self, handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        if self.flags & F_RESPONSE_ONLY == F_RESPONSE_ONLY {
+            exit_error!(UnexpectedRequest);
+        }
+
+        if !self.methods.is_empty()
+        && !self.methods.iter().any(|method| method.as_bytes() == self.framing.request_method()) {
+            exit_error!(Method, self.framing.request_method()[0]);
+        }
+
+        set_state!(self, RequestUrl1, request_url1);
+
+        if proceed!(handler.on_request_method(Method::from_bytes(self.framing.request_method()))) {
+            transition!(self, context);
+        }
+
+        exit_callback!(self, context);
     }
 
     #[inline]
     fn request_url1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
+        self.field_length = 0;
+
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -717,20 +1410,25 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Url(context.byte))
+        Err(ErrorKind::Url(context.byte))
     }
 
     #[inline]
     fn request_url2(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         collect_visible_7bit!(
             context,
 
             // on end-of-stream
-            callback_eos_expr!(self, handler, context, on_url)
+            {
+                field_limit!(self, bs_slice_length!(context), max_url_length, UrlTooLong);
+                callback_eos_expr!(self, handler, context, on_url);
+            }
         );
 
         if context.byte == b' ' {
+            field_limit!(self, bs_slice_length!(context) - 1, max_url_length, UrlTooLong);
+
             callback_ignore_transition!(
                 self,
                 handler,
@@ -741,18 +1439,20 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Url(context.byte))
+        Err(ErrorKind::Url(context.byte))
     }
 
     #[inline]
     fn request_http1(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         macro_rules! version {
             ($major:expr, $minor:expr, $length:expr) => (
                 bs_jump!(context, $length);
                 set_state!(self, InitialEnd, initial_end);
 
-                if handler.on_version($major, $minor) {
+                self.framing.version($major, $minor);
+
+                if proceed!(handler.on_version($major, $minor)) {
                     transition!(self, context);
                 }
 
@@ -786,12 +1486,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn request_http2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -804,12 +1504,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn request_http3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -822,12 +1522,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn request_http4(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -840,12 +1540,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn request_http5(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -858,12 +1558,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn request_version_major1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -881,12 +1581,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn request_version_major2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -908,12 +1608,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn request_version_major3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -935,12 +1635,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn request_version_period(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -953,12 +1653,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn request_version_minor1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -973,16 +1673,16 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn request_version_minor2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
-        if context.byte == b'\r' {
+        if context.byte == b'\r' || (context.byte == b'\n' && self.flags & F_LENIENT == F_LENIENT) {
             bs_replay!(context);
 
             transition!(
@@ -1002,16 +1702,16 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn request_version_minor3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
-        if context.byte == b'\r' {
+        if context.byte == b'\r' || (context.byte == b'\n' && self.flags & F_LENIENT == F_LENIENT) {
             bs_replay!(context);
 
             transition!(
@@ -1031,19 +1731,26 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn request_version_cr(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
-        if context.byte == b'\r' {
+        if context.byte == b'\r' || (context.byte == b'\n' && self.flags & F_LENIENT == F_LENIENT) {
+            if context.byte == b'\n' {
+                // LF line ending, which is consumed by the initial LF state
+                bs_replay!(context);
+            }
+
             set_state!(self, InitialEnd, initial_end);
 
-            if handler.on_version(get_lower14!(self) as u16, get_upper14!(self) as u16) {
+            self.framing.version(get_lower14!(self) as u16, get_upper14!(self) as u16);
+
+            if proceed!(handler.on_version(get_lower14!(self) as u16, get_upper14!(self) as u16)) {
                 transition!(
                     self,
                     context
@@ -1053,7 +1760,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             exit_callback!(self, context);
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     // ---------------------------------------------------------------------------------------------
@@ -1062,7 +1769,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn response_version_major1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1080,12 +1787,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn response_version_major2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1107,12 +1814,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn response_version_major3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1134,12 +1841,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn response_version_period(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1152,12 +1859,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn response_version_minor1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1172,12 +1879,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn response_version_minor2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1201,12 +1908,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn response_version_minor3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1230,12 +1937,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn response_version_space(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1246,7 +1953,9 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                 response_status_code1
             );
 
-            if handler.on_version(get_lower14!(self) as u16, get_upper14!(self) as u16) {
+            self.framing.version(get_lower14!(self) as u16, get_upper14!(self) as u16);
+
+            if proceed!(handler.on_version(get_lower14!(self) as u16, get_upper14!(self) as u16)) {
                 transition!(
                     self,
                     context
@@ -1256,12 +1965,16 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             exit_callback!(self, context);
         }
 
-        Err(ParserError::Version(context.byte))
+        Err(ErrorKind::Version(context.byte))
     }
 
     #[inline]
     fn response_status_code1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
+        if self.flags & F_REQUEST_ONLY == F_REQUEST_ONLY {
+            exit_error!(UnexpectedResponse);
+        }
+
         exit_if_eos!(self, context);
 
         if bs_available!(context) > 2 {
@@ -1311,12 +2024,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::StatusCode(context.byte))
+        Err(ErrorKind::StatusCode(context.byte))
     }
 
     #[inline]
     fn response_status_code2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1331,12 +2044,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::StatusCode(context.byte))
+        Err(ErrorKind::StatusCode(context.byte))
     }
 
     #[inline]
     fn response_status_code3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1351,23 +2064,49 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::StatusCode(context.byte))
+        Err(ErrorKind::StatusCode(context.byte))
     }
 
     #[inline]
     fn response_status_code_space(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
         if context.byte == b' ' {
+            self.framing.status_code(get_lower14!(self) as u16);
+
             set_state!(
                 self,
                 ResponseStatus1,
                 response_status1
             );
 
-            if handler.on_status_code(get_lower14!(self) as u16) {
+            if proceed!(handler.on_status_code(get_lower14!(self) as u16)) {
+                transition!(
+                    self,
+                    context
+                );
+            }
+
+            exit_callback!(self, context);
+        } else if (context.byte == b'\r' || context.byte == b'\n')
+                  && self.flags & F_LENIENT == F_LENIENT {
+            // missing reason phrase
+            if context.byte == b'\n' {
+                // LF line ending, which is consumed by the initial LF state
+                bs_replay!(context);
+            }
+
+            self.framing.status_code(get_lower14!(self) as u16);
+
+            set_state!(
+                self,
+                InitialEnd,
+                initial_end
+            );
+
+            if proceed!(handler.on_status_code(get_lower14!(self) as u16)) {
                 transition!(
                     self,
                     context
@@ -1377,12 +2116,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             exit_callback!(self, context);
         }
 
-        Err(ParserError::StatusCode(context.byte))
+        Err(ErrorKind::StatusCode(context.byte))
     }
 
     #[inline]
     fn response_status1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1396,21 +2135,36 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                 ResponseStatus2,
                 response_status2
             );
+        } else if (context.byte == b'\r' || context.byte == b'\n')
+                  && self.flags & F_LENIENT == F_LENIENT {
+            // empty reason phrase
+            if context.byte == b'\n' {
+                // LF line ending, which is consumed by the initial LF state
+                bs_replay!(context);
+            }
+
+            transition!(
+                self,
+                context,
+                InitialEnd,
+                initial_end
+            );
         }
 
-        Err(ParserError::Status(context.byte))
+        Err(ErrorKind::Status(context.byte))
     }
 
     #[inline]
     fn response_status2(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         bs_collect!(
             context,
 
             // collect loop
             if context.byte > 0x1F && context.byte != 0x7F {
                 continue;
-            } else if context.byte == b'\r' {
+            } else if context.byte == b'\r'
+                   || (context.byte == b'\n' && self.flags & F_LENIENT == F_LENIENT) {
                 break;
             } else {
                 exit_error!(Status, context.byte);
@@ -1422,6 +2176,20 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             })
         );
 
+        if context.byte == b'\n' {
+            // LF line ending, which is consumed by the initial LF state
+            bs_replay!(context);
+
+            callback_transition!(
+                self,
+                handler,
+                context,
+                on_status,
+                InitialEnd,
+                initial_end
+            );
+        }
+
         callback_ignore_transition!(
             self,
             handler,
@@ -1438,10 +2206,10 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn initial_end(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         set_state!(self, InitialLf, initial_lf);
 
-        if handler.on_initial_finished() {
+        if proceed!(handler.on_initial_finished()) {
             transition!(self, context);
         }
 
@@ -1450,7 +2218,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn initial_lf(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1463,17 +2231,23 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::CrlfSequence(context.byte))
+        Err(ErrorKind::CrlfSequence(context.byte))
     }
 
     #[inline]
     fn check_header_name(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
+        self.field_length = 0;
+
         if context.byte == b' ' || context.byte == b'\t' {
             // multiline value
+            if self.flags & F_STRICT == F_STRICT {
+                exit_error!(ObsoleteLineFolding);
+            }
+
             transition!(
                 self,
                 context,
@@ -1484,6 +2258,18 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
         bs_replay!(context);
 
+        self.header_count += 1;
+
+        let max_headers = if let ParserType::Multipart = self.parser_type {
+            self.limits.max_multipart_headers
+        } else {
+            self.limits.max_headers
+        };
+
+        if self.header_count > max_headers {
+            exit_error!(TooManyHeaders);
+        }
+
         transition!(
             self,
             context,
@@ -1494,19 +2280,35 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn first_header_name(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         macro_rules! name {
             ($header:expr, $length:expr) => ({
+                let stream = context.stream;
+
+                // supply the header name as it was received when header name case is preserved
+                let name = if self.flags & F_PRESERVE_CASE == F_PRESERVE_CASE {
+                    &stream[bs_index!(context)..bs_index!(context) + $length - 1]
+                } else {
+                    $header
+                };
+
                 bs_jump!(context, $length);
 
-                callback_transition!(
+                field_limit!(self, $length - 1, max_header_name_length, HeaderNameTooLong);
+                framing_name!(self, $header);
+                known_name!(self, $header);
+                framing_name_finished!(self);
+
+                header_callback!(
                     self,
                     handler,
                     context,
+                    callback_transition,
                     on_header_name,
-                    $header,
-                    StripHeaderValue,
-                    strip_header_value
+                    on_trailer_name,
+                    name,
+                    HeaderNameFinished,
+                    header_name_finished
                 );
             });
         }
@@ -1593,23 +2395,31 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::HeaderName(context.byte))
+        Err(ErrorKind::HeaderName(context.byte))
     }
 
     #[inline]
     fn upper_header_name(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
-        if context.byte > 0x40 && context.byte < 0x5B {
+        if context.byte > 0x40 && context.byte < 0x5B && self.flags & F_PRESERVE_CASE == 0 {
             // upper-cased byte, let's lower-case it
-            callback_transition!(
+            field_limit!(self, 1, max_header_name_length, HeaderNameTooLong);
+            framing_name!(self, &[context.byte + 0x20]);
+            known_name!(self, &[context.byte + 0x20]);
+
+            // the lower-cased byte is produced from the upper-cased byte
+            header_callback!(
                 self,
                 handler,
                 context,
+                callback_transition,
                 on_header_name,
+                on_trailer_name,
                 &[context.byte + 0x20],
+                self.stream_offset + context.stream_index - 1,
                 LowerHeaderName,
                 lower_header_name
             );
@@ -1627,46 +2437,120 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn lower_header_name(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         collect_tokens!(
             context,
 
-            // stop on these bytes
-            context.byte > 0x40 && context.byte < 0x5B,
+            // stop on upper-cased bytes, unless header name case is preserved
+            context.byte > 0x40 && context.byte < 0x5B && self.flags & F_PRESERVE_CASE == 0,
 
             // on end-of-stream
-            callback_eos_expr!(self, handler, context, on_header_name)
+            {
+                field_limit!(
+                    self,
+                    bs_slice_length!(context),
+                    max_header_name_length,
+                    HeaderNameTooLong
+                );
+
+                framing_name!(self, bs_slice!(context));
+                known_name!(self, bs_slice!(context));
+
+                header_callback!(
+                    self,
+                    handler,
+                    context,
+                    callback_eos_expr,
+                    on_header_name,
+                    on_trailer_name
+                );
+            }
         );
 
         if context.byte > 0x40 && context.byte < 0x5B {
             // upper-cased byte
             bs_replay!(context);
 
-            callback_transition!(
+            field_limit!(
+                self,
+                bs_slice_length!(context),
+                max_header_name_length,
+                HeaderNameTooLong
+            );
+
+            framing_name!(self, bs_slice!(context));
+            known_name!(self, bs_slice!(context));
+
+            header_callback!(
                 self,
                 handler,
                 context,
+                callback_transition,
                 on_header_name,
+                on_trailer_name,
                 UpperHeaderName,
                 upper_header_name
             );
+        } else if context.byte == b' ' || context.byte == b'\t' {
+            // whitespace between the header name and the colon is a request smuggling vector
+            exit_error!(HeaderNameWhitespace);
         } else if context.byte == b':' {
-            callback_ignore_transition!(
+            field_limit!(
+                self,
+                bs_slice_length!(context) - 1,
+                max_header_name_length,
+                HeaderNameTooLong
+            );
+
+            framing_name!(self, bs_slice_ignore!(context));
+            known_name!(self, bs_slice_ignore!(context));
+            framing_name_finished!(self);
+
+            header_callback!(
                 self,
                 handler,
                 context,
+                callback_ignore_transition,
                 on_header_name,
-                StripHeaderValue,
-                strip_header_value
+                on_trailer_name,
+                HeaderNameFinished,
+                header_name_finished
             );
         }
 
-        Err(ParserError::HeaderName(context.byte))
+        Err(ErrorKind::HeaderName(context.byte))
+    }
+
+    #[inline]
+    fn header_name_finished(&mut self, handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        let name = if self.name_length <= MAX_HEADER_NAME_LENGTH {
+            // header names are mixed-case when header name case is preserved
+            self.name[..self.name_length].make_ascii_lowercase();
+
+            HeaderName::from_bytes(&self.name[..self.name_length])
+        } else {
+            None
+        };
+
+        self.name_length = 0;
+
+        set_state!(self, StripHeaderValue, strip_header_value);
+
+        if let Some(name) = name {
+            if !proceed!(handler.on_known_header(name)) {
+                exit_callback!(self, context);
+            }
+        }
+
+        transition!(self, context);
     }
 
     #[inline]
-    fn strip_header_value(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    fn strip_header_value(&mut self, handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        self.field_length = 0;
+
         consume_linear_space!(
             context,
 
@@ -1674,8 +2558,10 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             exit_eos!(self, context)
         );
 
-        // make sure we have something visible to collect in next state
-        if is_visible_7bit!(context.byte) {
+        // make sure we have something visible to collect in next state, which includes obsolete
+        // text outside of strict mode
+        if is_visible_7bit!(context.byte)
+        || (context.byte > 0x7F && self.flags & F_STRICT == 0) {
             bs_replay!(context);
 
             transition!(
@@ -1684,27 +2570,101 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                 HeaderValue,
                 header_value
             );
+        } else if (context.byte == b'\r' || context.byte == b'\n')
+                  && self.flags & F_LENIENT == F_LENIENT {
+            // empty value
+            if context.byte == b'\n' {
+                // LF line ending, which is consumed by the header LF state
+                bs_replay!(context);
+            }
+
+            header_callback!(
+                self,
+                handler,
+                context,
+                callback_transition,
+                on_header_value,
+                on_trailer_value,
+                b"",
+                HeaderLf1,
+                header_lf1
+            );
         }
 
-        Err(ParserError::HeaderValue(context.byte))
+        Err(ErrorKind::HeaderValue(context.byte))
     }
 
     #[inline]
     fn header_value(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         collect_field!(
             context,
 
+            // stop on obsolete text in strict mode
+            context.byte > 0x7F && self.flags & F_STRICT == F_STRICT,
+
             // on end-of-stream
-            callback_eos_expr!(self, handler, context, on_header_value)
+            {
+                field_limit!(
+                    self,
+                    bs_slice_length!(context),
+                    max_header_value_length,
+                    HeaderValueTooLong
+                );
+
+                framing_value!(self, bs_slice!(context));
+
+                header_callback!(
+                    self,
+                    handler,
+                    context,
+                    callback_eos_expr,
+                    on_header_value,
+                    on_trailer_value
+                );
+            }
         );
 
         if context.byte == b'\r' {
-            callback_ignore_transition!(
+            field_limit!(
+                self,
+                bs_slice_length!(context) - 1,
+                max_header_value_length,
+                HeaderValueTooLong
+            );
+
+            framing_value!(self, bs_slice_ignore!(context));
+
+            header_callback!(
                 self,
                 handler,
                 context,
+                callback_ignore_transition,
                 on_header_value,
+                on_trailer_value,
+                HeaderLf1,
+                header_lf1
+            );
+        } else if context.byte == b'\n' && self.flags & F_LENIENT == F_LENIENT {
+            // LF line ending, which is consumed by the header LF state
+            bs_replay!(context);
+
+            field_limit!(
+                self,
+                bs_slice_length!(context),
+                max_header_value_length,
+                HeaderValueTooLong
+            );
+
+            framing_value!(self, bs_slice!(context));
+
+            header_callback!(
+                self,
+                handler,
+                context,
+                callback_transition,
+                on_header_value,
+                on_trailer_value,
                 HeaderLf1,
                 header_lf1
             );
@@ -1717,17 +2677,38 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::HeaderValue(context.byte))
+        Err(ErrorKind::HeaderValue(context.byte))
     }
 
     #[inline]
     fn header_quoted_value(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         collect_quoted_field!(
             context,
 
+            // stop on obsolete text in strict mode
+            context.byte > 0x7F && self.flags & F_STRICT == F_STRICT,
+
             // on end-of-stream
-            callback_eos_expr!(self, handler, context, on_header_value)
+            {
+                field_limit!(
+                    self,
+                    bs_slice_length!(context),
+                    max_header_value_length,
+                    HeaderValueTooLong
+                );
+
+                framing_value!(self, bs_slice!(context));
+
+                header_callback!(
+                    self,
+                    handler,
+                    context,
+                    callback_eos_expr,
+                    on_header_value,
+                    on_trailer_value
+                );
+            }
         );
 
         if context.byte == b'"' {
@@ -1746,38 +2727,65 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::HeaderValue(context.byte))
+        Err(ErrorKind::HeaderValue(context.byte))
     }
 
     #[inline]
     fn header_escaped_value(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         // since we're not collecting, and because it's EOS, we must execute the callback
         // manually
         if bs_available!(context) == 0 {
-            callback_eos_expr!(self, handler, context, on_header_value);
+            field_limit!(
+                self,
+                bs_slice_length!(context),
+                max_header_value_length,
+                HeaderValueTooLong
+            );
+
+            framing_value!(self, bs_slice!(context));
+
+            header_callback!(
+                self,
+                handler,
+                context,
+                callback_eos_expr,
+                on_header_value,
+                on_trailer_value
+            );
         }
 
         bs_next!(context);
 
         // escaped bytes must be 7bit, and cannot be control characters
         if context.byte > 0x1F && context.byte < 0x7B {
-            callback_transition!(
+            field_limit!(
+                self,
+                bs_slice_length!(context),
+                max_header_value_length,
+                HeaderValueTooLong
+            );
+
+            framing_value!(self, bs_slice!(context));
+
+            header_callback!(
                 self,
                 handler,
                 context,
+                callback_transition,
                 on_header_value,
+                on_trailer_value,
                 HeaderQuotedValue,
                 header_quoted_value
             );
         }
 
-        Err(ParserError::HeaderValue(context.byte))
+        Err(ErrorKind::HeaderValue(context.byte))
     }
 
     #[inline]
     fn header_cr1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         unsafe {
             if bs_has_bytes!(context, 2) && bs_starts_with2!(context, b"\r\n") {
                 bs_jump!(context, 2);
@@ -1803,12 +2811,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::CrlfSequence(context.byte))
+        Err(ErrorKind::CrlfSequence(context.byte))
     }
 
     #[inline]
     fn header_lf1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -1821,12 +2829,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::CrlfSequence(context.byte))
+        Err(ErrorKind::CrlfSequence(context.byte))
     }
 
     #[inline]
     fn header_cr2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         unsafe {
             if bs_has_bytes!(context, 2) && bs_starts_with2!(context, b"\r\n") {
                 bs_jump!(context, 2);
@@ -1847,99 +2855,16 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             transition!(
                 self,
                 context,
-                HeaderLf2,
-                header_lf2
-            );
-        }
-
-        bs_replay!(context);
-
-        transition!(
-            self,
-            context,
-            CheckHeaderName,
-            check_header_name
-        );
-    }
-
-    #[inline]
-    fn header_lf2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        exit_if_eos!(self, context);
-        bs_next!(context);
-
-        if context.byte == b'\n' {
-            transition!(
-                self,
-                context,
-                HeaderEnd,
-                header_end
-            );
-        }
-
-        Err(ParserError::CrlfSequence(context.byte))
-    }
-
-    #[inline]
-    fn header_end(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        if let ParserType::Chunked = self.parser_type {
-            set_state!(self, BodyFinished, body_finished);
-        } else if let ParserType::Multipart = self.parser_type {
-            set_state!(self, MultipartDetectData, multipart_detect_data);
-        } else {
-            set_state!(self, Finished, finished);
-        }
-
-        if handler.on_headers_finished() {
-            transition!(self, context);
-        }
-
-        exit_callback!(self, context);
-    }
-
-    // ---------------------------------------------------------------------------------------------
-    // CHUNK STATES
-    // ---------------------------------------------------------------------------------------------
-
-    #[inline]
-    fn chunk_length1(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        exit_if_eos!(self, context);
-        bs_next!(context);
-
-        if context.byte == b'0' {
-            set_state!(self, ChunkLengthCr, chunk_length_cr);
-        } else if is_hex!(context.byte) {
-            self.length = hex_to_byte!(context.byte) as usize;
-
-            set_state!(self, ChunkLength2, chunk_length2);
-        } else {
-            exit_error!(ChunkLength, context.byte);
-        }
-
-        if handler.on_chunk_begin() {
-            transition!(self, context);
-        }
-
-        exit_callback!(self, context);
-    }
-
-    #[inline]
-    fn chunk_length2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        exit_if_eos!(self, context);
-        bs_next!(context);
-
-        if is_hex!(context.byte) {
-            self.length <<= 4;
-            self.length  |= hex_to_byte!(context.byte) as usize;
-
-            transition!(
-                self,
-                context,
-                ChunkLength3,
-                chunk_length3
+                HeaderLf2,
+                header_lf2
+            );
+        } else if context.byte == b'\n' && self.flags & F_LENIENT == F_LENIENT {
+            // LF line ending
+            transition!(
+                self,
+                context,
+                HeaderEnd,
+                header_end
             );
         }
 
@@ -1948,179 +2873,248 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
         transition!(
             self,
             context,
-            ChunkLengthCr,
-            chunk_length_cr
+            CheckHeaderName,
+            check_header_name
         );
     }
 
     #[inline]
-    fn chunk_length3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    fn header_lf2(&mut self, _handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
-        if is_hex!(context.byte) {
-            self.length <<= 4;
-            self.length  |= hex_to_byte!(context.byte) as usize;
-
+        if context.byte == b'\n' {
             transition!(
                 self,
                 context,
-                ChunkLength4,
-                chunk_length4
+                HeaderEnd,
+                header_end
             );
         }
 
-        bs_replay!(context);
-
-        transition!(
-            self,
-            context,
-            ChunkLengthCr,
-            chunk_length_cr
-        );
+        Err(ErrorKind::CrlfSequence(context.byte))
     }
 
     #[inline]
-    fn chunk_length4(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        exit_if_eos!(self, context);
-        bs_next!(context);
+    fn header_end(&mut self, handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        self.header_count = 0;
 
-        if is_hex!(context.byte) {
-            self.length <<= 4;
-            self.length  |= hex_to_byte!(context.byte) as usize;
+        if self.parser_type == ParserType::Head {
+            self.framing.validate(self.flags & F_HEAD_REQUEST == F_HEAD_REQUEST)?;
+        }
 
-            transition!(
-                self,
-                context,
-                ChunkLength5,
-                chunk_length5
-            );
+        if let ParserType::Chunked = self.parser_type {
+            set_state!(self, BodyFinished, body_finished);
+        } else if let ParserType::Multipart = self.parser_type {
+            set_state!(self, MultipartDetectData, multipart_detect_data);
+        } else if self.framing.is_upgrade(self.flags & F_CONNECT_REQUEST == F_CONNECT_REQUEST) {
+            set_state!(self, Upgrade, upgrade);
+        } else if self.flags & F_MESSAGE == F_MESSAGE && self.framing.interim().is_some() {
+            set_state!(self, Interim, interim);
+        } else if self.flags & F_MESSAGE == F_MESSAGE {
+            set_state!(self, DetectBody, detect_body);
+        } else {
+            set_state!(self, Finished, finished);
         }
 
-        bs_replay!(context);
+        let finished = if let ParserType::Chunked = self.parser_type {
+            handler.on_trailers_finished()
+        } else {
+            handler.on_headers_finished()
+        };
 
-        transition!(
-            self,
-            context,
-            ChunkLengthCr,
-            chunk_length_cr
-        );
+        if proceed!(finished) {
+            transition!(self, context);
+        }
+
+        exit_callback!(self, context);
     }
 
     #[inline]
-    fn chunk_length5(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        exit_if_eos!(self, context);
-        bs_next!(context);
+    fn detect_body(&mut self, _handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        match self.framing.body(self.flags & F_HEAD_REQUEST == F_HEAD_REQUEST)? {
+            Body::Chunked => {
+                self.parser_type = ParserType::Chunked;
 
-        if is_hex!(context.byte) {
-            self.length <<= 4;
-            self.length  |= hex_to_byte!(context.byte) as usize;
+                transition!(
+                    self,
+                    context,
+                    ChunkLength1,
+                    chunk_length1
+                );
+            },
+            Body::Eof => {
+                self.parser_type = ParserType::Eof;
 
-            transition!(
-                self,
-                context,
-                ChunkLength6,
-                chunk_length6
-            );
-        }
+                transition!(
+                    self,
+                    context,
+                    BodyByEof,
+                    body_by_eof
+                );
+            },
+            Body::Length(length) => {
+                self.length      = length as u64;
+                self.parser_type = ParserType::Length;
 
-        bs_replay!(context);
+                transition!(
+                    self,
+                    context,
+                    BodyByLength,
+                    body_by_length
+                );
+            },
+            Body::Multipart(length) => {
+                self.bit_data         = 0;
+                self.flags           |= F_MULTIPART_LENGTH;
+                self.multipart_length = length as u64;
+                self.parser_type      = ParserType::Multipart;
 
-        transition!(
-            self,
-            context,
-            ChunkLengthCr,
-            chunk_length_cr
-        );
+                // lower14 == 1 when we expect a boundary, which is only the first boundary
+                set_lower14!(self, 1);
+
+                set_state!(self, MultipartHyphen1, multipart_hyphen1);
+
+                // multipart parsing must be limited to the content length, so the remaining
+                // stream is handed off by `resume()`
+                exit_eos!(self, context);
+            },
+            Body::None => {
+                transition!(
+                    self,
+                    context,
+                    MessageFinished,
+                    message_finished
+                );
+            },
+            Body::UrlEncoded(length) => {
+                self.length      = length as u64;
+                self.parser_type = ParserType::UrlEncoded;
+
+                set_state!(self, FirstUrlEncodedName, first_url_encoded_name);
+
+                // URL encoded parsing must be limited to the content length, so the remaining
+                // stream is handed off by `resume()`
+                exit_eos!(self, context);
+            }
+        }
     }
 
     #[inline]
-    fn chunk_length6(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        exit_if_eos!(self, context);
-        bs_next!(context);
+    fn interim(&mut self, handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        let status_code = self.framing.interim().unwrap_or(0);
 
-        if is_hex!(context.byte) {
-            self.length <<= 4;
-            self.length  |= hex_to_byte!(context.byte) as usize;
+        // the final response follows, and belongs to the same message
+        self.bit_data      = 0;
+        self.field_length  = 0;
+        self.region_length = 0;
 
-            transition!(
-                self,
-                context,
-                ChunkLength7,
-                chunk_length7
-            );
+        self.framing.reset();
+
+        set_state!(self, Detect1, detect1);
+
+        if proceed!(handler.on_interim(status_code)) {
+            transition!(self, context);
         }
 
-        bs_replay!(context);
+        exit_callback!(self, context);
+    }
 
-        transition!(
-            self,
-            context,
-            ChunkLengthCr,
-            chunk_length_cr
-        );
+    #[inline]
+    fn upgrade(&mut self, handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        set_state!(self, Upgraded, upgraded);
+
+        if proceed!(handler.on_upgrade()) {
+            transition!(self, context);
+        }
+
+        exit_callback!(self, context);
+    }
+
+    #[inline]
+    fn upgraded(&mut self, _handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        exit_upgrade!(self, context);
     }
 
+    // ---------------------------------------------------------------------------------------------
+    // CHUNK STATES
+    // ---------------------------------------------------------------------------------------------
+
     #[inline]
-    fn chunk_length7(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    fn chunk_length1(&mut self, handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        self.region_length = 0;
+
         exit_if_eos!(self, context);
         bs_next!(context);
 
-        if is_hex!(context.byte) {
-            self.length <<= 4;
-            self.length  |= hex_to_byte!(context.byte) as usize;
+        if context.byte == b'0' {
+            set_state!(self, ChunkLengthCr, chunk_length_cr);
+        } else if is_hex!(context.byte) {
+            self.length = hex_to_byte!(context.byte) as u64;
 
-            transition!(
-                self,
-                context,
-                ChunkLength8,
-                chunk_length8
-            );
+            set_state!(self, ChunkLength2, chunk_length2);
+        } else {
+            exit_error!(ChunkLength, context.byte);
         }
 
-        bs_replay!(context);
+        if proceed!(handler.on_chunk_begin()) {
+            transition!(self, context);
+        }
 
-        transition!(
-            self,
-            context,
-            ChunkLengthCr,
-            chunk_length_cr
-        );
+        exit_callback!(self, context);
     }
 
     #[inline]
-    fn chunk_length8(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        exit_if_eos!(self, context);
-        bs_next!(context);
+    fn chunk_length2(&mut self, _handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        loop {
+            exit_if_eos!(self, context);
+            bs_next!(context);
+
+            if !is_hex!(context.byte) {
+                bs_replay!(context);
+
+                transition!(
+                    self,
+                    context,
+                    ChunkLengthCr,
+                    chunk_length_cr
+                );
+            }
+
+            if self.length > u64::MAX >> 4 {
+                // another digit would overflow
+                exit_error!(ChunkLengthOverflow);
+            }
 
-        if is_hex!(context.byte) {
             self.length <<= 4;
-            self.length  |= hex_to_byte!(context.byte) as usize;
-        } else {
-            bs_replay!(context);
+            self.length  |= hex_to_byte!(context.byte) as u64;
         }
-
-        transition!(
-            self,
-            context,
-            ChunkLengthCr,
-            chunk_length_cr
-        );
     }
 
     #[inline]
     fn chunk_length_cr(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
+        if self.length > self.limits.max_chunk_size {
+            exit_error!(ChunkTooLarge);
+        }
+
         exit_if_eos!(self, context);
         bs_next!(context);
 
-        if context.byte == b'\r' {
+        if context.byte == b'\r' || (context.byte == b'\n' && self.flags & F_LENIENT == F_LENIENT) {
+            if context.byte == b'\n' {
+                // LF line ending, which is consumed by the LF state
+                bs_replay!(context);
+            }
+
             if self.length == 0 {
                 callback_transition!(
                     self,
@@ -2154,12 +3148,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::ChunkLength(context.byte))
+        Err(ErrorKind::ChunkLength(context.byte))
     }
 
     #[inline]
     fn chunk_length_lf(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -2172,12 +3166,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::CrlfSequence(context.byte))
+        Err(ErrorKind::CrlfSequence(context.byte))
     }
 
     #[inline]
     fn strip_chunk_extension_name(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         consume_linear_space!(
             context,
 
@@ -2197,12 +3191,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::ChunkExtensionName(context.byte))
+        Err(ErrorKind::ChunkExtensionName(context.byte))
     }
 
     #[inline]
     fn lower_chunk_extension_name(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         collect_tokens!(
             context,
 
@@ -2222,7 +3216,9 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                 StripChunkExtensionValue,
                 strip_chunk_extension_value
             );
-        } else if context.byte == b'\r' || context.byte == b';' {
+        } else if context.byte == b'\r'
+               || context.byte == b';'
+               || (context.byte == b'\n' && self.flags & F_LENIENT == F_LENIENT) {
             // extension name without a value
             bs_replay!(context);
 
@@ -2248,22 +3244,24 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::ChunkExtensionName(context.byte))
+        Err(ErrorKind::ChunkExtensionName(context.byte))
     }
 
     #[inline]
     fn upper_chunk_extension_name(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
         if context.byte > 0x40 && context.byte < 0x5B {
+            // the lower-cased byte is produced from the upper-cased byte
             callback_transition!(
                 self,
                 handler,
                 context,
                 on_chunk_extension_name,
                 &[context.byte + 0x20],
+                self.stream_offset + context.stream_index - 1,
                 LowerChunkExtensionName,
                 lower_chunk_extension_name
             );
@@ -2281,7 +3279,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn strip_chunk_extension_value(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         consume_linear_space!(
             context,
 
@@ -2301,12 +3299,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::ChunkExtensionValue(context.byte))
+        Err(ErrorKind::ChunkExtensionValue(context.byte))
     }
 
     #[inline]
     fn chunk_extension_value(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         collect_field!(
             context,
 
@@ -2317,7 +3315,9 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             callback_eos_expr!(self, handler, context, on_chunk_extension_value)
         );
 
-        if context.byte == b'\r' || context.byte == b';' {
+        if context.byte == b'\r'
+        || context.byte == b';'
+        || (context.byte == b'\n' && self.flags & F_LENIENT == F_LENIENT) {
             bs_replay!(context);
 
             callback_transition!(
@@ -2339,12 +3339,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::ChunkExtensionValue(context.byte))
+        Err(ErrorKind::ChunkExtensionValue(context.byte))
     }
 
     #[inline]
     fn chunk_extension_quoted_value(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         collect_quoted_field!(
             context,
 
@@ -2372,44 +3372,51 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::ChunkExtensionValue(context.byte))
+        Err(ErrorKind::ChunkExtensionValue(context.byte))
     }
 
     #[inline]
     fn chunk_extension_escaped_value(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
         // escaped bytes must be 7bit, and cannot be control characters
         if context.byte > 0x1F && context.byte < 0x7B {
+            // the escaped byte follows the backslash
             callback_transition!(
                 self,
                 handler,
                 context,
                 on_chunk_extension_value,
                 &[context.byte],
+                self.stream_offset + context.stream_index - 1,
                 ChunkExtensionQuotedValue,
                 chunk_extension_quoted_value
             );
         }
 
-        Err(ParserError::ChunkExtensionValue(context.byte))
+        Err(ErrorKind::ChunkExtensionValue(context.byte))
     }
 
     #[inline]
     fn chunk_extension_finished(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
         if context.byte == b'\r' {
+            set_state!(self, ChunkExtensionsFinished, chunk_extensions_finished);
+        } else if context.byte == b'\n' && self.flags & F_LENIENT == F_LENIENT {
+            // LF line ending, which is consumed by the chunk length LF state
+            bs_replay!(context);
+
             set_state!(self, ChunkExtensionsFinished, chunk_extensions_finished);
         } else {
             set_state!(self, StripChunkExtensionName, strip_chunk_extension_name);
         }
 
-        if handler.on_chunk_extension_finished() {
+        if proceed!(handler.on_chunk_extension_finished()) {
             transition!(self, context);
         }
 
@@ -2418,10 +3425,10 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn chunk_extensions_finished(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         set_state!(self, ChunkLengthLf, chunk_length_lf);
 
-        if handler.on_chunk_extensions_finished() {
+        if proceed!(handler.on_chunk_extensions_finished()) {
             transition!(
                 self,
                 context
@@ -2433,12 +3440,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn chunk_data(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
-        if bs_available!(context) >= self.length {
+        if bs_available!(context) as u64 >= self.length {
             // collect remaining chunk data
-            bs_collect_length!(context, self.length);
+            bs_collect_length!(context, self.length as usize);
 
             self.length = 0;
 
@@ -2453,7 +3460,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
         }
 
         // collect remaining stream data
-        self.length -= bs_available!(context);
+        self.length -= bs_available!(context) as u64;
 
         bs_collect_length!(context, bs_available!(context));
 
@@ -2469,7 +3476,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn chunk_data_cr(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -2480,14 +3487,22 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                 ChunkDataLf,
                 chunk_data_lf
             );
+        } else if context.byte == b'\n' && self.flags & F_LENIENT == F_LENIENT {
+            // LF line ending
+            transition!(
+                self,
+                context,
+                ChunkLength1,
+                chunk_length1
+            );
         }
 
-        Err(ParserError::CrlfSequence(context.byte))
+        Err(ErrorKind::CrlfSequence(context.byte))
     }
 
     #[inline]
     fn chunk_data_lf(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -2500,7 +3515,79 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::CrlfSequence(context.byte))
+        Err(ErrorKind::CrlfSequence(context.byte))
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // CONTENT LENGTH STATES
+    // ---------------------------------------------------------------------------------------------
+
+    #[inline]
+    fn body_by_length(&mut self, handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        if self.length == 0 {
+            // zero length body, or all data has been collected
+            transition!(
+                self,
+                context,
+                BodyFinished,
+                body_finished
+            );
+        }
+
+        exit_if_eos!(self, context);
+
+        if bs_available!(context) as u64 >= self.length {
+            // collect remaining body data
+            bs_collect_length!(context, self.length as usize);
+
+            self.length = 0;
+
+            callback_transition!(
+                self,
+                handler,
+                context,
+                on_body,
+                BodyFinished,
+                body_finished
+            );
+        }
+
+        // collect remaining stream data
+        self.length -= bs_available!(context) as u64;
+
+        bs_collect_length!(context, bs_available!(context));
+
+        callback_transition!(
+            self,
+            handler,
+            context,
+            on_body,
+            BodyByLength,
+            body_by_length
+        );
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // END OF INPUT STATES
+    // ---------------------------------------------------------------------------------------------
+
+    #[inline]
+    fn body_by_eof(&mut self, handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        exit_if_eos!(self, context);
+
+        // collect remaining stream data
+        bs_collect_length!(context, bs_available!(context));
+
+        callback_transition!(
+            self,
+            handler,
+            context,
+            on_body,
+            BodyByEof,
+            body_by_eof
+        );
     }
 
     // ---------------------------------------------------------------------------------------------
@@ -2509,7 +3596,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn multipart_hyphen1(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -2529,17 +3616,18 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                 context,
                 on_multipart_data,
                 &[b'\r', b'\n', context.byte],
+                self.stream_offset + context.stream_index - 3,
                 MultipartDataByByte,
                 multipart_data_by_byte
             );
         }
 
-        Err(ParserError::MultipartBoundary(context.byte))
+        Err(ErrorKind::MultipartBoundary(context.byte))
     }
 
     #[inline]
     fn multipart_hyphen2(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -2559,21 +3647,25 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                 context,
                 on_multipart_data,
                 &[b'\r', b'\n', b'-', context.byte],
+                self.stream_offset + context.stream_index - 4,
                 MultipartDataByByte,
                 multipart_data_by_byte
             );
         }
 
-        Err(ParserError::MultipartBoundary(context.byte))
+        Err(ErrorKind::MultipartBoundary(context.byte))
     }
 
     #[inline]
     fn multipart_boundary(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         let (length, callback_data, finished) = {
-            let boundary = self.boundary.unwrap();
+            let boundary = match self.boundary {
+                Some(boundary) => boundary,
+                None           => self.framing.boundary()
+            };
 
             let slice =
                 if boundary.len() - get_upper14!(self) as usize <= bs_available!(context) {
@@ -2659,14 +3751,14 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn multipart_boundary_cr(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
         if context.byte == b'\r' {
             set_state!(self, InitialLf, initial_lf);
 
-            if handler.on_multipart_begin() {
+            if proceed!(handler.on_multipart_begin()) {
                 transition!(self, context);
             }
 
@@ -2680,12 +3772,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::MultipartBoundary(context.byte))
+        Err(ErrorKind::MultipartBoundary(context.byte))
     }
 
     #[inline]
     fn multipart_boundary_lf(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -2698,14 +3790,14 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::MultipartBoundary(context.byte))
+        Err(ErrorKind::MultipartBoundary(context.byte))
     }
 
     #[inline]
     fn multipart_detect_data(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         if let Some(length) = handler.content_length() {
-            self.length = length;
+            self.length = length as u64;
 
             // expect boundary after data
             set_lower14!(self, 1);
@@ -2731,12 +3823,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn multipart_data_by_length(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
-        if bs_available!(context) >= self.length {
+        if bs_available!(context) as u64 >= self.length {
             // collect remaining multipart data
-            bs_collect_length!(context, self.length);
+            bs_collect_length!(context, self.length as usize);
 
             self.length = 0;
 
@@ -2751,7 +3843,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
         }
 
         // collect remaining stream data
-        self.length -= bs_available!(context);
+        self.length -= bs_available!(context) as u64;
 
         bs_collect_length!(context, bs_available!(context));
 
@@ -2767,7 +3859,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn multipart_data_by_length_cr(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -2782,12 +3874,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
         // this state is only used after multipart_data_by_length, so we can error if we don't
         // find the carriage return
-        Err(ParserError::MultipartBoundary(context.byte))
+        Err(ErrorKind::MultipartBoundary(context.byte))
     }
 
     #[inline]
     fn multipart_data_by_length_lf(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -2802,12 +3894,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
         // this state is only used after multipart_data_by_length, so we can error if we don't
         // find the carriage return
-        Err(ParserError::MultipartBoundary(context.byte))
+        Err(ErrorKind::MultipartBoundary(context.byte))
     }
 
     #[inline]
     fn multipart_data_by_byte(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         bs_collect_until!(
             context,
 
@@ -2830,7 +3922,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn multipart_data_by_byte_lf(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -2843,12 +3935,14 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
+        // the data is produced from the bytes that did not begin the boundary
         callback_transition!(
             self,
             handler,
             context,
             on_multipart_data,
             &[b'\r', context.byte],
+            self.stream_offset + context.stream_index - 2,
             MultipartDataByByte,
             multipart_data_by_byte
         );
@@ -2856,11 +3950,21 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn multipart_end(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
         if context.byte == b'-' {
+            if self.flags & F_MULTIPART_LENGTH == F_MULTIPART_LENGTH {
+                // the body finishes at the content length
+                transition!(
+                    self,
+                    context,
+                    MultipartEpilogue,
+                    multipart_epilogue
+                );
+            }
+
             transition!(
                 self,
                 context,
@@ -2869,7 +3973,16 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             );
         }
 
-        Err(ParserError::MultipartBoundary(context.byte))
+        Err(ErrorKind::MultipartBoundary(context.byte))
+    }
+
+    #[inline]
+    fn multipart_epilogue(&mut self, _handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        // data that follows the last boundary is ignored
+        bs_jump!(context, bs_available!(context));
+
+        exit_eos!(self, context);
     }
 
     // ---------------------------------------------------------------------------------------------
@@ -2878,12 +3991,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn first_url_encoded_name(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         bs_available!(context) > 0 || exit_eos!(self, context);
 
         set_state!(self, UrlEncodedName, url_encoded_name);
 
-        if handler.on_url_encoded_begin() {
+        if proceed!(handler.on_url_encoded_begin()) {
             transition!(
                 self,
                 context,
@@ -2897,7 +4010,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn url_encoded_name(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         collect_visible_7bit!(
             context,
 
@@ -2954,14 +4067,14 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                 );
             },
             _ => {
-                Err(ParserError::UrlEncodedName(context.byte))
+                Err(ErrorKind::UrlEncodedName(context.byte))
             }
         }
     }
 
     #[inline]
     fn url_encoded_name_hex1(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -2988,7 +4101,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn url_encoded_name_hex2(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -3005,12 +4118,14 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             }
         );
 
+        // the decoded byte is produced from the `%XX` sequence
         callback_transition!(
             self,
             handler,
             context,
             on_url_encoded_name,
             &[(get_upper14!(self) | get_lower14!(self)) as u8],
+            self.stream_offset + context.stream_index - 3,
             UrlEncodedName,
             url_encoded_name
         );
@@ -3018,13 +4133,15 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn url_encoded_name_plus(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
+        // the space is produced from the preceding `+`
         callback_transition!(
             self,
             handler,
             context,
             on_url_encoded_name,
             b" ",
+            self.stream_offset + context.stream_index - 1,
             UrlEncodedName,
             url_encoded_name
         );
@@ -3032,7 +4149,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn url_encoded_value(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         collect_visible_7bit!(
             context,
 
@@ -3078,14 +4195,14 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
                 );
             },
             _ => {
-                Err(ParserError::UrlEncodedValue(context.byte))
+                Err(ErrorKind::UrlEncodedValue(context.byte))
             }
         }
     }
 
     #[inline]
     fn url_encoded_value_hex1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -3112,7 +4229,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn url_encoded_value_hex2(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
         bs_next!(context);
 
@@ -3129,12 +4246,14 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             }
         );
 
+        // the decoded byte is produced from the `%XX` sequence
         callback_transition!(
             self,
             handler,
             context,
             on_url_encoded_value,
             &[(get_upper14!(self) | get_lower14!(self)) as u8],
+            self.stream_offset + context.stream_index - 3,
             UrlEncodedValue,
             url_encoded_value
         );
@@ -3142,13 +4261,15 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn url_encoded_value_plus(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
+        // the space is produced from the preceding `+`
         callback_transition!(
             self,
             handler,
             context,
             on_url_encoded_value,
             b" ",
+            self.stream_offset + context.stream_index - 1,
             UrlEncodedValue,
             url_encoded_value
         );
@@ -3160,16 +4281,40 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn dead(&mut self, _handler: &mut T, _context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_error!(Dead);
     }
 
     #[inline]
     fn body_finished(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        set_state!(self, Finished, finished);
+    -> Result<ParserValue, ErrorKind<E>> {
+        if self.flags & F_MESSAGE == F_MESSAGE {
+            set_state!(self, MessageFinished, message_finished);
+        } else {
+            set_state!(self, Finished, finished);
+        }
+
+        if proceed!(handler.on_body_finished()) {
+            transition!(self, context);
+        }
+
+        exit_callback!(self, context);
+    }
+
+    #[inline]
+    fn message_finished(&mut self, handler: &mut T, context: &mut ByteStream)
+    -> Result<ParserValue, ErrorKind<E>> {
+        // connection closes after a body that is delimited by the end of input
+        let keep_alive = self.parser_type != ParserType::Eof && self.framing.is_keep_alive();
+
+        if keep_alive && self.flags & F_PERSISTENT == F_PERSISTENT {
+            // parse the next message
+            self.reset();
+        } else {
+            set_state!(self, Finished, finished);
+        }
 
-        if handler.on_body_finished() {
+        if proceed!(handler.on_message_finished(keep_alive)) {
             transition!(self, context);
         }
 
@@ -3178,7 +4323,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn finished(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_finished!(self, context);
     }
 }
@@ -14,53 +14,124 @@
 // | limitations under the License.                                                                |
 // +-----------------------------------------------------------------------------------------------+
 
-use std::fmt;
+use excerpt::{ excerpt, write_excerpt };
+use http1::parser_state::ParserState;
 
-/// Parser error messages.
+use std::{ error, fmt };
+
+/// Parser error kinds.
+///
+/// `E` is the error type of the handler, which aborts parsing with `Action::Abort`.
 #[derive(Clone,Copy,PartialEq)]
-pub enum ParserError {
+pub enum ErrorKind<E = ()> {
     /// Invalid chunk extension name on byte `u8`.
     ChunkExtensionName(u8),
 
     /// Invalid chunk extension value on byte `u8`.
     ChunkExtensionValue(u8),
 
+    /// Chunk extension length limit exceeded.
+    ChunkExtensionTooLong,
+
+    /// The `chunked` transfer coding is missing, repeated, or not the final transfer coding.
+    ChunkedNotLast,
+
     /// Invalid chunk length on byte `u8`.
     ChunkLength(u8),
 
+    /// Chunk size limit exceeded.
+    ChunkTooLarge,
+
+    /// Invalid content length on byte `u8`.
+    ContentLength(u8),
+
+    /// Multiple content lengths that differ.
+    ContentLengthMismatch,
+
+    /// Content length overflow.
+    ContentLengthOverflow,
+
     /// Invalid CRLF sequence on byte `u8`.
     CrlfSequence(u8),
 
     /// Parsing has failed.
     Dead,
 
+    /// Trailer that is not allowed to be sent as a trailer, such as `content-length`.
+    ForbiddenTrailer,
+
+    /// Handler aborted parsing with error `E`.
+    Handler(E),
+
+    /// Head size limit exceeded.
+    HeadTooLarge,
+
     /// Invalid header name on byte `u8`.
     HeaderName(u8),
 
+    /// Header name length limit exceeded.
+    HeaderNameTooLong,
+
+    /// Whitespace between a header name and the colon.
+    HeaderNameWhitespace,
+
     /// Invalid header value on byte `u8`.
     HeaderValue(u8),
 
+    /// Header value length limit exceeded.
+    HeaderValueTooLong,
+
     /// Chunk length overflow.
     ChunkLengthOverflow,
 
     /// Invalid request method on byte `u8`.
     Method(u8),
 
+    /// Method length limit exceeded.
+    MethodTooLong,
+
     /// Invalid multipart data.
     Multipart(u8),
 
     /// Invalid multipart boundary.
     MultipartBoundary(u8),
 
+    /// Multipart body did not end within the content length.
+    MultipartLength,
+
+    /// Obsolete line folding within a header value.
+    ObsoleteLineFolding,
+
     /// Invalid status on byte `u8`.
     Status(u8),
 
     /// Invalid status code on byte `u8`.
     StatusCode(u8),
 
+    /// Header count limit exceeded.
+    TooManyHeaders,
+
+    /// Both transfer encoding and content length are present.
+    TransferEncodingWithContentLength,
+
+    /// Trailer that was not announced by the `Trailer` header.
+    UnannouncedTrailer,
+
+    /// Input ended before the message was complete.
+    UnexpectedEof,
+
+    /// A request was found where a response is expected.
+    UnexpectedRequest,
+
+    /// A response was found where a request is expected.
+    UnexpectedResponse,
+
     /// Invalid URL character on byte `u8`.
     Url(u8),
 
+    /// URL length limit exceeded.
+    UrlTooLong,
+
     /// Invalid URL encoded name on byte `u8`.
     UrlEncodedName(u8),
 
@@ -71,124 +142,287 @@ pub enum ParserError {
     Version(u8),
 }
 
-impl ParserError {
+impl<E> ErrorKind<E> {
+    /// Retrieve the offending byte, if this kind of error occurs on a byte.
+    pub fn byte(&self) -> Option<u8> {
+        match *self {
+            ErrorKind::ChunkExtensionName(byte)
+            | ErrorKind::ChunkExtensionValue(byte)
+            | ErrorKind::ChunkLength(byte)
+            | ErrorKind::ContentLength(byte)
+            | ErrorKind::CrlfSequence(byte)
+            | ErrorKind::HeaderName(byte)
+            | ErrorKind::HeaderValue(byte)
+            | ErrorKind::Method(byte)
+            | ErrorKind::Multipart(byte)
+            | ErrorKind::MultipartBoundary(byte)
+            | ErrorKind::Status(byte)
+            | ErrorKind::StatusCode(byte)
+            | ErrorKind::Url(byte)
+            | ErrorKind::UrlEncodedName(byte)
+            | ErrorKind::UrlEncodedValue(byte)
+            | ErrorKind::Version(byte) => {
+                Some(byte)
+            },
+            _ => {
+                None
+            }
+        }
+    }
+}
+
+impl<E: fmt::Debug> ErrorKind<E> {
     /// Format this for debug and display purposes.
     fn format(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            ParserError::ChunkExtensionName(byte) => {
+            ErrorKind::ChunkExtensionName(byte) => {
                 write!(
                     formatter,
-                    "<ParserError::ChunkExtensionName: {}>",
+                    "<ErrorKind::ChunkExtensionName: {}>",
                     byte
                 )
             },
-            ParserError::ChunkExtensionValue(byte) => {
+            ErrorKind::ChunkExtensionValue(byte) => {
+                write!(
+                    formatter,
+                    "<ErrorKind::ChunkExtensionValue: {}>",
+                    byte
+                )
+            },
+            ErrorKind::ChunkExtensionTooLong => {
+                write!(
+                    formatter,
+                    "<ErrorKind::ChunkExtensionTooLong>"
+                )
+            },
+            ErrorKind::ChunkedNotLast => {
                 write!(
                     formatter,
-                    "<ParserError::ChunkExtensionValue: {}>",
+                    "<ErrorKind::ChunkedNotLast>"
+                )
+            },
+            ErrorKind::ChunkLength(byte) => {
+                write!(
+                    formatter,
+                    "<ErrorKind::ChunkLength: {}>",
                     byte
                 )
             },
-            ParserError::ChunkLength(byte) => {
+            ErrorKind::ChunkLengthOverflow => {
+                write!(
+                    formatter,
+                    "<ErrorKind::ChunkLengthOverflow>"
+                )
+            },
+            ErrorKind::ChunkTooLarge => {
+                write!(
+                    formatter,
+                    "<ErrorKind::ChunkTooLarge>"
+                )
+            },
+            ErrorKind::ContentLength(byte) => {
                 write!(
                     formatter,
-                    "<ParserError::ChunkLength: {}>",
+                    "<ErrorKind::ContentLength: {}>",
                     byte
                 )
             },
-            ParserError::ChunkLengthOverflow => {
+            ErrorKind::ContentLengthMismatch => {
+                write!(
+                    formatter,
+                    "<ErrorKind::ContentLengthMismatch>"
+                )
+            },
+            ErrorKind::ContentLengthOverflow => {
                 write!(
                     formatter,
-                    "<ParserError::ChunkLengthOverflow>"
+                    "<ErrorKind::ContentLengthOverflow>"
                 )
             },
-            ParserError::CrlfSequence(byte) => {
+            ErrorKind::CrlfSequence(byte) => {
                 write!(
                     formatter,
-                    "<ParserError::CrlfSequence: {}>",
+                    "<ErrorKind::CrlfSequence: {}>",
                     byte
                 )
             },
-            ParserError::Dead => {
+            ErrorKind::Dead => {
+                write!(
+                    formatter,
+                    "<ErrorKind::Dead>"
+                )
+            },
+            ErrorKind::ForbiddenTrailer => {
                 write!(
                     formatter,
-                    "<ParserError::Dead>"
+                    "<ErrorKind::ForbiddenTrailer>"
                 )
             },
-            ParserError::HeaderName(byte) => {
+            ErrorKind::Handler(ref error) => {
                 write!(
                     formatter,
-                    "<ParserError::HeaderName: {}>",
+                    "<ErrorKind::Handler: {:?}>",
+                    error
+                )
+            },
+            ErrorKind::HeadTooLarge => {
+                write!(
+                    formatter,
+                    "<ErrorKind::HeadTooLarge>"
+                )
+            },
+            ErrorKind::HeaderName(byte) => {
+                write!(
+                    formatter,
+                    "<ErrorKind::HeaderName: {}>",
                     byte
                 )
             },
-            ParserError::HeaderValue(byte) => {
+            ErrorKind::HeaderNameTooLong => {
                 write!(
                     formatter,
-                    "<ParserError::HeaderValue: {}>",
+                    "<ErrorKind::HeaderNameTooLong>"
+                )
+            },
+            ErrorKind::HeaderNameWhitespace => {
+                write!(
+                    formatter,
+                    "<ErrorKind::HeaderNameWhitespace>"
+                )
+            },
+            ErrorKind::HeaderValue(byte) => {
+                write!(
+                    formatter,
+                    "<ErrorKind::HeaderValue: {}>",
                     byte
                 )
             },
-            ParserError::Method(byte) => {
+            ErrorKind::HeaderValueTooLong => {
+                write!(
+                    formatter,
+                    "<ErrorKind::HeaderValueTooLong>"
+                )
+            },
+            ErrorKind::Method(byte) => {
                 write!(
                     formatter,
-                    "<ParserError::Method: {}>",
+                    "<ErrorKind::Method: {}>",
                     byte
                 )
             },
-            ParserError::Multipart(byte) => {
+            ErrorKind::MethodTooLong => {
+                write!(
+                    formatter,
+                    "<ErrorKind::MethodTooLong>"
+                )
+            },
+            ErrorKind::Multipart(byte) => {
                 write!(
                     formatter,
-                    "<ParserError::Multipart: {}>",
+                    "<ErrorKind::Multipart: {}>",
                     byte
                 )
             },
-            ParserError::MultipartBoundary(byte) => {
+            ErrorKind::MultipartBoundary(byte) => {
                 write!(
                     formatter,
-                    "<ParserError::MultipartBoundary: {}>",
+                    "<ErrorKind::MultipartBoundary: {}>",
                     byte
                 )
             },
-            ParserError::Status(byte) => {
+            ErrorKind::MultipartLength => {
+                write!(
+                    formatter,
+                    "<ErrorKind::MultipartLength>"
+                )
+            },
+            ErrorKind::ObsoleteLineFolding => {
+                write!(
+                    formatter,
+                    "<ErrorKind::ObsoleteLineFolding>"
+                )
+            },
+            ErrorKind::Status(byte) => {
                 write!(
                     formatter,
-                    "<ParserError::Status: {}>",
+                    "<ErrorKind::Status: {}>",
                     byte
                 )
             },
-            ParserError::StatusCode(byte) => {
+            ErrorKind::StatusCode(byte) => {
                 write!(
                     formatter,
-                    "<ParserError::StatusCode: {}>",
+                    "<ErrorKind::StatusCode: {}>",
                     byte
                 )
             },
-            ParserError::Url(byte) => {
+            ErrorKind::TooManyHeaders => {
+                write!(
+                    formatter,
+                    "<ErrorKind::TooManyHeaders>"
+                )
+            },
+            ErrorKind::TransferEncodingWithContentLength => {
                 write!(
                     formatter,
-                    "<ParserError::Url: {}>",
+                    "<ErrorKind::TransferEncodingWithContentLength>"
+                )
+            },
+            ErrorKind::UnannouncedTrailer => {
+                write!(
+                    formatter,
+                    "<ErrorKind::UnannouncedTrailer>"
+                )
+            },
+            ErrorKind::UnexpectedEof => {
+                write!(
+                    formatter,
+                    "<ErrorKind::UnexpectedEof>"
+                )
+            },
+            ErrorKind::UnexpectedRequest => {
+                write!(
+                    formatter,
+                    "<ErrorKind::UnexpectedRequest>"
+                )
+            },
+            ErrorKind::UnexpectedResponse => {
+                write!(
+                    formatter,
+                    "<ErrorKind::UnexpectedResponse>"
+                )
+            },
+            ErrorKind::Url(byte) => {
+                write!(
+                    formatter,
+                    "<ErrorKind::Url: {}>",
                     byte
                 )
             },
-            ParserError::UrlEncodedName(byte) => {
+            ErrorKind::UrlTooLong => {
+                write!(
+                    formatter,
+                    "<ErrorKind::UrlTooLong>"
+                )
+            },
+            ErrorKind::UrlEncodedName(byte) => {
                 write!(
                     formatter,
-                    "<ParserError::UrlEncodedName: {}>",
+                    "<ErrorKind::UrlEncodedName: {}>",
                     byte
                 )
             },
-            ParserError::UrlEncodedValue(byte) => {
+            ErrorKind::UrlEncodedValue(byte) => {
                 write!(
                     formatter,
-                    "<ParserError::UrlEncodedValue: {}>",
+                    "<ErrorKind::UrlEncodedValue: {}>",
                     byte
                 )
             },
-            ParserError::Version(byte) => {
+            ErrorKind::Version(byte) => {
                 write!(
                     formatter,
-                    "<ParserError::Version: {}>",
+                    "<ErrorKind::Version: {}>",
                     byte
                 )
             }
@@ -196,14 +430,149 @@ impl ParserError {
     }
 }
 
-impl fmt::Debug for ParserError {
+impl<E: fmt::Debug> fmt::Debug for ErrorKind<E> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         self.format(formatter)
     }
 }
 
-impl fmt::Display for ParserError {
+impl<E: fmt::Debug> fmt::Display for ErrorKind<E> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         self.format(formatter)
     }
 }
+
+impl<E: fmt::Debug> error::Error for ErrorKind<E> {
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Parser error.
+///
+/// Along with the kind of error, this carries the absolute offset of the stream at which the error
+/// occurred, the state the parser was in, and an excerpt of the bytes surrounding the offset. The
+/// excerpt is limited to the slice of data that was being parsed, and is empty when there is no
+/// data to show, such as when the input ends prematurely.
+///
+/// The `Display` implementation shows the excerpt, with a caret beneath the offending byte.
+#[derive(Clone,PartialEq)]
+pub struct ParserError<E = ()> {
+    /// Bytes surrounding the offset.
+    excerpt: Vec<u8>,
+
+    /// Index of the offset within the excerpt.
+    excerpt_index: usize,
+
+    /// Kind of error.
+    kind: ErrorKind<E>,
+
+    /// Absolute stream offset.
+    offset: usize,
+
+    /// Parser state in which the error occurred.
+    state: ParserState
+}
+
+impl<E> ParserError<E> {
+    /// Create a new `ParserError`.
+    ///
+    /// # Arguments
+    ///
+    /// **`kind`**
+    ///
+    /// The kind of error.
+    ///
+    /// **`state`**
+    ///
+    /// The parser state in which the error occurred.
+    ///
+    /// **`stream`**
+    ///
+    /// The stream of data that was being parsed.
+    ///
+    /// **`index`**
+    ///
+    /// The index within `stream` at which the error occurred.
+    ///
+    /// **`stream_offset`**
+    ///
+    /// The absolute offset of `stream`.
+    pub fn new(kind: ErrorKind<E>, state: ParserState, stream: &[u8], index: usize,
+               stream_offset: usize) -> ParserError<E> {
+        let (excerpt, excerpt_index) = excerpt(stream, index);
+
+        ParserError{
+            excerpt,
+            excerpt_index,
+            kind,
+            offset: stream_offset + index,
+            state
+        }
+    }
+
+    /// Retrieve the bytes surrounding the offset.
+    pub fn excerpt(&self) -> &[u8] {
+        &self.excerpt
+    }
+
+    /// Retrieve the index of the offset within the excerpt.
+    ///
+    /// When the index is equal to the excerpt length, the error occurred after the last byte of
+    /// the excerpt.
+    pub fn excerpt_index(&self) -> usize {
+        self.excerpt_index
+    }
+
+    /// Consume this, and retrieve the kind of error.
+    ///
+    /// This is useful to take ownership of the error of an `ErrorKind::Handler`.
+    pub fn into_kind(self) -> ErrorKind<E> {
+        self.kind
+    }
+
+    /// Retrieve the kind of error.
+    pub fn kind(&self) -> &ErrorKind<E> {
+        &self.kind
+    }
+
+    /// Retrieve the absolute stream offset at which the error occurred.
+    ///
+    /// When the error occurred on a byte, this is the offset of that byte.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Retrieve the parser state in which the error occurred.
+    pub fn state(&self) -> ParserState {
+        self.state
+    }
+}
+
+impl<E: fmt::Debug> fmt::Debug for ParserError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "<ParserError: {:?}, offset: {}, state: {:?}>",
+            self.kind,
+            self.offset,
+            self.state
+        )
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for ParserError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} at offset {} in state {:?}",
+            self.kind,
+            self.offset,
+            self.state
+        )?;
+
+        write_excerpt(formatter, &self.excerpt, self.excerpt_index)
+    }
+}
+
+impl<E: fmt::Debug> error::Error for ParserError<E> {
+}
@@ -0,0 +1,34 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+//! HTTP 1.x parser modes.
+
+/// Parser conformance mode.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum ParserMode {
+    /// Lenient mode accepts LF line endings without the preceding CR, empty header values, and
+    /// response status lines without a reason phrase, as sent by some embedded devices.
+    Lenient,
+
+    /// Normal mode, which is the default.
+    Normal,
+
+    /// Strict mode follows [RFC 9112](https://tools.ietf.org/html/rfc9112), and rejects obsolete
+    /// line folding, obsolete text within header values, and white space preceding the request
+    /// or status line. LF line endings without the preceding CR are rejected, as they are in
+    /// normal mode.
+    Strict
+}
@@ -48,6 +48,9 @@ pub enum ParserState {
     /// Parsing request method.
     RequestMethod,
 
+    /// Processing the request method once it has been parsed.
+    RequestMethodFinished,
+
     /// Parsing request URL byte 1.
     RequestUrl1,
 
@@ -161,6 +164,9 @@ pub enum ParserState {
     /// Parsing lower-cased header name.
     LowerHeaderName,
 
+    /// Processing the header name once it has been parsed.
+    HeaderNameFinished,
+
     /// Stripping linear white space before header value.
     StripHeaderValue,
 
@@ -188,6 +194,15 @@ pub enum ParserState {
     /// Processing end-of-header flag checks.
     HeaderEnd,
 
+    /// Detecting message body type.
+    DetectBody,
+
+    /// Processing an interim response, which is followed by the final response.
+    Interim,
+
+    /// Switching the connection to another protocol.
+    Upgrade,
+
     // ---------------------------------------------------------------------------------------------
     // CHUNKED TRANSFER
     // ---------------------------------------------------------------------------------------------
@@ -195,25 +210,31 @@ pub enum ParserState {
     /// Parsing chunk length byte 1.
     ChunkLength1,
 
-    /// Parsing chunk length byte 2.
+    /// Parsing chunk length byte 2+.
     ChunkLength2,
 
     /// Parsing chunk length byte 3.
+    #[deprecated(since = "0.1.7", note = "chunk length bytes 2+ are parsed by `ChunkLength2`")]
     ChunkLength3,
 
     /// Parsing chunk length byte 4.
+    #[deprecated(since = "0.1.7", note = "chunk length bytes 2+ are parsed by `ChunkLength2`")]
     ChunkLength4,
 
     /// Parsing chunk length byte 5.
+    #[deprecated(since = "0.1.7", note = "chunk length bytes 2+ are parsed by `ChunkLength2`")]
     ChunkLength5,
 
     /// Parsing chunk length byte 6.
+    #[deprecated(since = "0.1.7", note = "chunk length bytes 2+ are parsed by `ChunkLength2`")]
     ChunkLength6,
 
     /// Parsing chunk length byte 7.
+    #[deprecated(since = "0.1.7", note = "chunk length bytes 2+ are parsed by `ChunkLength2`")]
     ChunkLength7,
 
     /// Parsing chunk length byte 8.
+    #[deprecated(since = "0.1.7", note = "chunk length bytes 2+ are parsed by `ChunkLength2`")]
     ChunkLength8,
 
     /// Parsing chunk length carriage return or semi-colon.
@@ -298,6 +319,24 @@ pub enum ParserState {
     /// Parsing last boundary second hyphen that indicates end of multipart body.
     MultipartEnd,
 
+    /// Skipping the epilogue that follows the last boundary of a multipart body that is limited
+    /// to the content length.
+    MultipartEpilogue,
+
+    // ---------------------------------------------------------------------------------------------
+    // CONTENT LENGTH
+    // ---------------------------------------------------------------------------------------------
+
+    /// Parsing body data by content length.
+    BodyByLength,
+
+    // ---------------------------------------------------------------------------------------------
+    // END OF INPUT
+    // ---------------------------------------------------------------------------------------------
+
+    /// Parsing body data until the end of input.
+    BodyByEof,
+
     // ---------------------------------------------------------------------------------------------
     // URL ENCODED
     // ---------------------------------------------------------------------------------------------
@@ -336,8 +375,14 @@ pub enum ParserState {
     /// End of body parsing.
     BodyFinished,
 
+    /// Processing end-of-message and keep-alive checks.
+    MessageFinished,
+
     /// Parsing entire message has finished.
-    Finished
+    Finished,
+
+    /// Parsing head has finished, and the connection has switched to another protocol.
+    Upgraded
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -399,6 +444,13 @@ pub enum State {
     /// Chunk data.
     ChunkData,
 
+    // ---------------------------------------------------------------------------------------------
+    // CONTENT LENGTH STATES
+    // ---------------------------------------------------------------------------------------------
+
+    /// Body data.
+    Body,
+
     // ---------------------------------------------------------------------------------------------
     // MULTIPART STATES
     // ---------------------------------------------------------------------------------------------
@@ -17,13 +17,20 @@
 //! HTTP 1.x parser types.
 
 /// Parser type.
+#[derive(Clone,Copy,Debug,PartialEq)]
 pub enum ParserType {
     /// Chunk transfer encoding parser type.
     Chunked,
 
+    /// End of input delimited parser type.
+    Eof,
+
     /// Head parser type.
     Head,
 
+    /// Content length parser type.
+    Length,
+
     /// Multipart parser type.
     Multipart,
 
@@ -0,0 +1,163 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::{ Action, Success };
+use http1::*;
+
+#[derive(Clone,Copy,Debug,PartialEq)]
+enum Policy {
+    ForbiddenHeader,
+    Unauthorized
+}
+
+#[derive(Default)]
+struct X {
+    name:     Vec<u8>,
+    pause:    bool,
+    raw:      bool,
+    raw_data: Vec<u8>,
+    value:    bool
+}
+
+impl HttpHandler<Policy> for X {
+    fn on_header_name(&mut self, name: &[u8]) -> Action<Policy> {
+        if self.value {
+            self.name.clear();
+        }
+
+        self.name.extend_from_slice(name);
+        self.value = false;
+
+        if self.name == b"x-forbidden" {
+            Action::Abort(Policy::ForbiddenHeader)
+        } else {
+            Action::Continue
+        }
+    }
+
+    fn on_header_value(&mut self, value: &[u8]) -> Action<Policy> {
+        self.value = true;
+
+        if self.name == b"authorization" && value != b"token" {
+            Action::Abort(Policy::Unauthorized)
+        } else if self.pause {
+            Action::Pause
+        } else {
+            Action::Continue
+        }
+    }
+
+    fn on_raw(&mut self, data: &[u8]) -> Action<Policy> {
+        self.raw_data.extend_from_slice(data);
+
+        if self.raw && self.raw_data.ends_with(b"X-Secret:") {
+            Action::Abort(Policy::ForbiddenHeader)
+        } else {
+            Action::Continue
+        }
+    }
+}
+
+#[test]
+fn abort() {
+    let mut h = X::default();
+    let mut p = Parser::new();
+
+    match p.resume(&mut h, b"GET / HTTP/1.1\r\nAuthorization: nope\r\n\r\n").map_err(ParserError::into_kind) {
+        Err(ErrorKind::Handler(Policy::Unauthorized)) => {},
+        _ => panic!()
+    }
+
+    assert_eq!(p.state(), ParserState::Dead);
+
+    match p.resume(&mut h, b"\r\n").map_err(ParserError::into_kind) {
+        Err(ErrorKind::Dead) => {},
+        _ => panic!()
+    }
+}
+
+#[test]
+fn abort_known_header() {
+    let mut h = X::default();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    match p.resume(&mut h, b"POST / HTTP/1.1\r\nX-Forbidden: 1\r\n\r\n").map_err(ParserError::into_kind) {
+        Err(ErrorKind::Handler(Policy::ForbiddenHeader)) => {},
+        _ => panic!()
+    }
+
+    assert_eq!(p.state(), ParserState::Dead);
+}
+
+#[test]
+fn abort_raw() {
+    let mut h = X::default();
+    let mut p = Parser::new();
+
+    h.raw = true;
+
+    p.set_raw_passthrough(true);
+
+    match p.resume(&mut h, b"GET / HTTP/1.1\r\nX-Secret: 1\r\n\r\n").map_err(ParserError::into_kind) {
+        Err(ErrorKind::Handler(Policy::ForbiddenHeader)) => {},
+        _ => panic!()
+    }
+
+    assert_eq!(p.state(), ParserState::Dead);
+}
+
+#[test]
+fn continue_() {
+    let mut h = X::default();
+    let mut p = Parser::new();
+
+    match p.resume(&mut h, b"GET / HTTP/1.1\r\nAuthorization: token\r\n\r\n") {
+        Ok(Success::Finished(40)) => {},
+        _ => panic!()
+    }
+}
+
+#[test]
+fn format() {
+    assert_eq!(
+        format!("{}", ErrorKind::Handler(Policy::Unauthorized)),
+        "<ErrorKind::Handler: Unauthorized>"
+    );
+}
+
+#[test]
+fn pause() {
+    let mut h = X::default();
+    let mut p = Parser::new();
+
+    h.pause = true;
+
+    match p.resume(&mut h, b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n") {
+        Ok(Success::Callback(32)) => {},
+        _ => panic!()
+    }
+
+    assert_eq!(p.state(), ParserState::HeaderLf1);
+
+    h.pause = false;
+
+    match p.resume(&mut h, b"\n\r\n") {
+        Ok(Success::Finished(3)) => {},
+        _ => panic!()
+    }
+}
@@ -14,6 +14,7 @@
 // | limitations under the License.                                                                |
 // +-----------------------------------------------------------------------------------------------+
 
+use fsm::Action;
 use http1::*;
 use http1::test::*;
 
@@ -21,8 +22,8 @@ use http1::test::*;
 fn on_chunk_begin() {
     struct H;
     impl HttpHandler for H {
-        fn on_chunk_begin(&mut self) -> bool {
-            false
+        fn on_chunk_begin(&mut self) -> Action {
+            Action::Pause
         }
     }
 
@@ -44,8 +45,8 @@ fn on_chunk_begin() {
 fn on_chunk_data() {
     struct H;
     impl HttpHandler for H {
-        fn on_chunk_data(&mut self, _: &[u8]) -> bool {
-            false
+        fn on_chunk_data(&mut self, _: &[u8]) -> Action {
+            Action::Pause
         }
     }
 
@@ -75,8 +76,8 @@ fn on_chunk_data() {
 fn on_chunk_extension_finished() {
     struct H;
     impl HttpHandler for H {
-        fn on_chunk_extension_finished(&mut self) -> bool {
-            false
+        fn on_chunk_extension_finished(&mut self) -> Action {
+            Action::Pause
         }
     }
 
@@ -98,8 +99,8 @@ fn on_chunk_extension_finished() {
 fn on_chunk_extension_name() {
     struct H;
     impl HttpHandler for H {
-        fn on_chunk_extension_name(&mut self, _: &[u8]) -> bool {
-            false
+        fn on_chunk_extension_name(&mut self, _: &[u8]) -> Action {
+            Action::Pause
         }
     }
 
@@ -121,8 +122,8 @@ fn on_chunk_extension_name() {
 fn on_chunk_extension_value() {
     struct H;
     impl HttpHandler for H {
-        fn on_chunk_extension_value(&mut self, _: &[u8]) -> bool {
-            false
+        fn on_chunk_extension_value(&mut self, _: &[u8]) -> Action {
+            Action::Pause
         }
     }
 
@@ -144,8 +145,8 @@ fn on_chunk_extension_value() {
 fn on_chunk_extensions_finished() {
     struct H;
     impl HttpHandler for H {
-        fn on_chunk_extensions_finished(&mut self) -> bool {
-            false
+        fn on_chunk_extensions_finished(&mut self) -> Action {
+            Action::Pause
         }
     }
 
@@ -167,8 +168,8 @@ fn on_chunk_extensions_finished() {
 fn on_chunk_length() {
     struct H;
     impl HttpHandler for H {
-        fn on_chunk_length(&mut self, _: usize) -> bool {
-            false
+        fn on_chunk_length(&mut self, _: u64) -> Action {
+            Action::Pause
         }
     }
 
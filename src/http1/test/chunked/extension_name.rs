@@ -91,7 +91,7 @@ fn not_allowed() {
             &mut p,
             &mut h,
             &[*b],
-            ParserError::ChunkExtensionName(*b)
+            ErrorKind::ChunkExtensionName(*b)
         );
     }
 }
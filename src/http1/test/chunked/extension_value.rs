@@ -55,7 +55,7 @@ fn allowed() {
 #[test]
 fn allowed_escaped() {
     // escaped data can only be 7bit non-control
-    for b in (0..255).filter(|&x| x > 0x1F && x < 0x7B) {
+    for b in (0..=255).filter(|&x| x > 0x1F && x < 0x7B) {
         let (mut p, mut h) = setup!();
 
         assert_eos(
@@ -181,17 +181,17 @@ fn entire_iter() {
 #[test]
 fn not_allowed_header_fields_error() {
     // skip `\r`, `;` and `"`, otherwise state will change
-    for b in (0..255).filter(|&x| !is_header_field(x))
-                     .filter(|&x| x != b'\r')
-                     .filter(|&x| x != b';')
-                     .filter(|&x| x != b'"') {
+    for b in (0..=255).filter(|&x| !is_header_field(x))
+                      .filter(|&x| x != b'\r')
+                      .filter(|&x| x != b';')
+                      .filter(|&x| x != b'"') {
         let (mut p, mut h) = setup!();
 
         assert_error(
             &mut p,
             &mut h,
             &[b],
-            ParserError::ChunkExtensionValue(b)
+            ErrorKind::ChunkExtensionValue(b)
         );
     }
 }
@@ -199,9 +199,9 @@ fn not_allowed_header_fields_error() {
 #[test]
 fn not_allowed_quoted_header_fields_error() {
     // skip `"` and `\`, otherwise state will change
-    for b in (0..255).filter(|&x| !is_quoted_header_field(x))
-                     .filter(|&x| x != b'"')
-                     .filter(|&x| x != b'\\') {
+    for b in (0..=255).filter(|&x| !is_quoted_header_field(x))
+                      .filter(|&x| x != b'"')
+                      .filter(|&x| x != b'\\') {
         let (mut p, mut h) = setup!();
 
         assert_eos(
@@ -216,7 +216,7 @@ fn not_allowed_quoted_header_fields_error() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::ChunkExtensionValue(b)
+            ErrorKind::ChunkExtensionValue(b)
         );
     }
 }
@@ -179,13 +179,13 @@ fn entire_iter() {
         &mut p,
         &mut h,
         &[(b'F', ParserState::ChunkLength2),
-          (b'F', ParserState::ChunkLength3),
-          (b'F', ParserState::ChunkLength4),
-          (b'F', ParserState::ChunkLength5),
-          (b'F', ParserState::ChunkLength6),
-          (b'F', ParserState::ChunkLength7),
-          (b'F', ParserState::ChunkLength8),
-          (b'F', ParserState::ChunkLengthCr),
+          (b'F', ParserState::ChunkLength2),
+          (b'F', ParserState::ChunkLength2),
+          (b'F', ParserState::ChunkLength2),
+          (b'F', ParserState::ChunkLength2),
+          (b'F', ParserState::ChunkLength2),
+          (b'F', ParserState::ChunkLength2),
+          (b'F', ParserState::ChunkLength2),
           (b'\r', ParserState::ChunkLengthLf)]
     );
 
@@ -204,7 +204,7 @@ fn not_allowed_hex_error1() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::ChunkLength(b)
+            ErrorKind::ChunkLength(b)
         );
     }
 }
@@ -229,7 +229,7 @@ fn not_allowed_hex_error2() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::ChunkLength(b)
+            ErrorKind::ChunkLength(b)
         );
     }
 }
@@ -246,7 +246,7 @@ fn not_allowed_hex_error3() {
             &mut p,
             &mut h,
             b"FF",
-            ParserState::ChunkLength3,
+            ParserState::ChunkLength2,
             b"FF".len()
         );
 
@@ -254,7 +254,7 @@ fn not_allowed_hex_error3() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::ChunkLength(b)
+            ErrorKind::ChunkLength(b)
         );
     }
 }
@@ -271,7 +271,7 @@ fn not_allowed_hex_error4() {
             &mut p,
             &mut h,
             b"FFF",
-            ParserState::ChunkLength4,
+            ParserState::ChunkLength2,
             b"FFF".len()
         );
 
@@ -279,7 +279,7 @@ fn not_allowed_hex_error4() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::ChunkLength(b)
+            ErrorKind::ChunkLength(b)
         );
     }
 }
@@ -296,7 +296,7 @@ fn not_allowed_hex_error5() {
             &mut p,
             &mut h,
             b"FFFF",
-            ParserState::ChunkLength5,
+            ParserState::ChunkLength2,
             b"FFFF".len()
         );
 
@@ -304,7 +304,7 @@ fn not_allowed_hex_error5() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::ChunkLength(b)
+            ErrorKind::ChunkLength(b)
         );
     }
 }
@@ -321,7 +321,7 @@ fn not_allowed_hex_error6() {
             &mut p,
             &mut h,
             b"FFFFF",
-            ParserState::ChunkLength6,
+            ParserState::ChunkLength2,
             b"FFFFF".len()
         );
 
@@ -329,7 +329,7 @@ fn not_allowed_hex_error6() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::ChunkLength(b)
+            ErrorKind::ChunkLength(b)
         );
     }
 }
@@ -346,7 +346,7 @@ fn not_allowed_hex_error7() {
             &mut p,
             &mut h,
             b"FFFFFF",
-            ParserState::ChunkLength7,
+            ParserState::ChunkLength2,
             b"FFFFFF".len()
         );
 
@@ -354,7 +354,7 @@ fn not_allowed_hex_error7() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::ChunkLength(b)
+            ErrorKind::ChunkLength(b)
         );
     }
 }
@@ -371,7 +371,7 @@ fn not_allowed_hex_error8() {
             &mut p,
             &mut h,
             b"FFFFFFF",
-            ParserState::ChunkLength8,
+            ParserState::ChunkLength2,
             b"FFFFFFF".len()
         );
 
@@ -379,11 +379,29 @@ fn not_allowed_hex_error8() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::ChunkLength(b)
+            ErrorKind::ChunkLength(b)
         );
     }
 }
 
+#[test]
+fn allowed16() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"FFFFFFFFFFFFFFFF\r",
+        ParserState::ChunkLengthLf,
+        b"FFFFFFFFFFFFFFFF\r".len()
+    );
+
+    assert_eq!(
+        h.chunk_length,
+        0xFFFFFFFFFFFFFFFF
+    )
+}
+
 #[test]
 fn overflow_error() {
     let (mut p, mut h) = setup!();
@@ -391,8 +409,28 @@ fn overflow_error() {
     assert_error(
         &mut p,
         &mut h,
-        b"FFFFFFFFF",
-        ParserError::ChunkLength(b'F')
+        b"FFFFFFFFFFFFFFFFF",
+        ErrorKind::ChunkLengthOverflow
+    );
+}
+
+#[test]
+fn overflow_split_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"1000000000",
+        ParserState::ChunkLength2,
+        b"1000000000".len()
+    );
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"0000000",
+        ErrorKind::ChunkLengthOverflow
     );
 }
 
@@ -20,3 +20,4 @@ mod extension_name;
 mod extension_value;
 mod finished;
 mod length;
+mod trailer;
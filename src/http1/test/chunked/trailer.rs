@@ -0,0 +1,265 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Action;
+use http1::*;
+use http1::test::*;
+
+struct X {
+    header_name:       Vec<u8>,
+    headers_finished:  bool,
+    trailer_name:      Vec<u8>,
+    trailer_value:     Vec<u8>,
+    trailers_finished: bool
+}
+
+impl X {
+    fn new() -> X {
+        X{
+            header_name:       Vec::new(),
+            headers_finished:  false,
+            trailer_name:      Vec::new(),
+            trailer_value:     Vec::new(),
+            trailers_finished: false
+        }
+    }
+}
+
+impl HttpHandler for X {
+    fn on_header_name(&mut self, name: &[u8]) -> Action {
+        self.header_name.extend_from_slice(name);
+        Action::Continue
+    }
+
+    fn on_headers_finished(&mut self) -> Action {
+        self.headers_finished = true;
+        Action::Continue
+    }
+
+    fn on_trailer_name(&mut self, name: &[u8]) -> Action {
+        self.trailer_name.extend_from_slice(name);
+        Action::Continue
+    }
+
+    fn on_trailer_value(&mut self, value: &[u8]) -> Action {
+        self.trailer_value.extend_from_slice(value);
+        Action::Continue
+    }
+
+    fn on_trailers_finished(&mut self) -> Action {
+        self.trailers_finished = true;
+        Action::Continue
+    }
+}
+
+macro_rules! setup {
+    () => ({
+        let mut p = Parser::new();
+
+        p.init_message();
+        p.set_trailer_validation(true);
+
+        (p, X::new())
+    });
+}
+
+#[test]
+fn callbacks() {
+    let mut h = X::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n\
+          0\r\n\
+          Checksum: abc\r\n\
+          Expires: never\r\n\
+          \r\n",
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n\
+          0\r\n\
+          Checksum: abc\r\n\
+          Expires: never\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.header_name,
+        b"transfer-encoding"
+    );
+
+    assert_eq!(
+        &h.trailer_name,
+        b"checksumexpires"
+    );
+
+    assert_eq!(
+        &h.trailer_value,
+        b"abcnever"
+    );
+
+    assert!(h.headers_finished);
+    assert!(h.trailers_finished);
+}
+
+#[test]
+fn callbacks_without_trailers() {
+    let mut h = X::new();
+    let mut p = Parser::new();
+
+    p.init_chunked();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"0\r\n\r\n",
+        b"0\r\n\r\n".len()
+    );
+
+    assert!(!h.headers_finished);
+    assert!(h.trailers_finished);
+}
+
+#[test]
+fn validation_announced() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Trailer: Expires\r\n\
+          Trailer: X-Checksum, X-Signature\r\n\
+          \r\n\
+          0\r\n\
+          X-Checksum: abc\r\n\
+          x-signature: def\r\n\
+          Expires: never\r\n\
+          \r\n",
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Trailer: Expires\r\n\
+          Trailer: X-Checksum, X-Signature\r\n\
+          \r\n\
+          0\r\n\
+          X-Checksum: abc\r\n\
+          x-signature: def\r\n\
+          Expires: never\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.trailer_name,
+        b"x-checksumx-signatureexpires"
+    );
+}
+
+#[test]
+fn validation_disabled() {
+    let (mut p, mut h) = setup!();
+
+    p.set_trailer_validation(false);
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n\
+          0\r\n\
+          Content-Length: 5\r\n\
+          \r\n",
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n\
+          0\r\n\
+          Content-Length: 5\r\n\
+          \r\n".len()
+    );
+}
+
+#[test]
+fn validation_forbidden_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Trailer: Content-Length\r\n\
+          \r\n\
+          0\r\n\
+          Content-Length: 5\r\n",
+        ErrorKind::ForbiddenTrailer
+    );
+}
+
+#[test]
+fn validation_split_name() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Trailer: X-Checksum\r\n\
+          \r\n\
+          0\r\n\
+          X-Check",
+        ParserState::LowerHeaderName,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Trailer: X-Checksum\r\n\
+          \r\n\
+          0\r\n\
+          X-Check".len()
+    );
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"sum: abc\r\n\
+          \r\n",
+        b"sum: abc\r\n\
+          \r\n".len()
+    );
+}
+
+#[test]
+fn validation_unannounced_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Trailer: X-Checksum\r\n\
+          \r\n\
+          0\r\n\
+          X-Signature: def\r\n",
+        ErrorKind::UnannouncedTrailer
+    );
+}
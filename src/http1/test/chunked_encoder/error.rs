@@ -0,0 +1,142 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+
+use std::error::Error;
+use std::io::{ self, Write };
+
+struct Closed;
+
+impl Write for Closed {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn extension_name() {
+    let mut e = ChunkedEncoder::new(Vec::new());
+
+    match e.chunk_with_extensions(b"Hello", &[(b"na=me", b"value")]) {
+        Err(WriteError::ChunkExtensionName(b'=')) => {},
+        _ => panic!()
+    }
+
+    match e.chunk_with_extensions(b"Hello", &[(b"", b"value")]) {
+        Err(WriteError::EmptyChunkExtensionName) => {},
+        _ => panic!()
+    }
+
+    match e.chunk_with_extensions(b"Hello", &[(b"na me", b"value")]) {
+        Err(WriteError::ChunkExtensionName(b' ')) => {},
+        _ => panic!()
+    }
+
+    assert!(e.get_ref().is_empty());
+}
+
+#[test]
+fn extension_value() {
+    for b in (0..0x20).filter(|&x| x != b'\t').chain(Some(0x7F)) {
+        let mut e = ChunkedEncoder::new(Vec::new());
+
+        match e.chunk_with_extensions(b"Hello", &[(&b"name"[..], &[b'a', b][..])]) {
+            Err(WriteError::ChunkExtensionValue(x)) => assert_eq!(x, b),
+            _ => panic!()
+        }
+
+        assert!(e.get_ref().is_empty());
+    }
+}
+
+#[test]
+fn extension_value_injection() {
+    let mut e = ChunkedEncoder::new(Vec::new());
+
+    match e.chunk_with_extensions(b"Hello", &[(b"name", b"value\r\n0\r\n\r\n")]) {
+        Err(WriteError::ChunkExtensionValue(b'\r')) => {},
+        _ => panic!()
+    }
+
+    assert!(e.get_ref().is_empty());
+}
+
+#[test]
+fn io() {
+    let mut e = ChunkedEncoder::new(Closed);
+
+    match e.chunk(b"Hello") {
+        Err(ref error @ WriteError::Io(_)) => assert!(error.source().is_some()),
+        _ => panic!()
+    }
+}
+
+#[test]
+fn out_of_order() {
+    let mut e = ChunkedEncoder::new(Vec::new());
+
+    e.finish::<&[u8], &[u8]>(&[]).unwrap();
+
+    match e.chunk(b"Hello") {
+        Err(WriteError::OutOfOrder) => {},
+        _ => panic!()
+    }
+
+    match e.finish::<&[u8], &[u8]>(&[]) {
+        Err(WriteError::OutOfOrder) => {},
+        _ => panic!()
+    }
+
+    assert_eq!(e.get_ref(), b"0\r\n\r\n");
+}
+
+#[test]
+fn trailer() {
+    let mut e = ChunkedEncoder::new(Vec::new());
+
+    match e.finish(&[(&b"Expires"[..], &b"never"[..]), (&b"X:Bad"[..], &b"value"[..])]) {
+        Err(WriteError::HeaderName(b':')) => {},
+        _ => panic!()
+    }
+
+    match e.finish(&[(b"", b"value")]) {
+        Err(WriteError::EmptyHeaderName) => {},
+        _ => panic!()
+    }
+
+    match e.finish(&[(b"Expires", b"never\r\nX-Injected: 1")]) {
+        Err(WriteError::HeaderValue(b'\r')) => {},
+        _ => panic!()
+    }
+
+    match e.finish(&[(&b"Expires"[..], &b"never"[..]), (&b"Content-Length"[..], &b"5"[..])]) {
+        Err(WriteError::ForbiddenTrailer) => {},
+        _ => panic!()
+    }
+
+    match e.finish(&[(b"TRANSFER-ENCODING", b"chunked")]) {
+        Err(WriteError::ForbiddenTrailer) => {},
+        _ => panic!()
+    }
+
+    assert!(!e.is_finished());
+    assert!(e.get_ref().is_empty());
+}
@@ -0,0 +1,227 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+mod error;
+
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn chunks() {
+    let mut e = ChunkedEncoder::new(Vec::new());
+
+    e.chunk(b"Hello").unwrap();
+    e.chunk(b", ").unwrap();
+    e.chunk(b"world! This chunk is longer than 15 bytes").unwrap();
+    e.finish::<&[u8], &[u8]>(&[]).unwrap();
+
+    assert!(e.is_finished());
+
+    let stream = e.into_inner();
+
+    assert_eq!(
+        &stream[..],
+        &b"5\r\nHello\r\n\
+           2\r\n, \r\n\
+           29\r\nworld! This chunk is longer than 15 bytes\r\n\
+           0\r\n\
+           \r\n"[..]
+    );
+
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_chunked();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        &stream,
+        stream.len()
+    );
+
+    assert_eq!(
+        &h.chunk_data,
+        b"Hello, world! This chunk is longer than 15 bytes"
+    );
+}
+
+#[test]
+fn empty_chunk() {
+    let mut e = ChunkedEncoder::new(Vec::new());
+
+    e.chunk(b"").unwrap();
+    e.chunk_with_extensions(b"", &[(b"name", b"value")]).unwrap();
+
+    assert!(e.get_ref().is_empty());
+
+    e.finish::<&[u8], &[u8]>(&[]).unwrap();
+
+    assert_eq!(e.get_ref(), b"0\r\n\r\n");
+}
+
+#[test]
+fn extensions() {
+    let mut e = ChunkedEncoder::new(Vec::new());
+
+    e.chunk_with_extensions(
+        b"Hello",
+        &[(&b"Token"[..], &b"value"[..]),
+          (&b"quoted"[..], &b"a \"quoted\" \\ value"[..]),
+          (&b"flag"[..], &b""[..])]
+    ).unwrap();
+
+    e.finish::<&[u8], &[u8]>(&[]).unwrap();
+
+    let stream = e.into_inner();
+
+    assert_eq!(
+        &stream[..],
+        &b"5;Token=value;quoted=\"a \\\"quoted\\\" \\\\ value\";flag\r\n\
+           Hello\r\n\
+           0\r\n\
+           \r\n"[..]
+    );
+
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_chunked();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        &stream,
+        stream.len()
+    );
+
+    assert_eq!(
+        &h.chunk_extension_name,
+        b"tokenquotedflag"
+    );
+
+    assert_eq!(
+        &h.chunk_extension_value,
+        b"valuea \"quoted\" \\ value"
+    );
+
+    assert_eq!(
+        &h.chunk_data,
+        b"Hello"
+    );
+}
+
+#[test]
+fn extension_value_header_fields() {
+    // every byte that can be quoted must round-trip
+    let value: Vec<u8> = (0..256).map(|x| x as u8)
+                                 .filter(|&x| x == b'\t' || (x > 0x1F && x != 0x7F))
+                                 .collect();
+
+    let mut e = ChunkedEncoder::new(Vec::new());
+
+    e.chunk_with_extensions(b"Hello", &[(b"name", &value)]).unwrap();
+    e.finish::<&[u8], &[u8]>(&[]).unwrap();
+
+    let stream = e.into_inner();
+    let (mut p, mut h)  = http1_setup!();
+
+    p.init_chunked();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        &stream,
+        stream.len()
+    );
+
+    assert_eq!(
+        h.chunk_extension_value,
+        value
+    );
+}
+
+#[test]
+fn message() {
+    let mut w = Writer::new(Vec::new());
+
+    w.status_line((1, 1), 200, b"OK").unwrap();
+    w.header(b"Transfer-Encoding", b"chunked").unwrap();
+    w.header(b"Trailer", b"Expires").unwrap();
+    w.finish_head().unwrap();
+
+    {
+        let mut e = ChunkedEncoder::new(w.get_mut());
+
+        e.chunk(b"Hello").unwrap();
+        e.finish(&[(b"Expires", b"never")]).unwrap();
+    }
+
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+    p.set_trailer_validation(true);
+
+    match p.resume(&mut c, w.get_ref()) {
+        Ok(Success::Finished(length)) => assert_eq!(length, w.get_ref().len()),
+        _ => panic!()
+    }
+
+    let response = c.take_response().unwrap();
+
+    assert_eq!(response.body(), b"Hello");
+    assert_eq!(response.trailers().get(b"expires"), Some(&b"never"[..]));
+}
+
+#[test]
+fn trailers() {
+    let mut e = ChunkedEncoder::new(Vec::new());
+
+    e.chunk(b"Hello").unwrap();
+    e.finish(&[(&b"Expires"[..], &b"never"[..]),
+               (&b"X-Checksum"[..], &b"a1b2\tc3"[..])]).unwrap();
+
+    let stream = e.into_inner();
+
+    assert_eq!(
+        &stream[..],
+        &b"5\r\nHello\r\n\
+           0\r\n\
+           Expires: never\r\n\
+           X-Checksum: a1b2\tc3\r\n\
+           \r\n"[..]
+    );
+
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_chunked();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        &stream,
+        stream.len()
+    );
+
+    assert_eq!(
+        &h.header_name,
+        b"expiresx-checksum"
+    );
+
+    assert_eq!(
+        &h.header_value,
+        b"nevera1b2\tc3"
+    );
+}
@@ -0,0 +1,193 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn chunked() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_chunked();
+
+    assert_finished(
+        &mut p,
+        &mut c,
+        b"5\r\n\
+          Hello\r\n\
+          0\r\n\
+          X-Trailer: 1\r\n\
+          \r\n",
+        b"5\r\n\
+          Hello\r\n\
+          0\r\n\
+          X-Trailer: 1\r\n\
+          \r\n".len()
+    );
+
+    // there is no end of message
+    assert!(c.take_body().is_none());
+
+    c.finish();
+
+    assert!(c.take_request().is_none());
+    assert!(c.take_response().is_none());
+
+    let body = c.take_body().unwrap();
+
+    assert_eq!(body.data(), b"Hello");
+    assert_eq!(body.trailers().get(b"x-trailer"), Some(&b"1"[..]));
+}
+
+#[test]
+fn head() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_head();
+
+    assert_finished(
+        &mut p,
+        &mut c,
+        b"HTTP/1.1 404 Not Found\r\n\
+          Content-Length: 0\r\n\
+          \r\n",
+        b"HTTP/1.1 404 Not Found\r\n\
+          Content-Length: 0\r\n\
+          \r\n".len()
+    );
+
+    c.finish();
+
+    let response = c.take_response().unwrap();
+
+    assert_eq!(response.status_code(), 404);
+    assert_eq!(response.status(), b"Not Found");
+    assert_eq!(response.headers().get(b"content-length"), Some(&b"0"[..]));
+}
+
+#[test]
+fn multipart() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut c,
+        b"POST /upload HTTP/1.1\r\n\
+          Content-Type: multipart/form-data; boundary=XBoundaryX\r\n\
+          Content-Length: 145\r\n\
+          \r\n\
+          --XBoundaryX\r\n\
+          Content-Disposition: form-data; name=\"a\"\r\n\
+          \r\n\
+          First\r\n\
+          --XBoundaryX\r\n\
+          Content-Disposition: form-data; name=\"b\"\r\n\
+          \r\n\
+          Second\r\n\
+          --XBoundaryX--",
+        b"POST /upload HTTP/1.1\r\n\
+          Content-Type: multipart/form-data; boundary=XBoundaryX\r\n\
+          Content-Length: 145\r\n\
+          \r\n\
+          --XBoundaryX\r\n\
+          Content-Disposition: form-data; name=\"a\"\r\n\
+          \r\n\
+          First\r\n\
+          --XBoundaryX\r\n\
+          Content-Disposition: form-data; name=\"b\"\r\n\
+          \r\n\
+          Second\r\n\
+          --XBoundaryX--".len()
+    );
+
+    let request = c.take_request().unwrap();
+
+    assert_eq!(request.headers().len(), 2);
+    assert_eq!(request.parts().len(), 2);
+    assert_eq!(
+        request.parts()[0].headers().get(b"content-disposition"),
+        Some(&b"form-data; name=\"a\""[..])
+    );
+    assert_eq!(request.parts()[0].data(), b"First");
+    assert_eq!(request.parts()[1].data(), b"Second");
+    assert!(request.body().is_empty());
+}
+
+#[test]
+fn multipart_standalone() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_multipart();
+    p.set_boundary(b"XBoundaryX");
+
+    assert_finished(
+        &mut p,
+        &mut c,
+        b"--XBoundaryX\r\n\
+          Content-Type: text/plain\r\n\
+          \r\n\
+          Data\r\n\
+          --XBoundaryX--",
+        b"--XBoundaryX\r\n\
+          Content-Type: text/plain\r\n\
+          \r\n\
+          Data\r\n\
+          --XBoundaryX--".len()
+    );
+
+    c.finish();
+
+    assert!(c.take_response().is_none());
+
+    let body = c.take_body().unwrap();
+
+    assert_eq!(body.parts()[0].headers().get(b"content-type"), Some(&b"text/plain"[..]));
+    assert_eq!(body.parts()[0].data(), b"Data");
+}
+
+#[test]
+fn url_encoded() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_url_encoded();
+    p.set_length(b"Name+1=Value%201&Empty=".len());
+
+    assert_finished(
+        &mut p,
+        &mut c,
+        b"Name+1=Value%201&Empty=",
+        b"Name+1=Value%201&Empty=".len()
+    );
+
+    c.finish();
+
+    assert!(c.take_request().is_none());
+
+    let body = c.take_body().unwrap();
+
+    assert_eq!(
+        body.fields(),
+        &[(b"Name 1".to_vec(), b"Value 1".to_vec()),
+          (b"Empty".to_vec(), Vec::new())]
+    );
+}
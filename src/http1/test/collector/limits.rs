@@ -0,0 +1,107 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn max_body_length() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    c.set_limits(CollectorLimits{
+        max_body_length: 5,
+        ..CollectorLimits::default()
+    });
+
+    p.init_chunked();
+
+    assert_finished(
+        &mut p,
+        &mut c,
+        b"5\r\n\
+          Hello\r\n\
+          0\r\n\
+          \r\n",
+        b"5\r\n\
+          Hello\r\n\
+          0\r\n\
+          \r\n".len()
+    );
+
+    c.reset();
+    p.reset();
+
+    assert_error(
+        &mut p,
+        &mut c,
+        b"6\r\n\
+          Hello!\r\n",
+        ErrorKind::Handler(CollectorError::BodyTooLong)
+    );
+}
+
+#[test]
+fn max_fields() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    c.set_limits(CollectorLimits{
+        max_fields: 2,
+        ..CollectorLimits::default()
+    });
+
+    p.init_url_encoded();
+    p.set_length(b"a=1&b=2&c=3".len());
+
+    assert_error(
+        &mut p,
+        &mut c,
+        b"a=1&b=2&c=3",
+        ErrorKind::Handler(CollectorError::TooManyFields)
+    );
+
+    c.finish();
+
+    assert_eq!(c.take_body().unwrap().fields().len(), 2);
+}
+
+#[test]
+fn max_parts() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    c.set_limits(CollectorLimits{
+        max_parts: 1,
+        ..CollectorLimits::default()
+    });
+
+    p.init_multipart();
+    p.set_boundary(b"XBoundaryX");
+
+    assert_error(
+        &mut p,
+        &mut c,
+        b"--XBoundaryX\r\n\
+          \r\n\
+          First\r\n\
+          --XBoundaryX\r\n\
+          \r\n\
+          Second\r\n\
+          --XBoundaryX--",
+        ErrorKind::Handler(CollectorError::TooManyParts)
+    );
+}
@@ -0,0 +1,220 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn interim() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut c,
+        b"HTTP/1.1 100 Continue\r\n\
+          X-Interim: 1\r\n\
+          \r\n\
+          HTTP/1.1 200 OK\r\n\
+          Content-Length: 2\r\n\
+          \r\n\
+          OK",
+        b"HTTP/1.1 100 Continue\r\n\
+          X-Interim: 1\r\n\
+          \r\n\
+          HTTP/1.1 200 OK\r\n\
+          Content-Length: 2\r\n\
+          \r\n\
+          OK".len()
+    );
+
+    let response = c.take_response().unwrap();
+
+    assert_eq!(response.status_code(), 200);
+    assert_eq!(response.status(), b"OK");
+    assert!(!response.headers().contains(b"x-interim"));
+    assert_eq!(response.body(), b"OK");
+}
+
+#[test]
+fn persistent() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_persistent();
+
+    assert_eos(
+        &mut p,
+        &mut c,
+        b"GET /a HTTP/1.1\r\n\
+          Host: localhost\r\n\
+          \r\n\
+          POST /b HTTP/1.1\r\n\
+          Content-Length: 4\r\n\
+          \r\n\
+          Data\
+          GET /c HT",
+        ParserState::RequestHttp3,
+        b"GET /a HTTP/1.1\r\n\
+          Host: localhost\r\n\
+          \r\n\
+          POST /b HTTP/1.1\r\n\
+          Content-Length: 4\r\n\
+          \r\n\
+          Data\
+          GET /c HT".len()
+    );
+
+    let request = c.take_request().unwrap();
+
+    assert_eq!(request.url(), b"/a");
+    assert_eq!(request.headers().get(b"host"), Some(&b"localhost"[..]));
+    assert!(request.body().is_empty());
+
+    let request = c.take_request().unwrap();
+
+    assert_eq!(request.method(), b"POST");
+    assert_eq!(request.url(), b"/b");
+    assert!(!request.headers().contains(b"host"));
+    assert_eq!(request.body(), b"Data");
+
+    // the third request has not finished
+    assert!(c.take_request().is_none());
+}
+
+#[test]
+fn request() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    assert_eos(
+        &mut p,
+        &mut c,
+        b"GET /index.html HTTP/1.0\r\n\
+          Accept: text/html\r\n\
+          Acc",
+        ParserState::LowerHeaderName,
+        b"GET /index.html HTTP/1.0\r\n\
+          Accept: text/html\r\n\
+          Acc".len()
+    );
+
+    assert!(c.take_request().is_none());
+
+    assert_finished(
+        &mut p,
+        &mut c,
+        b"ept: application/json\r\n\
+          X-Other: 1\r\n\
+          \r\n",
+        b"ept: application/json\r\n\
+          X-Other: 1\r\n\
+          \r\n".len()
+    );
+
+    assert!(c.take_response().is_none());
+
+    let request = c.take_request().unwrap();
+
+    assert_eq!(request.method(), b"GET");
+    assert_eq!(request.url(), b"/index.html");
+    assert_eq!(request.version(), (1, 0));
+    assert_eq!(request.headers().len(), 3);
+    assert_eq!(request.headers().get(b"ACCEPT"), Some(&b"text/html"[..]));
+    assert_eq!(
+        request.headers().get_all(b"accept"),
+        vec![&b"text/html"[..], &b"application/json"[..]]
+    );
+    assert_eq!(request.headers().get(b"x-other"), Some(&b"1"[..]));
+    assert!(c.take_request().is_none());
+}
+
+#[test]
+fn response_chunked() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut c,
+        b"HTTP/1.1 200 OK\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Trailer: X-Checksum\r\n\
+          \r\n\
+          5\r\n\
+          Hello\r\n\
+          6\r\n\
+          , Body\r\n\
+          0\r\n\
+          X-Checksum: abc\r\n\
+          \r\n",
+        b"HTTP/1.1 200 OK\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Trailer: X-Checksum\r\n\
+          \r\n\
+          5\r\n\
+          Hello\r\n\
+          6\r\n\
+          , Body\r\n\
+          0\r\n\
+          X-Checksum: abc\r\n\
+          \r\n".len()
+    );
+
+    assert!(c.take_request().is_none());
+
+    let response = c.take_response().unwrap();
+
+    assert_eq!(response.version(), (1, 1));
+    assert_eq!(response.status_code(), 200);
+    assert_eq!(response.headers().len(), 2);
+    assert_eq!(response.body(), b"Hello, Body");
+    assert_eq!(response.trailers().get(b"x-checksum"), Some(&b"abc"[..]));
+    assert!(!response.headers().contains(b"x-checksum"));
+}
+
+#[test]
+fn upgrade() {
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    assert_upgrade(
+        &mut p,
+        &mut c,
+        b"GET /chat HTTP/1.1\r\n\
+          Connection: Upgrade\r\n\
+          Upgrade: websocket\r\n\
+          \r\n\
+          frame",
+        b"GET /chat HTTP/1.1\r\n\
+          Connection: Upgrade\r\n\
+          Upgrade: websocket\r\n\
+          \r\n".len()
+    );
+
+    let request = c.take_request().unwrap();
+
+    assert_eq!(request.headers().get(b"upgrade"), Some(&b"websocket"[..]));
+    assert!(request.body().is_empty());
+}
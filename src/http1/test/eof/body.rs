@@ -0,0 +1,141 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Success;
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn eof() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_eof();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"Hello, ",
+        ParserState::BodyByEof,
+        7
+    );
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"world!",
+        ParserState::BodyByEof,
+        6
+    );
+
+    assert!(!h.body_finished);
+    assert_eq!(p.finish(&mut h), Ok(Success::Finished(0)));
+    assert_eq!(p.state(), ParserState::Finished);
+
+    assert_eq!(
+        &h.body,
+        b"Hello, world!"
+    );
+
+    assert!(h.body_finished);
+}
+
+#[test]
+fn request_without_framing() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.0\r\n\
+          \r\n\
+          Hello",
+        b"POST / HTTP/1.0\r\n\
+          \r\n".len()
+    );
+
+    assert!(h.body.is_empty());
+}
+
+#[test]
+fn response_close() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_persistent();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 OK\r\n\
+          Connection: close\r\n\
+          \r\n\
+          Hello",
+        ParserState::BodyByEof,
+        b"HTTP/1.1 200 OK\r\n\
+          Connection: close\r\n\
+          \r\n\
+          Hello".len()
+    );
+
+    assert_eq!(p.finish(&mut h), Ok(Success::Finished(0)));
+
+    assert_eq!(
+        &h.body,
+        b"Hello"
+    );
+
+    assert_eq!(
+        h.message_finished,
+        vec![false]
+    );
+}
+
+#[test]
+fn response_http10() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"HTTP/1.0 200 OK\r\n\
+          \r\n\
+          Hello",
+        ParserState::BodyByEof,
+        b"HTTP/1.0 200 OK\r\n\
+          \r\n\
+          Hello".len()
+    );
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"\r\nHTTP/1.0 200 OK\r\n",
+        ParserState::BodyByEof,
+        b"\r\nHTTP/1.0 200 OK\r\n".len()
+    );
+
+    assert_eq!(p.finish(&mut h), Ok(Success::Finished(0)));
+
+    assert_eq!(
+        &h.body,
+        b"Hello\r\nHTTP/1.0 200 OK\r\n"
+    );
+
+    assert!(h.body_finished);
+}
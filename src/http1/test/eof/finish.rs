@@ -0,0 +1,166 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Success;
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn after_finished() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\r\n",
+        b"GET / HTTP/1.1\r\n\r\n".len()
+    );
+
+    assert_eq!(p.finish(&mut h), Ok(Success::Finished(0)));
+}
+
+#[test]
+fn between_messages() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_persistent();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"GET /a HTTP/1.1\r\n\r\n\
+          GET /b HTTP/1.1\r\n\r\n",
+        ParserState::StripDetect,
+        b"GET /a HTTP/1.1\r\n\r\n\
+          GET /b HTTP/1.1\r\n\r\n".len()
+    );
+
+    assert_eq!(p.finish(&mut h), Ok(Success::Finished(0)));
+    assert_eq!(p.state(), ParserState::Finished);
+}
+
+#[test]
+fn chunked() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n\
+          5\r\n\
+          Hello\r\n",
+        ParserState::ChunkLength1,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n\
+          5\r\n\
+          Hello\r\n".len()
+    );
+
+    assert_eq!(p.finish(&mut h).map_err(ParserError::into_kind), Err(ErrorKind::UnexpectedEof));
+    assert_eq!(p.state(), ParserState::Dead);
+}
+
+#[test]
+fn content_length() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Length: 10\r\n\
+          \r\n\
+          Hello",
+        ParserState::BodyByLength,
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Length: 10\r\n\
+          \r\n\
+          Hello".len()
+    );
+
+    assert_eq!(p.finish(&mut h).map_err(ParserError::into_kind), Err(ErrorKind::UnexpectedEof));
+}
+
+#[test]
+fn dead() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\0",
+        ErrorKind::HeaderName(0)
+    );
+
+    assert_eq!(p.finish(&mut h).map_err(ParserError::into_kind), Err(ErrorKind::Dead));
+}
+
+#[test]
+fn empty() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_eq!(p.finish(&mut h), Ok(Success::Finished(0)));
+    assert_eq!(h.message_begin, 0);
+}
+
+#[test]
+fn head() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Host: ",
+        ParserState::StripHeaderValue,
+        b"GET / HTTP/1.1\r\n\
+          Host: ".len()
+    );
+
+    assert_eq!(p.finish(&mut h).map_err(ParserError::into_kind), Err(ErrorKind::UnexpectedEof));
+}
+
+#[test]
+fn interim() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 100 Continue\r\n\r\n",
+        ParserState::Detect1,
+        b"HTTP/1.1 100 Continue\r\n\r\n".len()
+    );
+
+    assert_eq!(p.finish(&mut h).map_err(ParserError::into_kind), Err(ErrorKind::UnexpectedEof));
+}
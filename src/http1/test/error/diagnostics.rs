@@ -0,0 +1,173 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Action;
+use http1::*;
+use http1::test::*;
+
+use std::error;
+
+struct X;
+
+impl HttpHandler<&'static str> for X {
+    fn on_header_value(&mut self, _value: &[u8]) -> Action<&'static str> {
+        Action::Abort("denied")
+    }
+}
+
+fn error<T: error::Error>(_error: &T) {
+}
+
+#[test]
+fn display() {
+    let (mut p, mut h) = http1_setup!();
+
+    match p.resume(&mut h, b"GET / HTTP/1.x\r\n") {
+        Err(error) => {
+            assert_eq!(
+                format!("{}", error),
+                "<ErrorKind::Version: 120> at offset 13 in state RequestVersionMinor1\n\
+                 GET / HTTP/1.x\\r\\n\n\
+                 \x20            ^"
+            );
+
+            assert_eq!(
+                format!("{:?}", error),
+                "<ParserError: <ErrorKind::Version: 120>, offset: 13, state: RequestVersionMinor1>"
+            );
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn display_escaped() {
+    let (mut p, mut h) = http1_setup!();
+
+    match p.resume(&mut h, b"GET / HTTP/1.1\r\n\tHost: \x7F") {
+        Err(error) => {
+            assert_eq!(error.kind(), &ErrorKind::HeaderValue(0x7F));
+            assert_eq!(error.offset(), 23);
+
+            assert_eq!(
+                format!("{}", error).lines().skip(1).collect::<Vec<&str>>(),
+                vec!["TTP/1.1\\r\\n\\tHost: \\x7F",
+                     "                   ^"]
+            );
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn error_trait() {
+    error(&ErrorKind::<()>::Dead);
+
+    error(&ParserError::new(
+        ErrorKind::<()>::Dead,
+        ParserState::Dead,
+        b"",
+        0,
+        0
+    ));
+
+    error(&::util::DecodeError::Byte(0));
+    error(&::util::FieldError::Name(0));
+    error(&::util::QueryError::Name(0));
+}
+
+#[test]
+fn excerpt() {
+    let (mut p, mut h) = http1_setup!();
+
+    match p.resume(&mut h, b"GET /0123456789abcdefghij\0klmnopqrstuvwxyz HTTP/1.1\r\n") {
+        Err(error) => {
+            assert_eq!(error.kind(), &ErrorKind::Url(0));
+            assert_eq!(error.offset(), 25);
+            assert_eq!(error.excerpt(), b"456789abcdefghij\0klmnopqrstuvwxyz");
+            assert_eq!(error.excerpt_index(), 16);
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn handler() {
+    let mut h = X;
+    let mut p = Parser::new();
+
+    match p.resume(&mut h, b"GET / HTTP/1.1\r\nHost: localhost\r\n") {
+        Err(error) => {
+            assert_eq!(error.offset(), 32);
+            assert_eq!(error.state(), ParserState::HeaderLf1);
+            assert_eq!(error.into_kind(), ErrorKind::Handler("denied"));
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn offset() {
+    let (mut p, mut h) = http1_setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n",
+        ParserState::HeaderCr2,
+        b"GET / HTTP/1.1\r\n".len()
+    );
+
+    match p.resume(&mut h, b"Host: localhost\r\n\0") {
+        Err(error) => {
+            assert_eq!(error.kind(), &ErrorKind::HeaderName(0));
+            assert_eq!(error.offset(), 33);
+            assert_eq!(error.excerpt(), b"ost: localhost\r\n\0");
+            assert_eq!(error.excerpt_index(), 16);
+        },
+        _ => panic!()
+    }
+
+    assert_eq!(p.state(), ParserState::Dead);
+}
+
+#[test]
+fn unexpected_eof() {
+    let (mut p, mut h) = http1_setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\nHost",
+        ParserState::LowerHeaderName,
+        b"GET / HTTP/1.1\r\nHost".len()
+    );
+
+    match p.finish(&mut h) {
+        Err(error) => {
+            assert_eq!(error.kind(), &ErrorKind::UnexpectedEof);
+            assert_eq!(error.offset(), 20);
+            assert_eq!(error.state(), ParserState::LowerHeaderName);
+            assert!(error.excerpt().is_empty());
+
+            assert_eq!(
+                format!("{}", error),
+                "<ErrorKind::UnexpectedEof> at offset 20 in state LowerHeaderName"
+            );
+        },
+        _ => panic!()
+    }
+}
@@ -14,6 +14,7 @@
 // | limitations under the License.                                                                |
 // +-----------------------------------------------------------------------------------------------+
 
+use fsm::Action;
 use http1::*;
 use http1::test::*;
 
@@ -21,8 +22,8 @@ use http1::test::*;
 fn on_header_name() {
     struct H;
     impl HttpHandler for H {
-        fn on_header_name(&mut self, _: &[u8]) -> bool {
-            false
+        fn on_header_name(&mut self, _: &[u8]) -> Action {
+            Action::Pause
         }
     }
 
@@ -44,8 +45,8 @@ fn on_header_name() {
 fn on_header_value() {
     struct H;
     impl HttpHandler for H {
-        fn on_header_value(&mut self, _: &[u8]) -> bool {
-            false
+        fn on_header_value(&mut self, _: &[u8]) -> Action {
+            Action::Pause
         }
     }
 
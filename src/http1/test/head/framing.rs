@@ -0,0 +1,252 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+macro_rules! setup {
+    () => ({
+        let (mut p, mut h) = http1_setup!();
+
+        p.init_head();
+
+        (p, h)
+    });
+}
+
+#[test]
+fn chunked_not_last_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked, gzip\r\n\
+          \r\n",
+        ErrorKind::ChunkedNotLast
+    );
+}
+
+#[test]
+fn chunked_not_last_split_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chun",
+        ParserState::HeaderValue,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chun".len()
+    );
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"ked, gzip\r\n\
+          \r\n",
+        ErrorKind::ChunkedNotLast
+    );
+}
+
+#[test]
+fn chunked_repeated_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Transfer-Encoding: Chunked\r\n\
+          \r\n",
+        ErrorKind::ChunkedNotLast
+    );
+}
+
+#[test]
+fn content_length_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 1a\r\n\
+          \r\n",
+        ErrorKind::ContentLength(b'a')
+    );
+}
+
+#[test]
+fn content_length_empty_item_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 5, , 5\r\n\
+          \r\n",
+        ErrorKind::ContentLength(b',')
+    );
+}
+
+#[test]
+fn content_length_inner_space_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 1 0\r\n\
+          \r\n",
+        ErrorKind::ContentLength(b' ')
+    );
+}
+
+#[test]
+fn content_length_mismatch_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 5, 6\r\n\
+          \r\n",
+        ErrorKind::ContentLengthMismatch
+    );
+}
+
+#[test]
+fn content_length_mismatch_multiple_headers_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 5\r\n\
+          Content-Length: 6\r\n\
+          \r\n",
+        ErrorKind::ContentLengthMismatch
+    );
+}
+
+#[test]
+fn content_length_overflow_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 99999999999999999999999999\r\n\
+          \r\n",
+        ErrorKind::ContentLengthOverflow
+    );
+}
+
+#[test]
+fn content_length_split_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 1",
+        ParserState::HeaderValue,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 1".len()
+    );
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"a\r\n\
+          \r\n",
+        ErrorKind::ContentLength(b'a')
+    );
+}
+
+#[test]
+fn content_length_with_transfer_encoding_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 5\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n",
+        ErrorKind::TransferEncodingWithContentLength
+    );
+}
+
+#[test]
+fn no_content_response() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 204 No Content\r\n\
+          Content-Length: 5\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n",
+        b"HTTP/1.1 204 No Content\r\n\
+          Content-Length: 5\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n".len()
+    );
+}
+
+#[test]
+fn response_transfer_encoding() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 OK\r\n\
+          Transfer-Encoding: gzip\r\n\
+          \r\n",
+        b"HTTP/1.1 200 OK\r\n\
+          Transfer-Encoding: gzip\r\n\
+          \r\n".len()
+    );
+}
+
+#[test]
+fn transfer_encoding_with_content_length_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Content-Length: 5\r\n\
+          \r\n",
+        ErrorKind::TransferEncodingWithContentLength
+    );
+}
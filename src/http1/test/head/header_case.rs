@@ -0,0 +1,248 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+macro_rules! setup {
+    () => ({
+        let (mut p, mut h) = http1_setup!();
+
+        p.set_header_case_preservation(true);
+
+        (p, h)
+    });
+}
+
+#[test]
+fn default_lower_case() {
+    let (mut p, mut h) = http1_setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Host: localhost\r\n\
+          X-Custom-Header: 1\r\n\
+          \r\n",
+        b"GET / HTTP/1.1\r\n\
+          Host: localhost\r\n\
+          X-Custom-Header: 1\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.header_name,
+        b"hostx-custom-header"
+    );
+}
+
+#[test]
+fn disabled() {
+    let (mut p, mut h) = setup!();
+
+    p.set_header_case_preservation(false);
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          X-Custom-Header: 1\r\n\
+          \r\n",
+        b"GET / HTTP/1.1\r\n\
+          X-Custom-Header: 1\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.header_name,
+        b"x-custom-header"
+    );
+}
+
+#[test]
+fn fast_path() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Host: localhost\r\n\
+          User-Agent: http-box\r\n\
+          \r\n",
+        b"GET / HTTP/1.1\r\n\
+          Host: localhost\r\n\
+          User-Agent: http-box\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.header_name,
+        b"HostUser-Agent"
+    );
+
+    assert_eq!(
+        h.known_headers,
+        vec![HeaderName::Host, HeaderName::UserAgent]
+    );
+}
+
+#[test]
+fn message_framing() {
+    let (mut p, mut h) = setup!();
+
+    p.init_message();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          CONTENT-le",
+        ParserState::LowerHeaderName,
+        b"POST / HTTP/1.1\r\n\
+          CONTENT-le".len()
+    );
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"NGTH: 5\r\n\
+          \r\n\
+          Hello",
+        b"NGTH: 5\r\n\
+          \r\n\
+          Hello".len()
+    );
+
+    assert_eq!(
+        &h.header_name,
+        b"CONTENT-leNGTH"
+    );
+
+    assert_eq!(
+        h.known_headers,
+        vec![HeaderName::ContentLength]
+    );
+
+    assert_eq!(
+        &h.body,
+        b"Hello"
+    );
+}
+
+#[test]
+fn reset() {
+    let (mut p, mut h) = setup!();
+
+    p.reset();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          X-Amz-Date: 20161016T000000Z\r\n\
+          \r\n",
+        b"GET / HTTP/1.1\r\n\
+          X-Amz-Date: 20161016T000000Z\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.header_name,
+        b"X-Amz-Date"
+    );
+}
+
+#[test]
+fn slow_path() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          x-Custom-HEADER: 1\r\n\
+          Sec-WebSocket-Key: abc\r\n\
+          \r\n",
+        b"GET / HTTP/1.1\r\n\
+          x-Custom-HEADER: 1\r\n\
+          Sec-WebSocket-Key: abc\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.header_name,
+        b"x-Custom-HEADERSec-WebSocket-Key"
+    );
+
+    assert_eq!(
+        h.known_headers,
+        vec![HeaderName::SecWebsocketKey]
+    );
+}
+
+#[test]
+fn trailer_validation() {
+    let (mut p, mut h) = setup!();
+
+    p.init_message();
+    p.set_trailer_validation(true);
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Trailer: X-Checksum\r\n\
+          \r\n\
+          0\r\n\
+          X-CheckSum: abc\r\n\
+          \r\n",
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Trailer: X-Checksum\r\n\
+          \r\n\
+          0\r\n\
+          X-CheckSum: abc\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.header_name,
+        b"Transfer-EncodingTrailerX-CheckSum"
+    );
+}
+
+#[test]
+fn trailer_validation_forbidden_error() {
+    let (mut p, mut h) = setup!();
+
+    p.init_message();
+    p.set_trailer_validation(true);
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Trailer: Content-Length\r\n\
+          \r\n\
+          0\r\n\
+          Content-Length: 5\r\n",
+        ErrorKind::ForbiddenTrailer
+    );
+}
@@ -143,8 +143,8 @@ fn entire_iter() {
 
 #[test]
 fn not_allowed_error() {
-    // skip `:` otherwise it will change state
-    for b in non_token_vec().iter().filter(|&x| *x != b':') {
+    // skip `:` otherwise it will change state, and skip white space which has its own error
+    for b in non_token_vec().iter().filter(|&x| *x != b':' && *x != b' ' && *x != b'\t') {
         let (mut p, mut h) = setup!();
 
         assert_eos(
@@ -159,7 +159,29 @@ fn not_allowed_error() {
             &mut p,
             &mut h,
             &[*b],
-            ParserError::HeaderName(*b)
+            ErrorKind::HeaderName(*b)
+        );
+    }
+}
+
+#[test]
+fn whitespace_error() {
+    for b in [b' ', b'\t'].iter() {
+        let (mut p, mut h) = setup!();
+
+        assert_eos(
+            &mut p,
+            &mut h,
+            b"Header",
+            ParserState::LowerHeaderName,
+            b"Header".len()
+        );
+
+        assert_error(
+            &mut p,
+            &mut h,
+            &[*b, b':'],
+            ErrorKind::HeaderNameWhitespace
         );
     }
 }
@@ -37,10 +37,47 @@ macro_rules! setup {
     });
 }
 
+#[test]
+fn allowed_0xff() {
+    // 0xFF is the last entry of each byte table
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"a\xFF\"\xFF\"\r",
+        ParserState::HeaderLf1,
+        b"a\xFF\"\xFF\"\r".len()
+    );
+
+    assert_eq!(
+        &h.header_value,
+        b"a\xFF\"\xFF\""
+    );
+}
+
+#[test]
+fn allowed_leading_obsolete_text() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b" \xC3\xA9t\xC3\xA9\r",
+        ParserState::HeaderLf1,
+        b" \xC3\xA9t\xC3\xA9\r".len()
+    );
+
+    assert_eq!(
+        &h.header_value,
+        b"\xC3\xA9t\xC3\xA9"
+    );
+}
+
 #[test]
 fn allowed_escaped() {
     // escaped data can only be 7bit non-control
-    for b in (0..255).filter(|&x| x > 0x1F && x < 0x7B) {
+    for b in (0..=255).filter(|&x| x > 0x1F && x < 0x7B) {
         let (mut p, mut h) = setup!();
 
         assert_eos(
@@ -214,7 +251,7 @@ fn multiline() {
 #[test]
 fn not_allowed_escaped_error() {
     // escaped data can only be 7bit non-control
-    for b in (0..255).filter(|&x| x < 0x20 || x > 0x7A) {
+    for b in (0..=255).filter(|&x| x < 0x20 || x > 0x7A) {
         let (mut p, mut h) = setup!();
 
         assert_eos(
@@ -229,7 +266,7 @@ fn not_allowed_escaped_error() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::HeaderValue(b)
+            ErrorKind::HeaderValue(b)
         );
     }
 }
@@ -237,16 +274,16 @@ fn not_allowed_escaped_error() {
 #[test]
 fn not_allowed_header_fields_error() {
     // skip `\r` and `"`, otherwise state will change
-    for b in (0..255).filter(|&x| !is_header_field(x))
-                     .filter(|&x| x != b'\r')
-                     .filter(|&x| x != b'"') {
+    for b in (0..=255).filter(|&x| !is_header_field(x))
+                      .filter(|&x| x != b'\r')
+                      .filter(|&x| x != b'"') {
         let (mut p, mut h) = setup!();
 
         assert_error(
             &mut p,
             &mut h,
             &[b],
-            ParserError::HeaderValue(b)
+            ErrorKind::HeaderValue(b)
         );
     }
 }
@@ -254,9 +291,9 @@ fn not_allowed_header_fields_error() {
 #[test]
 fn not_allowed_quoted_header_fields_error() {
     // skip `"` and `\`, otherwise state will change
-    for b in (0..255).filter(|&x| !is_quoted_header_field(x))
-                     .filter(|&x| x != b'"')
-                     .filter(|&x| x != b'\\') {
+    for b in (0..=255).filter(|&x| !is_quoted_header_field(x))
+                      .filter(|&x| x != b'"')
+                      .filter(|&x| x != b'\\') {
         let (mut p, mut h) = setup!();
 
         assert_eos(
@@ -271,7 +308,7 @@ fn not_allowed_quoted_header_fields_error() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::HeaderValue(b)
+            ErrorKind::HeaderValue(b)
         );
     }
 }
@@ -0,0 +1,218 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Action;
+use http1::*;
+use http1::test::*;
+
+macro_rules! setup {
+    () => ({
+        let (mut p, mut h) = http1_setup!();
+
+        assert_eos(
+            &mut p,
+            &mut h,
+            b"GET / HTTP/1.1\r\n",
+            ParserState::HeaderCr2,
+            b"GET / HTTP/1.1\r\n".len()
+        );
+
+        (p, h)
+    });
+}
+
+#[test]
+fn as_bytes() {
+    for name in &[HeaderName::Accept, HeaderName::ContentLength, HeaderName::Host,
+                  HeaderName::SecWebsocketKey, HeaderName::XForwardedFor] {
+        assert_eq!(
+            HeaderName::from_bytes(name.as_bytes()),
+            Some(*name)
+        );
+    }
+
+    assert_eq!(
+        HeaderName::from_bytes(b"Host"),
+        None
+    );
+}
+
+#[test]
+fn callback_exit() {
+    struct H;
+    impl HttpHandler for H {
+        fn on_known_header(&mut self, _: HeaderName) -> Action {
+            Action::Pause
+        }
+    }
+
+    let mut h = H;
+    let mut p = Parser::new();
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\nHost: localhost\r\n",
+        ParserState::StripHeaderValue,
+        b"GET / HTTP/1.1\r\nHost:".len()
+    );
+}
+
+#[test]
+fn fast_path() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"Host: localhost\r\n\
+          User-Agent: http-box\r\n\
+          X-Forwarded-For: 127.0.0.1\r\n\
+          \r\n",
+        b"Host: localhost\r\n\
+          User-Agent: http-box\r\n\
+          X-Forwarded-For: 127.0.0.1\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        h.known_headers,
+        vec![HeaderName::Host, HeaderName::UserAgent, HeaderName::XForwardedFor]
+    );
+}
+
+#[test]
+fn mixed_case() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"sEC-wEBSOCKET-kEY: abc\r\n\
+          \r\n",
+        b"sEC-wEBSOCKET-kEY: abc\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        h.known_headers,
+        vec![HeaderName::SecWebsocketKey]
+    );
+}
+
+#[test]
+fn multipart() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_multipart();
+    p.set_boundary(b"XXDebugBoundaryXX");
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"--XXDebugBoundaryXX\r\n\
+          Content-Disposition: form-data; name=\"a\"\r\n\
+          \r\n",
+        ParserState::MultipartDataByByte,
+        b"--XXDebugBoundaryXX\r\n\
+          Content-Disposition: form-data; name=\"a\"\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        h.known_headers,
+        vec![HeaderName::ContentDisposition]
+    );
+}
+
+#[test]
+fn split() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"Access-Control-",
+        ParserState::LowerHeaderName,
+        b"Access-Control-".len()
+    );
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"Request-Meth",
+        ParserState::LowerHeaderName,
+        b"Request-Meth".len()
+    );
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"od: GET\r\n",
+        ParserState::HeaderCr2,
+        b"od: GET\r\n".len()
+    );
+
+    assert_eq!(
+        h.known_headers,
+        vec![HeaderName::AccessControlRequestMethod]
+    );
+}
+
+#[test]
+fn trailers() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_chunked();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"0\r\n\
+          Server-Timing: total;dur=5\r\n\
+          X-Checksum: abc\r\n\
+          \r\n",
+        b"0\r\n\
+          Server-Timing: total;dur=5\r\n\
+          X-Checksum: abc\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        h.known_headers,
+        vec![HeaderName::ServerTiming]
+    );
+}
+
+#[test]
+fn unknown() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"X-Custom: 1\r\n\
+          Hostname: localhost\r\n\
+          Cross-Origin-Embedder-Policy-Report-Only-X: 1\r\n\
+          \r\n",
+        b"X-Custom: 1\r\n\
+          Hostname: localhost\r\n\
+          Cross-Origin-Embedder-Policy-Report-Only-X: 1\r\n\
+          \r\n".len()
+    );
+
+    assert!(h.known_headers.is_empty());
+}
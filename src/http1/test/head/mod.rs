@@ -15,5 +15,9 @@
 // +-----------------------------------------------------------------------------------------------+
 
 mod callback;
+mod framing;
+mod header_case;
 mod header_name;
 mod header_value;
+mod known_header;
+mod one_shot;
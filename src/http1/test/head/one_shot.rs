@@ -0,0 +1,236 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+
+#[test]
+fn request() {
+    let stream = b"POST /form HTTP/1.1\r\n\
+                   Host: localhost\r\n\
+                   X-Custom: A\r\n\
+                   X-Custom: B\r\n\
+                   Content-Length: 4\r\n\
+                   \r\n\
+                   Data";
+
+    match RequestHead::parse(stream) {
+        Ok(Parsed::Complete(head)) => {
+            assert_eq!(head.method(), b"POST");
+            assert_eq!(head.url(), b"/form");
+            assert_eq!(head.version(), (1, 1));
+            assert_eq!(head.headers().len(), 4);
+            assert_eq!(head.headers()[0], (&b"Host"[..], &b"localhost"[..]));
+            assert_eq!(head.headers()[2], (&b"X-Custom"[..], &b"B"[..]));
+            assert_eq!(head.header(b"x-custom"), Some(&b"A"[..]));
+            assert_eq!(head.header(b"CONTENT-LENGTH"), Some(&b"4"[..]));
+            assert_eq!(head.header(b"Accept"), None);
+            assert_eq!(&stream[head.length()..], b"Data");
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn request_leading_empty_line() {
+    match RequestHead::parse(b"\r\nGET / HTTP/1.1\r\nA: one\r\n\r\n") {
+        Ok(Parsed::Complete(head)) => {
+            assert_eq!(head.method(), b"GET");
+            assert_eq!(head.url(), b"/");
+            assert_eq!(head.headers(), &[(&b"A"[..], &b"one"[..])]);
+            assert_eq!(head.length(), 28);
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn request_multiline_value() {
+    match RequestHead::parse(b"GET / HTTP/1.0\r\nX-Folded: A\r\n  B\r\nX-Other: C\r\n\r\n") {
+        Ok(Parsed::Complete(head)) => {
+            assert_eq!(head.version(), (1, 0));
+            assert_eq!(head.headers(), &[(&b"X-Folded"[..], &b"A\r\n  B"[..]),
+                                         (&b"X-Other"[..], &b"C"[..])]);
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn request_no_headers() {
+    match RequestHead::parse(b"GET / HTTP/1.1\r\n\r\n") {
+        Ok(Parsed::Complete(head)) => {
+            assert!(head.headers().is_empty());
+            assert_eq!(head.length(), 18);
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn request_partial() {
+    let stream = b"GET /index.html HTTP/1.1\r\n\
+                   Host: localhost\r\n\
+                   \r\n";
+
+    for length in 0..stream.len() {
+        assert!(RequestHead::parse(&stream[..length]).unwrap().is_partial());
+    }
+
+    assert!(RequestHead::parse(stream).unwrap().is_complete());
+}
+
+#[test]
+fn request_framing_error() {
+    let stream = b"POST / HTTP/1.1\r\n\
+                   Transfer-Encoding: chunked\r\n\
+                   Content-Length: 5\r\n\
+                   \r\n";
+
+    match RequestHead::parse(stream).map_err(ParserError::into_kind) {
+        Err(ErrorKind::TransferEncodingWithContentLength) => {},
+        _ => panic!()
+    }
+}
+
+#[test]
+fn request_response() {
+    match RequestHead::parse(b"HTTP/1.1 200 OK\r\n\r\n") {
+        Err(error) => {
+            assert_eq!(error.kind(), &ErrorKind::UnexpectedResponse);
+            assert_eq!(error.offset(), 9);
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn request_too_many_headers() {
+    let mut stream = b"GET / HTTP/1.1\r\n".to_vec();
+
+    for _ in 0..MAX_HEADERS {
+        stream.extend_from_slice(b"X-Header: value\r\n");
+    }
+
+    stream.extend_from_slice(b"\r\n");
+
+    match RequestHead::parse(&stream) {
+        Ok(Parsed::Complete(head)) => {
+            assert_eq!(head.headers().len(), MAX_HEADERS);
+        },
+        _ => panic!()
+    }
+
+    let length = stream.len();
+
+    stream.truncate(length - 2);
+    stream.extend_from_slice(b"X-Header: value\r\n\r\n");
+
+    match RequestHead::parse(&stream).map_err(ParserError::into_kind) {
+        Err(ErrorKind::TooManyHeaders) => {},
+        _ => panic!()
+    }
+}
+
+#[test]
+fn request_upgrade() {
+    let stream = b"GET /chat HTTP/1.1\r\n\
+                   Connection: Upgrade\r\n\
+                   Upgrade: websocket\r\n\
+                   \r\n\
+                   frame";
+
+    match RequestHead::parse(stream) {
+        Ok(Parsed::Complete(head)) => {
+            assert_eq!(head.header(b"upgrade"), Some(&b"websocket"[..]));
+            assert_eq!(&stream[head.length()..], b"frame");
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn response() {
+    let stream = b"HTTP/1.1 404 Not Found\r\n\
+                   Content-Type: text/plain\r\n\
+                   Content-Length: 9\r\n\
+                   \r\n\
+                   Not Found";
+
+    match ResponseHead::parse(stream) {
+        Ok(Parsed::Complete(head)) => {
+            assert_eq!(head.version(), (1, 1));
+            assert_eq!(head.status_code(), 404);
+            assert_eq!(head.status(), b"Not Found");
+            assert_eq!(head.headers(), &[(&b"Content-Type"[..], &b"text/plain"[..]),
+                                         (&b"Content-Length"[..], &b"9"[..])]);
+            assert_eq!(&stream[head.length()..], b"Not Found");
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn response_leading_empty_line() {
+    match ResponseHead::parse(b"\r\nHTTP/1.1 200 OK\r\nA: one\r\n\r\n") {
+        Ok(Parsed::Complete(head)) => {
+            assert_eq!(head.status_code(), 200);
+            assert_eq!(head.status(), b"OK");
+            assert_eq!(head.headers(), &[(&b"A"[..], &b"one"[..])]);
+            assert_eq!(head.length(), 29);
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn response_partial() {
+    let stream = b"HTTP/1.1 200 OK\r\n\
+                   Content-Length: 0\r\n\
+                   \r\n";
+
+    for length in 0..stream.len() {
+        assert!(ResponseHead::parse(&stream[..length]).unwrap().is_partial());
+    }
+
+    assert!(ResponseHead::parse(stream).unwrap().is_complete());
+}
+
+#[test]
+fn response_request() {
+    match ResponseHead::parse(b"GET / HTTP/1.1\r\n\r\n") {
+        Err(error) => {
+            assert_eq!(error.kind(), &ErrorKind::UnexpectedRequest);
+            assert_eq!(error.offset(), 4);
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn response_switching_protocols() {
+    let stream = b"HTTP/1.1 101 Switching Protocols\r\n\
+                   Upgrade: websocket\r\n\
+                   \r\n\
+                   frame";
+
+    match ResponseHead::parse(stream) {
+        Ok(Parsed::Complete(head)) => {
+            assert_eq!(head.status_code(), 101);
+            assert_eq!(&stream[head.length()..], b"frame");
+        },
+        _ => panic!()
+    }
+}
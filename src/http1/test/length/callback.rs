@@ -0,0 +1,73 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Action;
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn on_body() {
+    struct H;
+    impl HttpHandler for H {
+        fn on_body(&mut self, _: &[u8]) -> Action {
+            Action::Pause
+        }
+    }
+
+    let mut h = H;
+    let mut p = Parser::new();
+
+    p.init_length(10);
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"Hello",
+        ParserState::BodyByLength,
+        b"Hello".len()
+    );
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"World",
+        ParserState::BodyFinished,
+        b"World".len()
+    );
+}
+
+#[test]
+fn on_body_finished() {
+    struct H;
+    impl HttpHandler for H {
+        fn on_body_finished(&mut self) -> Action {
+            Action::Pause
+        }
+    }
+
+    let mut h = H;
+    let mut p = Parser::new();
+
+    p.init_length(5);
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"Hello",
+        ParserState::Finished,
+        b"Hello".len()
+    );
+}
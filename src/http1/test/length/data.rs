@@ -0,0 +1,114 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn entire_body() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_length(12);
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"Hello, Rust!",
+        b"Hello, Rust!".len()
+    );
+
+    assert_eq!(
+        &h.body,
+        b"Hello, Rust!"
+    );
+
+    assert!(h.body_finished);
+}
+
+#[test]
+fn entire_iter() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_length(12);
+
+    iter_assert_eos(
+        &mut p,
+        &mut h,
+        &[(b'H', ParserState::BodyByLength),
+          (b'e', ParserState::BodyByLength),
+          (b'l', ParserState::BodyByLength),
+          (b'l', ParserState::BodyByLength),
+          (b'o', ParserState::BodyByLength),
+          (b',', ParserState::BodyByLength),
+          (b' ', ParserState::BodyByLength),
+          (b'R', ParserState::BodyByLength),
+          (b'u', ParserState::BodyByLength),
+          (b's', ParserState::BodyByLength),
+          (b't', ParserState::BodyByLength)]
+    );
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"!",
+        b"!".len()
+    );
+
+    assert_eq!(
+        &h.body,
+        b"Hello, Rust!"
+    );
+}
+
+#[test]
+fn extra_data() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_length(5);
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HelloGET / HTTP/1.1\r\n",
+        b"Hello".len()
+    );
+
+    assert_eq!(
+        &h.body,
+        b"Hello"
+    );
+}
+
+#[test]
+fn zero_length() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_length(0);
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"",
+        0
+    );
+
+    assert_eq!(
+        &h.body,
+        b""
+    );
+
+    assert!(h.body_finished);
+}
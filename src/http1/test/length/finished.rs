@@ -0,0 +1,45 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn finished() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_length(b"{\"key\": \"value\"}".len());
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"{\"key\": ",
+        ParserState::BodyByLength,
+        b"{\"key\": ".len()
+    );
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"\"value\"}",
+        b"\"value\"}".len()
+    );
+
+    assert_eq!(
+        &h.body,
+        b"{\"key\": \"value\"}"
+    );
+}
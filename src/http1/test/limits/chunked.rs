@@ -0,0 +1,120 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+macro_rules! setup {
+    ($length:expr) => ({
+        let (mut p, mut h) = http1_setup!();
+
+        p.init_chunked();
+        p.set_limits(Limits{
+            max_chunk_extension_length: $length,
+            ..Limits::default()
+        });
+
+        (p, h)
+    });
+}
+
+#[test]
+fn extension_length() {
+    let (mut p, mut h) = setup!(14);
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"1;name1=a;name2\r\n\
+          X\r\n\
+          1;name1=a;name2\r\n",
+        ParserState::ChunkData,
+        37
+    );
+
+    assert_eq!(h.chunk_data, b"X");
+}
+
+#[test]
+fn extension_length_error() {
+    let (mut p, mut h) = setup!(13);
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"1;name1=a;name2\r\n",
+        ErrorKind::ChunkExtensionTooLong
+    );
+}
+
+#[test]
+fn extension_length_error_whitespace() {
+    let (mut p, mut h) = setup!(15);
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"1;      ",
+        ParserState::StripChunkExtensionName,
+        8
+    );
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"          ",
+        ErrorKind::ChunkExtensionTooLong
+    );
+}
+
+#[test]
+fn size() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_chunked();
+    p.set_limits(Limits{
+        max_chunk_size: 16,
+        ..Limits::default()
+    });
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"10\r\n",
+        ParserState::ChunkData,
+        b"10\r\n".len()
+    );
+
+    assert_eq!(h.chunk_length, 16);
+}
+
+#[test]
+fn size_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_chunked();
+    p.set_limits(Limits{
+        max_chunk_size: 16,
+        ..Limits::default()
+    });
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"11;name=value\r\n",
+        ErrorKind::ChunkTooLarge
+    );
+}
@@ -0,0 +1,391 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+macro_rules! setup {
+    ($limits:expr) => ({
+        let (mut p, mut h) = http1_setup!();
+
+        p.set_limits($limits);
+
+        (p, h)
+    });
+}
+
+#[test]
+fn head_size() {
+    let (mut p, mut h) = setup!(Limits{
+        max_head_size: 32,
+        ..Limits::default()
+    });
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Host: abcdef\r\n\
+          \r\n",
+        32
+    );
+}
+
+#[test]
+fn head_size_error() {
+    let (mut p, mut h) = setup!(Limits{
+        max_head_size: 31,
+        ..Limits::default()
+    });
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Host: abcdef\r\n\
+          \r\n",
+        ErrorKind::HeadTooLarge
+    );
+}
+
+#[test]
+fn head_size_error_multiple_slices() {
+    let (mut p, mut h) = setup!(Limits{
+        max_head_size: 31,
+        ..Limits::default()
+    });
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n",
+        ParserState::HeaderCr2,
+        16
+    );
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"Host: abcdef",
+        ParserState::HeaderValue,
+        12
+    );
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"\r\n\r\n",
+        ErrorKind::HeadTooLarge
+    );
+}
+
+#[test]
+fn header_name_length() {
+    let (mut p, mut h) = setup!(Limits{
+        max_header_name_length: 6,
+        ..Limits::default()
+    });
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          HeAdEr: value\r\n\
+          \r\n",
+        33
+    );
+
+    assert_eq!(h.header_name, b"header");
+}
+
+#[test]
+fn header_name_length_error() {
+    let (mut p, mut h) = setup!(Limits{
+        max_header_name_length: 5,
+        ..Limits::default()
+    });
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          HeAdEr: value\r\n\
+          \r\n",
+        ErrorKind::HeaderNameTooLong
+    );
+}
+
+#[test]
+fn header_name_length_error_common() {
+    let (mut p, mut h) = setup!(Limits{
+        max_header_name_length: 11,
+        ..Limits::default()
+    });
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Content-Type: text/plain; charset=utf-8\r\n\
+          \r\n",
+        ErrorKind::HeaderNameTooLong
+    );
+}
+
+#[test]
+fn header_value_length() {
+    let (mut p, mut h) = setup!(Limits{
+        max_header_value_length: 7,
+        ..Limits::default()
+    });
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Header: \"a\\b\"cd\r\n\
+          \r\n",
+        35
+    );
+
+    assert_eq!(h.header_value, b"\"a\\b\"cd");
+}
+
+#[test]
+fn header_value_length_error() {
+    let (mut p, mut h) = setup!(Limits{
+        max_header_value_length: 6,
+        ..Limits::default()
+    });
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Header: \"a\\b\"cd\r\n\
+          \r\n",
+        ErrorKind::HeaderValueTooLong
+    );
+}
+
+#[test]
+fn header_value_length_error_multiple_slices() {
+    let (mut p, mut h) = setup!(Limits{
+        max_header_value_length: 5,
+        ..Limits::default()
+    });
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Header: abc",
+        ParserState::HeaderValue,
+        27
+    );
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"def\r\n",
+        ErrorKind::HeaderValueTooLong
+    );
+
+    assert_eq!(h.header_value, b"abc");
+}
+
+#[test]
+fn headers() {
+    let (mut p, mut h) = setup!(Limits{
+        max_headers: 2,
+        ..Limits::default()
+    });
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Header1: value1\r\n\
+          Header2: value2\r\n\
+          \r\n",
+        52
+    );
+}
+
+#[test]
+fn headers_error() {
+    let (mut p, mut h) = setup!(Limits{
+        max_headers: 2,
+        ..Limits::default()
+    });
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Header1: value1\r\n\
+          Header2: value2\r\n\
+          Header3: value3\r\n\
+          \r\n",
+        ErrorKind::TooManyHeaders
+    );
+}
+
+#[test]
+fn method_length() {
+    let (mut p, mut h) = setup!(Limits{
+        max_method_length: 6,
+        ..Limits::default()
+    });
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"DELETE / HTTP/1.1\r\n\r\n",
+        21
+    );
+
+    assert_eq!(h.method, b"DELETE");
+}
+
+#[test]
+fn method_length_error() {
+    let (mut p, mut h) = setup!(Limits{
+        max_method_length: 5,
+        ..Limits::default()
+    });
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"DELETE / HTTP/1.1\r\n\r\n",
+        ErrorKind::MethodTooLong
+    );
+
+    assert_eq!(h.method, b"");
+}
+
+#[test]
+fn method_length_error_multiple_slices() {
+    let (mut p, mut h) = setup!(Limits{
+        max_method_length: 5,
+        ..Limits::default()
+    });
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"EXTEN",
+        ParserState::RequestMethod,
+        5
+    );
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"SION / HTTP/1.1\r\n\r\n",
+        ErrorKind::MethodTooLong
+    );
+
+    assert_eq!(h.method, b"EXTEN");
+}
+
+#[test]
+fn reset() {
+    let (mut p, mut h) = setup!(Limits{
+        max_headers: 1,
+        ..Limits::default()
+    });
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Header1: value1\r\n\
+          \r\n",
+        35
+    );
+
+    p.reset();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Header1: value1\r\n\
+          \r\n",
+        35
+    );
+}
+
+#[test]
+fn url_length() {
+    let (mut p, mut h) = setup!(Limits{
+        max_url_length: 8,
+        ..Limits::default()
+    });
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET /abc?def HTTP/1.1\r\n\r\n",
+        25
+    );
+
+    assert_eq!(h.url, b"/abc?def");
+}
+
+#[test]
+fn url_length_error() {
+    let (mut p, mut h) = setup!(Limits{
+        max_url_length: 7,
+        ..Limits::default()
+    });
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"GET /abc?def HTTP/1.1\r\n\r\n",
+        ErrorKind::UrlTooLong
+    );
+
+    assert_eq!(h.url, b"");
+}
+
+#[test]
+fn url_length_error_multiple_slices() {
+    let (mut p, mut h) = setup!(Limits{
+        max_url_length: 7,
+        ..Limits::default()
+    });
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"GET /abc?",
+        ParserState::RequestUrl2,
+        9
+    );
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"def HTTP/1.1\r\n\r\n",
+        ErrorKind::UrlTooLong
+    );
+
+    assert_eq!(h.url, b"/abc?");
+}
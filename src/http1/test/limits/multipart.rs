@@ -0,0 +1,77 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+macro_rules! setup {
+    () => ({
+        let (mut p, mut h) = http1_setup!();
+
+        p.init_multipart();
+        p.set_boundary(b"XTestBoundaryX");
+        p.set_limits(Limits{
+            max_multipart_headers: 2,
+            ..Limits::default()
+        });
+
+        (p, h)
+    });
+}
+
+#[test]
+fn headers() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"--XTestBoundaryX\r\n\
+          Header1: Value1\r\n\
+          Header2: Value2\r\n\
+          \r\n\
+          Data\r\n\
+          --XTestBoundaryX\r\n\
+          Header1: Value1\r\n\
+          Header2: Value2\r\n\
+          \r\n",
+        ParserState::MultipartDataByByte,
+        b"--XTestBoundaryX\r\n\
+          Header1: Value1\r\n\
+          Header2: Value2\r\n\
+          \r\n\
+          Data\r\n\
+          --XTestBoundaryX\r\n\
+          Header1: Value1\r\n\
+          Header2: Value2\r\n\
+          \r\n".len()
+    );
+}
+
+#[test]
+fn headers_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"--XTestBoundaryX\r\n\
+          Header1: Value1\r\n\
+          Header2: Value2\r\n\
+          Header3: Value3\r\n",
+        ErrorKind::TooManyHeaders
+    );
+}
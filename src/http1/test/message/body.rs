@@ -0,0 +1,282 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn chunked() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: gzip, chunked\r\n\
+          \r\n\
+          5\r\n\
+          Hello\r\n\
+          0\r\n\
+          \r\n",
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: gzip, chunked\r\n\
+          \r\n\
+          5\r\n\
+          Hello\r\n\
+          0\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.chunk_data,
+        b"Hello"
+    );
+
+    assert!(h.body_finished);
+}
+
+#[test]
+fn chunked_split_header_name() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          tRANSFER-eNC",
+        ParserState::LowerHeaderName,
+        b"POST / HTTP/1.1\r\n\
+          tRANSFER-eNC".len()
+    );
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"oding: chu",
+        ParserState::HeaderValue,
+        b"oding: chu".len()
+    );
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"nked\r\n\
+          \r\n\
+          0\r\n\
+          \r\n",
+        b"nked\r\n\
+          \r\n\
+          0\r\n\
+          \r\n".len()
+    );
+
+    assert!(h.body_finished);
+}
+
+#[test]
+fn content_length() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Type: application/json\r\n\
+          Content-Length: 16\r\n\
+          \r\n\
+          {\"key\": \"value\"}",
+        b"POST / HTTP/1.1\r\n\
+          Content-Type: application/json\r\n\
+          Content-Length: 16\r\n\
+          \r\n\
+          {\"key\": \"value\"}".len()
+    );
+
+    assert_eq!(
+        &h.body,
+        b"{\"key\": \"value\"}"
+    );
+
+    assert!(h.body_finished);
+}
+
+#[test]
+fn content_length_duplicate() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 5\r\n\
+          Content-Length: 5, 5\r\n\
+          \r\n\
+          Hello",
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 5\r\n\
+          Content-Length: 5, 5\r\n\
+          \r\n\
+          Hello".len()
+    );
+
+    assert_eq!(
+        &h.body,
+        b"Hello"
+    );
+}
+
+#[test]
+fn multipart() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Type: multipart/form-data; boundary=\"XTestBoundaryX\"\r\n\
+          Content-Length: 86\r\n\
+          \r\n\
+          --XTestBoundaryX\r\n\
+          Content-Disposition: form-data; name=\"a\"\r\n\
+          \r\n\
+          Data\r\n\
+          --XTestBoundaryX--",
+        b"POST / HTTP/1.1\r\n\
+          Content-Type: multipart/form-data; boundary=\"XTestBoundaryX\"\r\n\
+          Content-Length: 86\r\n\
+          \r\n\
+          --XTestBoundaryX\r\n\
+          Content-Disposition: form-data; name=\"a\"\r\n\
+          \r\n\
+          Data\r\n\
+          --XTestBoundaryX--".len()
+    );
+
+    assert_eq!(
+        &h.multipart_data,
+        b"Data"
+    );
+
+    assert_eq!(
+        &h.header_name,
+        b"content-typecontent-lengthcontent-disposition"
+    );
+}
+
+#[test]
+fn no_body() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Host: localhost\r\n\
+          \r\n\
+          GET",
+        b"GET / HTTP/1.1\r\n\
+          Host: localhost\r\n\
+          \r\n".len()
+    );
+
+    assert!(!h.body_finished);
+}
+
+#[test]
+fn reset() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n\
+          0\r\n\
+          \r\n",
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n\
+          0\r\n\
+          \r\n".len()
+    );
+
+    p.reset();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 2\r\n\
+          \r\n\
+          OK",
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 2\r\n\
+          \r\n\
+          OK".len()
+    );
+
+    assert_eq!(
+        &h.body,
+        b"OK"
+    );
+}
+
+#[test]
+fn url_encoded() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Type: application/x-www-form-urlencoded; charset=utf-8\r\n\
+          Content-Length: 17\r\n\
+          \r\n\
+          Name+1=Value%201!\r\n",
+        b"POST / HTTP/1.1\r\n\
+          Content-Type: application/x-www-form-urlencoded; charset=utf-8\r\n\
+          Content-Length: 17\r\n\
+          \r\n\
+          Name+1=Value%201!".len()
+    );
+
+    assert_eq!(
+        &h.url_encoded_name,
+        b"Name 1"
+    );
+
+    assert_eq!(
+        &h.url_encoded_value,
+        b"Value 1!"
+    );
+}
@@ -0,0 +1,89 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Action;
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn on_headers_finished() {
+    struct H;
+    impl HttpHandler for H {
+        fn on_headers_finished(&mut self) -> Action {
+            Action::Pause
+        }
+    }
+
+    let mut h = H;
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 5\r\n\
+          \r\n",
+        ParserState::DetectBody,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 5\r\n\
+          \r\n".len()
+    );
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"Hello",
+        b"Hello".len()
+    );
+}
+
+#[test]
+fn on_headers_finished_url_encoded() {
+    struct H;
+    impl HttpHandler for H {
+        fn on_headers_finished(&mut self) -> Action {
+            Action::Pause
+        }
+    }
+
+    let mut h = H;
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Type: application/x-www-form-urlencoded\r\n\
+          Content-Length: 3\r\n\
+          \r\n",
+        ParserState::DetectBody,
+        b"POST / HTTP/1.1\r\n\
+          Content-Type: application/x-www-form-urlencoded\r\n\
+          Content-Length: 3\r\n\
+          \r\n".len()
+    );
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"a=b",
+        b"a=b".len()
+    );
+}
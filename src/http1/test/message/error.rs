@@ -0,0 +1,182 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn chunked_not_last_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked, gzip\r\n\
+          \r\n",
+        ErrorKind::ChunkedNotLast
+    );
+}
+
+#[test]
+fn chunked_not_last_multiple_headers_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Transfer-Encoding: gzip\r\n\
+          \r\n",
+        ErrorKind::ChunkedNotLast
+    );
+}
+
+#[test]
+fn chunked_twice_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked, chunked\r\n\
+          \r\n",
+        ErrorKind::ChunkedNotLast
+    );
+}
+
+#[test]
+fn chunked_missing_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: gzip\r\n\
+          \r\n",
+        ErrorKind::ChunkedNotLast
+    );
+}
+
+#[test]
+fn content_length_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 1x\r\n\
+          \r\n",
+        ErrorKind::ContentLength(b'x')
+    );
+}
+
+#[test]
+fn content_length_overflow_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 999999999999999999999999\r\n\
+          \r\n",
+        ErrorKind::ContentLengthOverflow
+    );
+}
+
+#[test]
+fn content_length_mismatch_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 5, 6\r\n\
+          \r\n",
+        ErrorKind::ContentLengthMismatch
+    );
+}
+
+#[test]
+fn content_length_mismatch_multiple_headers_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 5\r\n\
+          Content-Length: 6\r\n\
+          \r\n",
+        ErrorKind::ContentLengthMismatch
+    );
+}
+
+#[test]
+fn transfer_encoding_with_content_length_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          Content-Length: 5\r\n\
+          \r\n",
+        ErrorKind::TransferEncodingWithContentLength
+    );
+}
+
+#[test]
+fn content_length_with_transfer_encoding_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n\
+          Content-Length: 5\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n",
+        ErrorKind::TransferEncodingWithContentLength
+    );
+}
@@ -0,0 +1,339 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Action;
+use http1::*;
+use http1::test::*;
+
+macro_rules! setup {
+    () => ({
+        let (mut p, mut h) = http1_setup!();
+
+        p.init_message();
+
+        (p, h)
+    });
+}
+
+#[test]
+fn head_request() {
+    let (mut p, mut h) = setup!();
+
+    p.set_request_method(b"HEAD");
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Length: 5\r\n\
+          \r\n\
+          Hello",
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Length: 5\r\n\
+          \r\n".len()
+    );
+
+    assert!(h.body.is_empty());
+}
+
+#[test]
+fn head_request_reset() {
+    let (mut p, mut h) = setup!();
+
+    p.set_request_method(b"HEAD");
+    p.reset();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Length: 5\r\n\
+          \r\n\
+          Hello",
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Length: 5\r\n\
+          \r\n\
+          Hello".len()
+    );
+
+    assert_eq!(
+        &h.body,
+        b"Hello"
+    );
+}
+
+#[test]
+fn interim() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 100 Continue\r\n\
+          \r\n\
+          HTTP/1.1 103 Early Hints\r\n\
+          Link: </style.css>; rel=preload\r\n\
+          \r\n\
+          HTTP/1.1 200 OK\r\n\
+          Content-Length: 5\r\n\
+          \r\n\
+          Hello",
+        b"HTTP/1.1 100 Continue\r\n\
+          \r\n\
+          HTTP/1.1 103 Early Hints\r\n\
+          Link: </style.css>; rel=preload\r\n\
+          \r\n\
+          HTTP/1.1 200 OK\r\n\
+          Content-Length: 5\r\n\
+          \r\n\
+          Hello".len()
+    );
+
+    assert_eq!(
+        h.interim,
+        vec![100, 103]
+    );
+
+    assert_eq!(h.status_code, 200);
+    assert_eq!(h.message_begin, 1);
+    assert_eq!(h.message_finished.len(), 1);
+
+    assert_eq!(
+        &h.body,
+        b"Hello"
+    );
+}
+
+#[test]
+fn interim_callback_exit() {
+    struct X;
+
+    impl HttpHandler for X {
+        fn on_interim(&mut self, _code: u16) -> Action {
+            Action::Pause
+        }
+    }
+
+    let mut h = X{};
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 100 Continue\r\n\
+          \r\n\
+          HTTP/1.1 204 No Content\r\n\
+          \r\n",
+        ParserState::Detect1,
+        b"HTTP/1.1 100 Continue\r\n\
+          \r\n".len()
+    );
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 204 No Content\r\n\
+          \r\n",
+        b"HTTP/1.1 204 No Content\r\n\
+          \r\n".len()
+    );
+}
+
+#[test]
+fn interim_split() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 100 Continue\r\n\
+          \r\n\
+          HTTP/1.1 2",
+        ParserState::ResponseStatusCode2,
+        b"HTTP/1.1 100 Continue\r\n\
+          \r\n\
+          HTTP/1.1 2".len()
+    );
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"00 OK\r\n\
+          Content-Length: 2\r\n\
+          \r\n\
+          OK",
+        b"00 OK\r\n\
+          Content-Length: 2\r\n\
+          \r\n\
+          OK".len()
+    );
+
+    assert_eq!(
+        &h.body,
+        b"OK"
+    );
+}
+
+#[test]
+fn no_content() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 204 No Content\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n\
+          0\r\n\
+          \r\n",
+        b"HTTP/1.1 204 No Content\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n".len()
+    );
+
+    assert!(!h.body_finished);
+}
+
+#[test]
+fn not_modified() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 304 Not Modified\r\n\
+          Content-Length: 5\r\n\
+          \r\n\
+          Hello",
+        b"HTTP/1.1 304 Not Modified\r\n\
+          Content-Length: 5\r\n\
+          \r\n".len()
+    );
+
+    assert!(h.body.is_empty());
+}
+
+#[test]
+fn persistent_head_request() {
+    struct X(Vec<u8>);
+
+    impl HttpHandler for X {
+        fn on_body(&mut self, data: &[u8]) -> Action {
+            self.0.extend_from_slice(data);
+            Action::Continue
+        }
+
+        fn on_message_finished(&mut self, _keep_alive: bool) -> Action {
+            Action::Pause
+        }
+    }
+
+    let mut h = X(Vec::new());
+    let mut p = Parser::new();
+
+    p.init_persistent();
+    p.set_request_method(b"HEAD");
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Length: 2\r\n\
+          \r\n\
+          HTTP/1.1 200 OK\r\n\
+          Content-Length: 2\r\n\
+          \r\n\
+          OK",
+        ParserState::StripDetect,
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Length: 2\r\n\
+          \r\n".len()
+    );
+
+    p.set_request_method(b"GET");
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Length: 2\r\n\
+          \r\n\
+          OK",
+        ParserState::StripDetect,
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Length: 2\r\n\
+          \r\n\
+          OK".len()
+    );
+
+    assert_eq!(
+        &h.0,
+        b"OK"
+    );
+}
+
+#[test]
+fn interim_eos() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 100 Continue\r\n\
+          \r\n",
+        ParserState::Detect1,
+        b"HTTP/1.1 100 Continue\r\n\
+          \r\n".len()
+    );
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 204 No Content\r\n\
+          \r\n",
+        b"HTTP/1.1 204 No Content\r\n\
+          \r\n".len()
+    );
+}
+
+#[test]
+fn transfer_encoding_not_chunked() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 OK\r\n\
+          Transfer-Encoding: gzip\r\n\
+          \r\n\
+          abc",
+        ParserState::BodyByEof,
+        b"HTTP/1.1 200 OK\r\n\
+          Transfer-Encoding: gzip\r\n\
+          \r\n\
+          abc".len()
+    );
+
+    assert_eq!(
+        &h.body,
+        b"abc"
+    );
+
+    assert!(p.finish(&mut h).is_ok());
+    assert!(h.body_finished);
+}
@@ -14,10 +14,10 @@
 // | limitations under the License.                                                                |
 // +-----------------------------------------------------------------------------------------------+
 
-use fsm::Success;
-use http1::{ HttpHandler, Parser, ParserError, ParserState };
+use fsm::{ Action, Success };
+use http1::{ ErrorKind, HeaderName, HttpHandler, Parser, ParserState };
 
-use std::str;
+use std::{ fmt, str };
 
 macro_rules! http1_setup {
     () => (
@@ -25,8 +25,8 @@ macro_rules! http1_setup {
     )
 }
 
-fn assert_callback<T: HttpHandler>(
-    parser:  &mut Parser<T>,
+fn assert_callback<E, T: HttpHandler<E>>(
+    parser:  &mut Parser<T, E>,
     handler: &mut T,
     stream:  &[u8],
     state:   ParserState,
@@ -41,8 +41,8 @@ fn assert_callback<T: HttpHandler>(
     }
 }
 
-fn assert_eos<T: HttpHandler>(
-    parser:  &mut Parser<T>,
+fn assert_eos<E, T: HttpHandler<E>>(
+    parser:  &mut Parser<T, E>,
     handler: &mut T,
     stream:  &[u8],
     state:   ParserState,
@@ -57,23 +57,23 @@ fn assert_eos<T: HttpHandler>(
     }
 }
 
-fn assert_error<T: HttpHandler>(
-    parser:  &mut Parser<T>,
+fn assert_error<E: fmt::Debug + PartialEq, T: HttpHandler<E>>(
+    parser:  &mut Parser<T, E>,
     handler: &mut T,
     stream:  &[u8],
-    error:   ParserError
+    error:   ErrorKind<E>
 ) {
     match parser.resume(handler, stream) {
         Err(error_) => {
-            assert_eq!(error, error_);
+            assert_eq!(&error, error_.kind());
             assert_eq!(ParserState::Dead, parser.state());
         },
         _ => panic!("assert_error() Err() match failed")
     }
 }
 
-fn iter_assert_eos<T: HttpHandler>(
-    parser:  &mut Parser<T>,
+fn iter_assert_eos<E, T: HttpHandler<E>>(
+    parser:  &mut Parser<T, E>,
     handler: &mut T,
     details: &[(u8, ParserState)]
 ) {
@@ -93,8 +93,8 @@ fn iter_assert_eos<T: HttpHandler>(
     }
 }
 
-fn assert_finished<T: HttpHandler>(
-    parser:  &mut Parser<T>,
+fn assert_finished<E, T: HttpHandler<E>>(
+    parser:  &mut Parser<T, E>,
     handler: &mut T,
     stream:  &[u8],
     length:  usize
@@ -108,6 +108,21 @@ fn assert_finished<T: HttpHandler>(
     }
 }
 
+fn assert_upgrade<E, T: HttpHandler<E>>(
+    parser:  &mut Parser<T, E>,
+    handler: &mut T,
+    stream:  &[u8],
+    length:  usize
+) {
+    match parser.resume(handler, stream) {
+        Ok(Success::Upgrade(length_)) => {
+            assert_eq!(length, length_);
+            assert_eq!(ParserState::Upgraded, parser.state());
+        },
+        _ => panic!("assert_upgrade() Ok() match failed")
+    }
+}
+
 /// `DebugHandler` works with all `http1::Parser` parsing methods.
 ///
 /// When in use, all parsed bytes will be printed, along with the callback name and length
@@ -120,6 +135,9 @@ fn assert_finished<T: HttpHandler>(
 /// collecting the data don't consume too much memory.
 #[derive(Default)]
 pub struct DebugHandler {
+    /// Body data.
+    pub body: Vec<u8>,
+
     /// Indicates that the body has successfully been parsed.
     pub body_finished: bool,
 
@@ -139,7 +157,7 @@ pub struct DebugHandler {
     pub chunk_extensions_finished: bool,
 
     /// Chunk length.
-    pub chunk_length: usize,
+    pub chunk_length: u64,
 
     /// Header name.
     pub header_name: Vec<u8>,
@@ -153,6 +171,18 @@ pub struct DebugHandler {
     /// Indicates that the initial request/response line has successfully been parsed.
     pub initial_finished: bool,
 
+    /// Interim response status codes.
+    pub interim: Vec<u16>,
+
+    /// Known header names.
+    pub known_headers: Vec<HeaderName>,
+
+    /// Count of messages that have begun.
+    pub message_begin: usize,
+
+    /// Keep-alive status of each message that has finished.
+    pub message_finished: Vec<bool>,
+
     /// Request method.
     pub method: Vec<u8>,
 
@@ -174,6 +204,9 @@ pub struct DebugHandler {
     /// URL encoded value.
     pub url_encoded_value: Vec<u8>,
 
+    /// Indicates that the connection has switched to another protocol.
+    pub upgrade: bool,
+
     /// HTTP major version.
     pub version_major: u16,
 
@@ -185,6 +218,7 @@ impl DebugHandler {
     /// Create a new `DebugHandler`.
     pub fn new() -> DebugHandler {
         DebugHandler{
+            body:                      Vec::new(),
             body_finished:             false,
             chunk_data:                Vec::new(),
             chunk_extension_finished:  false,
@@ -196,6 +230,10 @@ impl DebugHandler {
             header_value:              Vec::new(),
             headers_finished:          false,
             initial_finished:          false,
+            interim:                   Vec::new(),
+            known_headers:             Vec::new(),
+            message_begin:             0,
+            message_finished:          Vec::new(),
             method:                    Vec::new(),
             multipart_data:            Vec::new(),
             status:                    Vec::new(),
@@ -203,6 +241,7 @@ impl DebugHandler {
             url:                       Vec::new(),
             url_encoded_name:          Vec::new(),
             url_encoded_value:         Vec::new(),
+            upgrade:                   false,
             version_major:             0,
             version_minor:             0
         }
@@ -214,175 +253,235 @@ impl HttpHandler for DebugHandler {
         None
     }
 
-    fn on_body_finished(&mut self) -> bool {
+    fn on_body(&mut self, data: &[u8]) -> Action {
+        self.body.extend_from_slice(data);
+
+        for byte in data {
+            if is_not_visible_7bit!(*byte) && *byte != b' ' {
+                println!("on_body [{}]: *hidden*", data.len());
+                return Action::Continue;
+            }
+        }
+
+        println!("on_body [{}]: {:?}", data.len(), str::from_utf8(data).unwrap());
+        Action::Continue
+    }
+
+    fn on_body_finished(&mut self) -> Action {
+        self.body_finished = true;
         println!("on_body_finished");
-        true
+        Action::Continue
     }
 
-    fn on_chunk_begin(&mut self) -> bool {
+    fn on_chunk_begin(&mut self) -> Action {
         println!("on_chunk_begin");
-        true
+        Action::Continue
     }
 
-    fn on_chunk_data(&mut self, data: &[u8]) -> bool {
+    fn on_chunk_data(&mut self, data: &[u8]) -> Action {
         self.chunk_data.extend_from_slice(data);
 
         for byte in data {
             if is_not_visible_7bit!(*byte) && *byte != b' ' {
                 println!("on_chunk_data [{}]: *hidden*", data.len());
-                return true;
+                return Action::Continue;
             }
         }
 
         println!("on_chunk_data [{}]: {:?}", data.len(), str::from_utf8(data).unwrap());
-        true
+        Action::Continue
     }
 
-    fn on_chunk_extension_finished(&mut self) -> bool {
+    fn on_chunk_extension_finished(&mut self) -> Action {
         self.chunk_extension_finished = true;
-        true
+        Action::Continue
     }
 
-    fn on_chunk_extension_name(&mut self, name: &[u8]) -> bool {
+    fn on_chunk_extension_name(&mut self, name: &[u8]) -> Action {
         self.chunk_extension_name.extend_from_slice(name);
         println!("on_chunk_extension_name [{}]: {:?}", name.len(), str::from_utf8(name).unwrap());
-        true
+        Action::Continue
     }
 
-    fn on_chunk_extension_value(&mut self, value: &[u8]) -> bool {
+    fn on_chunk_extension_value(&mut self, value: &[u8]) -> Action {
         self.chunk_extension_value.extend_from_slice(value);
 
         for byte in value {
             if is_not_visible_7bit!(*byte) && *byte != b' ' {
                 println!("on_chunk_extension_value [{}]: *hidden*", value.len());
-                return true;
+                return Action::Continue;
             }
         }
 
-        true
+        Action::Continue
     }
 
-    fn on_chunk_extensions_finished(&mut self) -> bool {
+    fn on_chunk_extensions_finished(&mut self) -> Action {
         self.chunk_extensions_finished = true;
-        true
+        Action::Continue
     }
 
-    fn on_chunk_length(&mut self, length: usize) -> bool {
+    fn on_chunk_length(&mut self, length: u64) -> Action {
         self.chunk_length = length;
         println!("on_chunk_length: {}", length);
-        true
+        Action::Continue
     }
 
-    fn on_header_name(&mut self, name: &[u8]) -> bool {
+    fn on_header_name(&mut self, name: &[u8]) -> Action {
         self.header_name.extend_from_slice(name);
         println!("on_header_name [{}]: {:?}", name.len(), str::from_utf8(name).unwrap());
-        true
+        Action::Continue
     }
 
-    fn on_header_value(&mut self, value: &[u8]) -> bool {
+    fn on_header_value(&mut self, value: &[u8]) -> Action {
         self.header_value.extend_from_slice(value);
 
         for byte in value {
             if is_not_visible_7bit!(*byte) && *byte != b' ' {
                 println!("on_header_value [{}]: *hidden*", value.len());
-                return true;
+                return Action::Continue;
             }
         }
 
         println!("on_header_value [{}]: {:?}", value.len(), str::from_utf8(value).unwrap());
-        true
+        Action::Continue
     }
 
-    fn on_headers_finished(&mut self) -> bool {
+    fn on_headers_finished(&mut self) -> Action {
         self.headers_finished = true;
         println!("on_headers_finished");
-        true
+        Action::Continue
     }
 
-    fn on_initial_finished(&mut self) -> bool {
+    fn on_initial_finished(&mut self) -> Action {
         self.initial_finished = true;
         println!("on_initial_finished");
-        true
+        Action::Continue
+    }
+
+    fn on_interim(&mut self, code: u16) -> Action {
+        self.interim.push(code);
+        println!("on_interim: {}", code);
+        Action::Continue
     }
 
-    fn on_method(&mut self, method: &[u8]) -> bool {
+    fn on_known_header(&mut self, name: HeaderName) -> Action {
+        self.known_headers.push(name);
+        println!("on_known_header: {:?}", name);
+        Action::Continue
+    }
+
+    fn on_message_begin(&mut self) -> Action {
+        self.message_begin += 1;
+        println!("on_message_begin");
+        Action::Continue
+    }
+
+    fn on_message_finished(&mut self, keep_alive: bool) -> Action {
+        self.message_finished.push(keep_alive);
+        println!("on_message_finished: {}", keep_alive);
+        Action::Continue
+    }
+
+    fn on_method(&mut self, method: &[u8]) -> Action {
         self.method.extend_from_slice(method);
         println!("on_method [{}]: {:?}", method.len(), str::from_utf8(method).unwrap());
-        true
+        Action::Continue
     }
 
-    fn on_multipart_begin(&mut self) -> bool {
+    fn on_multipart_begin(&mut self) -> Action {
         println!("on_multipart_begin");
-        true
+        Action::Continue
     }
 
-    fn on_multipart_data(&mut self, data: &[u8]) -> bool {
+    fn on_multipart_data(&mut self, data: &[u8]) -> Action {
         self.multipart_data.extend_from_slice(data);
 
         for byte in data {
             if is_not_visible_7bit!(*byte) {
                 println!("on_multipart_data [{}]: *hidden*", data.len());
-                return true;
+                return Action::Continue;
             }
         }
 
         println!("on_multipart_data [{}]: {:?}", data.len(), str::from_utf8(data).unwrap());
-        true
+        Action::Continue
     }
 
-    fn on_status(&mut self, status: &[u8]) -> bool {
+    fn on_status(&mut self, status: &[u8]) -> Action {
         self.status.extend_from_slice(status);
 
         for byte in status {
             if is_not_visible_7bit!(*byte) {
                 println!("on_status [{}]: *hidden*", status.len());
-                return true;
+                return Action::Continue;
             }
         }
 
-        true
+        Action::Continue
     }
 
-    fn on_status_code(&mut self, code: u16) -> bool {
+    fn on_status_code(&mut self, code: u16) -> Action {
         self.status_code = code;
         println!("on_status_code: {}", code);
-        true
+        Action::Continue
+    }
+
+    fn on_upgrade(&mut self) -> Action {
+        self.upgrade = true;
+        println!("on_upgrade");
+        Action::Continue
     }
 
-    fn on_url(&mut self, url: &[u8]) -> bool {
+    fn on_url(&mut self, url: &[u8]) -> Action {
         self.url.extend_from_slice(url);
         println!("on_url [{}]: {:?}", url.len(), str::from_utf8(url).unwrap());
-        true
+        Action::Continue
     }
 
-    fn on_url_encoded_begin(&mut self) -> bool {
+    fn on_url_encoded_begin(&mut self) -> Action {
         println!("on_url_encoded_begin");
-        true
+        Action::Continue
     }
 
-    fn on_url_encoded_name(&mut self, name: &[u8]) -> bool {
+    fn on_url_encoded_name(&mut self, name: &[u8]) -> Action {
         self.url_encoded_name.extend_from_slice(name);
         println!("on_url_encoded_name [{}]: {:?}", name.len(), str::from_utf8(name).unwrap());
-        true
+        Action::Continue
     }
 
-    fn on_url_encoded_value(&mut self, value: &[u8]) -> bool {
+    fn on_url_encoded_value(&mut self, value: &[u8]) -> Action {
         self.url_encoded_value.extend_from_slice(value);
         println!("on_url_encoded_value [{}]: {:?}", value.len(), str::from_utf8(value).unwrap());
-        true
+        Action::Continue
     }
 
-    fn on_version(&mut self, major: u16, minor: u16) -> bool {
+    fn on_version(&mut self, major: u16, minor: u16) -> Action {
         self.version_major = major;
         self.version_minor = minor;
         println!("on_version: {}.{}", major, minor);
-        true
+        Action::Continue
     }
 }
 
+mod abort;
 mod chunked;
+mod chunked_encoder;
+mod collector;
+mod eof;
+mod error;
 mod head;
+mod length;
+mod limits;
+mod message;
+mod mode;
+mod offset;
 mod multipart;
+mod multipart_encoder;
+mod persistent;
+mod raw;
 mod request;
 mod response;
+mod upgrade;
 mod url_encoded;
+mod writer;
@@ -0,0 +1,275 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Success;
+use http1::*;
+use http1::test::*;
+
+macro_rules! setup {
+    () => ({
+        let (mut p, mut h) = http1_setup!();
+
+        p.set_mode(ParserMode::Lenient);
+
+        (p, h)
+    });
+}
+
+#[test]
+fn chunked_lf() {
+    let (mut p, mut h) = setup!();
+
+    p.init_chunked();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"3\n\
+          abc\n\
+          0\n\
+          \n",
+        b"3\n\
+          abc\n\
+          0\n\
+          \n".len()
+    );
+
+    assert_eq!(h.chunk_data, b"abc");
+}
+
+#[test]
+fn chunked_extension_lf() {
+    let (mut p, mut h) = setup!();
+
+    p.init_chunked();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"3;a=b\n\
+          abc\n\
+          1;c\n\
+          d\n\
+          0\n\
+          \n",
+        b"3;a=b\n\
+          abc\n\
+          1;c\n\
+          d\n\
+          0\n\
+          \n".len()
+    );
+
+    assert_eq!(h.chunk_data, b"abcd");
+    assert_eq!(h.chunk_extension_name, b"ac");
+    assert_eq!(h.chunk_extension_value, b"b");
+}
+
+#[test]
+fn chunked_lf_normal_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_chunked();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"3\nabc",
+        ErrorKind::ChunkLength(b'\n')
+    );
+}
+
+#[test]
+fn request_lf() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\n\
+          Header1: Value1\n\
+          Header2: Value2\n\
+          \n",
+        b"GET / HTTP/1.1\n\
+          Header1: Value1\n\
+          Header2: Value2\n\
+          \n".len()
+    );
+
+    assert_eq!(h.version_major, 1);
+    assert_eq!(h.version_minor, 1);
+    assert_eq!(h.header_name, b"header1header2");
+    assert_eq!(h.header_value, b"Value1Value2");
+    assert!(h.headers_finished);
+}
+
+#[test]
+fn request_lf_empty_value() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\n\
+          Host:\n\
+          X: b\n\
+          \n",
+        b"GET / HTTP/1.1\n\
+          Host:\n\
+          X: b\n\
+          \n".len()
+    );
+
+    assert_eq!(h.header_name, b"hostx");
+    assert_eq!(h.header_value, b"b");
+    assert!(h.headers_finished);
+}
+
+#[test]
+fn request_empty_value() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Host: \r\n\
+          X: b\r\n\
+          \r\n",
+        b"GET / HTTP/1.1\r\n\
+          Host: \r\n\
+          X: b\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(h.header_name, b"hostx");
+    assert_eq!(h.header_value, b"b");
+}
+
+#[test]
+fn request_lf_iter() {
+    let (mut p, mut h) = setup!();
+
+    let stream = b"GET / HTTP/1.1\n\
+                   Header: Value\n\
+                   \n";
+
+    for byte in stream[..stream.len() - 1].iter() {
+        match p.resume(&mut h, &[*byte]) {
+            Ok(Success::Eos(1)) => {},
+            _ => panic!("request_lf_iter() Success::Eos match failed")
+        }
+    }
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"\n",
+        1
+    );
+
+    assert_eq!(h.header_name, b"header");
+    assert_eq!(h.header_value, b"Value");
+}
+
+#[test]
+fn response_lf() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 OK\n\
+          Header: Value\n\
+          \n",
+        b"HTTP/1.1 200 OK\n\
+          Header: Value\n\
+          \n".len()
+    );
+
+    assert_eq!(h.status_code, 200);
+    assert_eq!(h.status, b"OK");
+    assert_eq!(h.header_value, b"Value");
+}
+
+#[test]
+fn response_empty_reason() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 \r\n\r\n",
+        b"HTTP/1.1 200 \r\n\r\n".len()
+    );
+
+    assert_eq!(h.status_code, 200);
+    assert_eq!(h.status, b"");
+}
+
+#[test]
+fn response_missing_reason() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200\r\n\r\n",
+        b"HTTP/1.1 200\r\n\r\n".len()
+    );
+
+    assert_eq!(h.status_code, 200);
+    assert!(h.initial_finished);
+}
+
+#[test]
+fn response_missing_reason_lf() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 204\n\n",
+        b"HTTP/1.1 204\n\n".len()
+    );
+
+    assert_eq!(h.status_code, 204);
+}
+
+#[test]
+fn response_missing_reason_normal_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200\r\n\r\n",
+        ErrorKind::StatusCode(b'\r')
+    );
+}
+
+#[test]
+fn request_lf_normal_error() {
+    let (mut p, mut h) = http1_setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\n\n",
+        ErrorKind::Version(b'\n')
+    );
+}
+
@@ -0,0 +1,193 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+macro_rules! setup {
+    () => ({
+        let (mut p, mut h) = http1_setup!();
+
+        p.init_head();
+        p.set_mode(ParserMode::Strict);
+
+        (p, h)
+    });
+}
+
+#[test]
+fn empty_lines() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"\r\nGET / HTTP/1.1\r\n\r\n",
+        b"\r\nGET / HTTP/1.1\r\n\r\n".len()
+    );
+}
+
+#[test]
+fn leading_whitespace_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b" GET / HTTP/1.1\r\n\r\n",
+        ErrorKind::Method(b' ')
+    );
+}
+
+#[test]
+fn leading_whitespace_normal() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b" \t\r\nGET / HTTP/1.1\r\n\r\n",
+        b" \t\r\nGET / HTTP/1.1\r\n\r\n".len()
+    );
+}
+
+#[test]
+fn line_feed_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Header: Value\n\
+          \r\n",
+        ErrorKind::HeaderValue(b'\n')
+    );
+}
+
+#[test]
+fn obsolete_line_folding_error() {
+    let (mut p, mut h) = setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Header: Value1\r\n \
+          Value2\r\n\
+          \r\n",
+        ErrorKind::ObsoleteLineFolding
+    );
+}
+
+#[test]
+fn obsolete_line_folding_normal() {
+    let (mut p, mut h) = http1_setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Header: Value1\r\n \
+          Value2\r\n\
+          \r\n",
+        b"GET / HTTP/1.1\r\n\
+          Header: Value1\r\n \
+          Value2\r\n\
+          \r\n".len()
+    );
+}
+
+#[test]
+fn obsolete_text_error() {
+    for b in 0x80..0xFF {
+        let (mut p, mut h) = setup!();
+
+        assert_eos(
+            &mut p,
+            &mut h,
+            b"GET / HTTP/1.1\r\nHeader: Value",
+            ParserState::HeaderValue,
+            b"GET / HTTP/1.1\r\nHeader: Value".len()
+        );
+
+        assert_error(
+            &mut p,
+            &mut h,
+            &[b],
+            ErrorKind::HeaderValue(b)
+        );
+    }
+}
+
+#[test]
+fn obsolete_text_quoted_error() {
+    for b in 0x80..0xFF {
+        let (mut p, mut h) = setup!();
+
+        assert_eos(
+            &mut p,
+            &mut h,
+            b"GET / HTTP/1.1\r\nHeader: \"Value",
+            ParserState::HeaderQuotedValue,
+            b"GET / HTTP/1.1\r\nHeader: \"Value".len()
+        );
+
+        assert_error(
+            &mut p,
+            &mut h,
+            &[b],
+            ErrorKind::HeaderValue(b)
+        );
+    }
+}
+
+#[test]
+fn obsolete_text_leading_error() {
+    for b in 0x80..0xFF {
+        let (mut p, mut h) = setup!();
+
+        assert_eos(
+            &mut p,
+            &mut h,
+            b"GET / HTTP/1.1\r\nHeader: ",
+            ParserState::StripHeaderValue,
+            b"GET / HTTP/1.1\r\nHeader: ".len()
+        );
+
+        assert_error(
+            &mut p,
+            &mut h,
+            &[b],
+            ErrorKind::HeaderValue(b)
+        );
+    }
+}
+
+#[test]
+fn obsolete_text_normal() {
+    let (mut p, mut h) = http1_setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\nHeader: Value\x80",
+        ParserState::HeaderValue,
+        b"GET / HTTP/1.1\r\nHeader: Value\x80".len()
+    );
+}
@@ -14,6 +14,7 @@
 // | limitations under the License.                                                                |
 // +-----------------------------------------------------------------------------------------------+
 
+use fsm::Action;
 use http1::*;
 use http1::test::*;
 
@@ -21,8 +22,8 @@ use http1::test::*;
 fn on_multipart_begin() {
     struct H;
     impl HttpHandler for H {
-        fn on_multipart_begin(&mut self) -> bool {
-            false
+        fn on_multipart_begin(&mut self) -> Action {
+            Action::Pause
         }
     }
 
@@ -45,8 +46,8 @@ fn on_multipart_begin() {
 fn on_multipart_data() {
     struct H;
     impl HttpHandler for H {
-        fn on_multipart_data(&mut self, _: &[u8]) -> bool {
-            false
+        fn on_multipart_data(&mut self, _: &[u8]) -> Action {
+            Action::Pause
         }
     }
 
@@ -0,0 +1,207 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+
+use std::io::{ self, Read };
+
+struct Broken;
+
+impl Read for Broken {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::ConnectionReset, "reset"))
+    }
+}
+
+fn delimiter(e: &MultipartEncoder<Vec<u8>>) -> Vec<u8> {
+    let mut delimiter = b"\r\n--".to_vec();
+
+    delimiter.extend_from_slice(e.boundary());
+    delimiter
+}
+
+#[test]
+fn boundary_in_data() {
+    let mut e = MultipartEncoder::new(Vec::new());
+    let mut d = b"data".to_vec();
+
+    d.extend_from_slice(&delimiter(&e));
+
+    e.part(b"a", None, None).unwrap();
+
+    let length = e.get_ref().len();
+
+    match e.data(&d) {
+        Err(WriteError::BoundaryInData) => {},
+        _ => panic!()
+    }
+
+    assert_eq!(e.get_ref().len(), length);
+}
+
+#[test]
+fn boundary_in_data_leading() {
+    let mut e = MultipartEncoder::new(Vec::new());
+    let d     = delimiter(&e);
+
+    e.part(b"a", None, None).unwrap();
+
+    // data that begins with the boundary follows the CRLF that ends the part headers
+    match e.data(&d[2..]) {
+        Err(WriteError::BoundaryInData) => {},
+        _ => panic!()
+    }
+
+    // a partial boundary is fine
+    let mut e = MultipartEncoder::new(Vec::new());
+    let d     = delimiter(&e);
+
+    e.part(b"a", None, None).unwrap();
+    e.data(&d[2..d.len() - 1]).unwrap();
+    e.data(b"\r\n").unwrap();
+    e.data(&d[..d.len() - 1]).unwrap();
+}
+
+#[test]
+fn boundary_in_data_retry() {
+    let mut e = MultipartEncoder::new(Vec::new());
+    let d     = delimiter(&e);
+
+    e.part(b"a", None, None).unwrap();
+
+    match e.data(&d) {
+        Err(WriteError::BoundaryInData) => {},
+        _ => panic!()
+    }
+
+    // the rejected data is not compared against the next data
+    e.data(&d[d.len() - 1..]).unwrap();
+}
+
+#[test]
+fn boundary_in_data_split() {
+    // every split of the delimiter across two writes is detected
+    for n in 1..49 {
+        let mut e = MultipartEncoder::new(Vec::new());
+        let d     = delimiter(&e);
+
+        e.part(b"a", None, None).unwrap();
+        e.data(b"data").unwrap();
+        e.data(&d[..n]).unwrap();
+
+        match e.data(&d[n..]) {
+            Err(WriteError::BoundaryInData) => {},
+            _ => panic!()
+        }
+    }
+
+    // and across multiple writes
+    let mut e = MultipartEncoder::new(Vec::new());
+    let d     = delimiter(&e);
+
+    e.part(b"a", None, None).unwrap();
+
+    for byte in &d[..d.len() - 1] {
+        e.data(&[*byte]).unwrap();
+    }
+
+    match e.data(&d[d.len() - 1..]) {
+        Err(WriteError::BoundaryInData) => {},
+        _ => panic!()
+    }
+}
+
+#[test]
+fn boundary_in_reader() {
+    let mut e = MultipartEncoder::new(Vec::new());
+    let mut d = vec![b'x'; 10000];
+
+    d.extend_from_slice(&delimiter(&e));
+
+    match e.file(b"a", b"a.txt", b"text/plain", &mut &d[..]) {
+        Err(WriteError::BoundaryInData) => {},
+        _ => panic!()
+    }
+}
+
+#[test]
+fn content_type() {
+    let mut e = MultipartEncoder::new(Vec::new());
+
+    match e.part(b"a", None, Some(b"text/plain\r\nX-Injected: 1")) {
+        Err(WriteError::HeaderValue(b'\r')) => {},
+        _ => panic!()
+    }
+
+    assert!(e.get_ref().is_empty());
+}
+
+#[test]
+fn io() {
+    let mut e = MultipartEncoder::new(Vec::new());
+
+    match e.file(b"a", b"a.txt", b"text/plain", &mut Broken) {
+        Err(WriteError::Io(ref error)) => assert_eq!(error.kind(), io::ErrorKind::ConnectionReset),
+        _ => panic!()
+    }
+}
+
+#[test]
+fn name() {
+    for b in (0..0x20).filter(|&x| x != b'\t' && x != b'\r' && x != b'\n').chain(Some(0x7F)) {
+        let mut e = MultipartEncoder::new(Vec::new());
+
+        match e.part(&[b'a', b], None, None) {
+            Err(WriteError::HeaderValue(x)) => assert_eq!(x, b),
+            _ => panic!()
+        }
+
+        match e.part(b"a", Some(&[b'a', b]), None) {
+            Err(WriteError::HeaderValue(x)) => assert_eq!(x, b),
+            _ => panic!()
+        }
+
+        assert!(e.get_ref().is_empty());
+    }
+}
+
+#[test]
+fn out_of_order() {
+    let mut e = MultipartEncoder::new(Vec::new());
+
+    match e.data(b"data") {
+        Err(WriteError::OutOfOrder) => {},
+        _ => panic!()
+    }
+
+    e.field(b"a", b"1").unwrap();
+    e.finish().unwrap();
+
+    match e.data(b"data") {
+        Err(WriteError::OutOfOrder) => {},
+        _ => panic!()
+    }
+
+    match e.field(b"b", b"2") {
+        Err(WriteError::OutOfOrder) => {},
+        _ => panic!()
+    }
+
+    match e.finish() {
+        Err(WriteError::OutOfOrder) => {},
+        _ => panic!()
+    }
+}
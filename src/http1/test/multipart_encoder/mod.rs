@@ -0,0 +1,223 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+mod error;
+
+use http1::*;
+use http1::test::*;
+use util::FieldIterator;
+
+#[test]
+fn boundary() {
+    let e1 = MultipartEncoder::new(Vec::new());
+    let e2 = MultipartEncoder::new(Vec::new());
+
+    assert!(e1.boundary().starts_with(b"HttpBoxBoundary"));
+    assert_eq!(e1.boundary().len(), 47);
+    assert!(e1.boundary().iter().all(|byte| byte.is_ascii_alphanumeric()));
+    assert_ne!(e1.boundary(), e2.boundary());
+
+    let mut content_type = b"multipart/form-data; boundary=".to_vec();
+
+    content_type.extend_from_slice(e1.boundary());
+
+    assert_eq!(e1.content_type(), content_type);
+}
+
+#[test]
+fn empty() {
+    let mut e = MultipartEncoder::new(Vec::new());
+
+    e.finish().unwrap();
+
+    assert!(e.is_finished());
+
+    let mut expected = b"--".to_vec();
+
+    expected.extend_from_slice(e.boundary());
+    expected.extend_from_slice(b"--");
+
+    assert_eq!(e.get_ref(), &expected);
+
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_multipart();
+    p.set_boundary(e.boundary());
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        e.get_ref(),
+        e.get_ref().len()
+    );
+}
+
+#[test]
+fn parts() {
+    let mut e = MultipartEncoder::new(Vec::new());
+
+    e.field(b"name", b"Bob").unwrap();
+    e.part(b"notes", None, Some(b"text/plain; charset=utf-8")).unwrap();
+    e.data(b"line 1\r\n").unwrap();
+    e.data(b"line 2").unwrap();
+
+    assert_eq!(
+        e.file(b"upload", b"hello.txt", b"application/octet-stream", &mut &b"\x00\xFF\r\n--"[..])
+         .unwrap(),
+        6
+    );
+
+    e.finish().unwrap();
+
+    let boundary     = String::from_utf8(e.boundary().to_vec()).unwrap();
+    let mut expected = format!(
+        "--{0}\r\n\
+         Content-Disposition: form-data; name=\"name\"\r\n\
+         \r\n\
+         Bob\r\n\
+         --{0}\r\n\
+         Content-Disposition: form-data; name=\"notes\"\r\n\
+         Content-Type: text/plain; charset=utf-8\r\n\
+         \r\n\
+         line 1\r\nline 2\r\n\
+         --{0}\r\n\
+         Content-Disposition: form-data; name=\"upload\"; filename=\"hello.txt\"\r\n\
+         Content-Type: application/octet-stream\r\n\
+         \r\n",
+        boundary
+    ).into_bytes();
+
+    expected.extend_from_slice(b"\x00\xFF\r\n--");
+    expected.extend_from_slice(format!("\r\n--{}--", boundary).as_bytes());
+
+    assert_eq!(e.get_ref(), &expected);
+
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_multipart();
+    p.set_boundary(e.boundary());
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        e.get_ref(),
+        e.get_ref().len()
+    );
+
+    assert_eq!(
+        &h.multipart_data,
+        b"Bobline 1\r\nline 2\x00\xFF\r\n--"
+    );
+
+    assert_eq!(
+        &h.header_name,
+        b"content-dispositioncontent-dispositioncontent-typecontent-dispositioncontent-type"
+    );
+}
+
+#[test]
+fn quoted_names() {
+    let mut e = MultipartEncoder::new(Vec::new());
+
+    e.part(b"a \"quoted\"\r\nname", Some(b"C:\\files\\r\xC3\xA9sum\xC3\xA9.txt"), None).unwrap();
+    e.data(b"data").unwrap();
+    e.finish().unwrap();
+
+    let boundary     = String::from_utf8(e.boundary().to_vec()).unwrap();
+    let mut expected = format!(
+        "--{0}\r\n\
+         Content-Disposition: form-data; name=\"a %22quoted%22%0D%0Aname\"; \
+         filename=\"C:\\\\files\\\\r",
+        boundary
+    ).into_bytes();
+
+    expected.extend_from_slice(b"\xC3\xA9sum\xC3\xA9.txt\"\r\n\r\ndata");
+    expected.extend_from_slice(format!("\r\n--{}--", boundary).as_bytes());
+
+    assert_eq!(e.get_ref(), &expected);
+
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_multipart();
+    p.set_boundary(e.boundary());
+
+    match p.resume(&mut c, e.get_ref()) {
+        Ok(Success::Finished(length)) => assert_eq!(length, e.get_ref().len()),
+        _ => panic!()
+    }
+
+    c.finish();
+
+    let body = c.take_body().unwrap();
+    let part = &body.parts()[0];
+
+    assert_eq!(
+        part.headers().get(b"content-disposition"),
+        Some(&b"form-data; name=\"a %22quoted%22%0D%0Aname\"; \
+                filename=\"C:\\\\files\\\\r\xC3\xA9sum\xC3\xA9.txt\""[..])
+    );
+
+    assert_eq!(part.data(), b"data");
+
+    let fields: Vec<(String, Option<String>)> = FieldIterator::new(
+        part.headers().get(b"content-disposition").unwrap(),
+        b';',
+        true
+    ).collect();
+
+    assert_eq!(
+        fields,
+        vec![
+            ("form-data".to_string(), None),
+            ("name".to_string(), Some("a %22quoted%22%0D%0Aname".to_string())),
+            ("filename".to_string(), Some("C:\\files\\r\u{E9}sum\u{E9}.txt".to_string()))
+        ]
+    );
+}
+
+#[test]
+fn request() {
+    let mut body = MultipartEncoder::new(Vec::new());
+
+    body.field(b"a", b"1").unwrap();
+    body.file(b"b", b"b.txt", b"text/plain", &mut &b"Hello"[..]).unwrap();
+    body.finish().unwrap();
+
+    let mut w = Writer::new(Vec::new());
+
+    w.request_line(b"POST", b"/upload", (1, 1)).unwrap();
+    w.header(b"Content-Type", &body.content_type()).unwrap();
+    w.header(b"Content-Length", body.get_ref().len().to_string().as_bytes()).unwrap();
+    w.body(body.get_ref()).unwrap();
+
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    match p.resume(&mut c, w.get_ref()) {
+        Ok(Success::Finished(length)) => assert_eq!(length, w.get_ref().len()),
+        _ => panic!()
+    }
+
+    let request = c.take_request().unwrap();
+
+    assert_eq!(request.parts().len(), 2);
+    assert_eq!(request.parts()[0].data(), b"1");
+    assert_eq!(request.parts()[1].data(), b"Hello");
+    assert_eq!(request.parts()[1].headers().get(b"content-type"), Some(&b"text/plain"[..]));
+}
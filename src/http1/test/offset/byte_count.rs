@@ -0,0 +1,112 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::{ Action, Success };
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn callback_exit() {
+    struct H;
+    impl HttpHandler for H {
+        fn on_header_name(&mut self, _: &[u8]) -> Action {
+            Action::Pause
+        }
+    }
+
+    let mut h = H;
+    let mut p = Parser::new();
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        ParserState::LowerHeaderName,
+        b"GET / HTTP/1.1\r\nH".len()
+    );
+
+    assert_eq!(
+        p.byte_count(),
+        b"GET / HTTP/1.1\r\nH".len()
+    );
+}
+
+#[test]
+fn error() {
+    let (mut p, mut h) = http1_setup!();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\x01",
+        ErrorKind::HeaderName(1)
+    );
+
+    assert_eq!(
+        p.byte_count(),
+        b"GET / HTTP/1.1\r\n\x01".len()
+    );
+}
+
+#[test]
+fn url_encoded() {
+    let (mut p, mut h) = http1_setup!();
+
+    let stream = b"POST / HTTP/1.1\r\n\
+                   Content-Type: application/x-www-form-urlencoded\r\n\
+                   Content-Length: 3\r\n\
+                   \r\n\
+                   a=b";
+
+    p.init_message();
+
+    match p.resume(&mut h, stream) {
+        Ok(Success::Finished(length)) => assert_eq!(length, stream.len()),
+        _ => panic!("url_encoded() Ok() match failed")
+    }
+
+    assert_eq!(
+        p.byte_count(),
+        stream.len()
+    );
+}
+
+#[test]
+fn split() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\nContent-Len",
+        ParserState::LowerHeaderName,
+        b"POST / HTTP/1.1\r\nContent-Len".len()
+    );
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"gth: 2\r\n\r\nOK",
+        b"gth: 2\r\n\r\nOK".len()
+    );
+
+    assert_eq!(
+        p.byte_count(),
+        b"POST / HTTP/1.1\r\nContent-Length: 2\r\n\r\nOK".len()
+    );
+}
@@ -0,0 +1,238 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::{ Action, Success };
+use http1::*;
+
+/// Collects the offset and data of each callback.
+struct X {
+    offset:  usize,
+    offsets: usize,
+    spans:   Vec<(&'static str, usize, Vec<u8>)>
+}
+
+impl X {
+    fn new() -> X {
+        X{
+            offset:  0,
+            offsets: 0,
+            spans:   Vec::new()
+        }
+    }
+
+    fn span(&mut self, name: &'static str, data: &[u8]) -> Action {
+        self.spans.push((name, self.offset, data.to_vec()));
+        Action::Continue
+    }
+}
+
+impl HttpHandler for X {
+    fn on_body(&mut self, data: &[u8]) -> Action {
+        self.span("body", data)
+    }
+
+    fn on_chunk_data(&mut self, data: &[u8]) -> Action {
+        self.span("chunk_data", data)
+    }
+
+    fn on_chunk_extension_name(&mut self, name: &[u8]) -> Action {
+        self.span("chunk_extension_name", name)
+    }
+
+    fn on_header_name(&mut self, name: &[u8]) -> Action {
+        self.span("header_name", name)
+    }
+
+    fn on_header_value(&mut self, value: &[u8]) -> Action {
+        self.span("header_value", value)
+    }
+
+    fn on_method(&mut self, method: &[u8]) -> Action {
+        self.span("method", method)
+    }
+
+    fn on_url(&mut self, url: &[u8]) -> Action {
+        self.span("url", url)
+    }
+
+    fn on_url_encoded_value(&mut self, value: &[u8]) -> Action {
+        self.span("url_encoded_value", value)
+    }
+
+    fn set_offset(&mut self, offset: usize) {
+        self.offset   = offset;
+        self.offsets += 1;
+    }
+}
+
+/// Parse `stream` in two pieces split at `index`, and assert that each span references its data
+/// within `stream`, ignoring the case of header names, and ignoring decoded data.
+fn assert_spans(stream: &[u8], index: usize) -> X {
+    let mut h = X::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    for piece in &[&stream[..index], &stream[index..]] {
+        match p.resume(&mut h, piece) {
+            Ok(Success::Eos(_))
+            | Ok(Success::Finished(_)) => {},
+            _ => panic!("assert_spans() Ok() match failed")
+        }
+    }
+
+    assert_eq!(
+        p.state(),
+        ParserState::Finished
+    );
+
+    for &(name, offset, ref data) in h.spans.iter().filter(|x| x.0 != "url_encoded_value") {
+        assert_eq!(
+            stream[offset..offset + data.len()].to_ascii_lowercase(),
+            data.to_ascii_lowercase(),
+            "{} at {}",
+            name,
+            offset
+        );
+    }
+
+    h
+}
+
+#[test]
+fn chunked() {
+    let stream = b"POST /chunked HTTP/1.1\r\n\
+                   Transfer-Encoding: chunked\r\n\
+                   \r\n\
+                   5;ext\r\n\
+                   Hello\r\n\
+                   6\r\n\
+                   World!\r\n\
+                   0\r\n\
+                   \r\n";
+
+    for index in 0..stream.len() {
+        assert_spans(stream, index);
+    }
+
+    let h = assert_spans(stream, stream.len());
+
+    assert!(h.spans.contains(&("chunk_data", 71, b"World!".to_vec())));
+}
+
+#[test]
+fn head() {
+    let stream = b"GET /path?query HTTP/1.1\r\n\
+                   Host: localhost\r\n\
+                   X-Custom-Header: value\r\n\
+                   \r\n";
+
+    for index in 0..stream.len() {
+        assert_spans(stream, index);
+    }
+
+    let h = assert_spans(stream, stream.len());
+
+    assert_eq!(
+        h.spans,
+        vec![("method", 0, b"GET".to_vec()),
+             ("url", 4, b"/path?query".to_vec()),
+             ("header_name", 26, b"host".to_vec()),
+             ("header_value", 32, b"localhost".to_vec()),
+             ("header_name", 43, b"x".to_vec()),
+             ("header_name", 44, b"-".to_vec()),
+             ("header_name", 45, b"c".to_vec()),
+             ("header_name", 46, b"ustom-".to_vec()),
+             ("header_name", 52, b"h".to_vec()),
+             ("header_name", 53, b"eader".to_vec()),
+             ("header_value", 60, b"value".to_vec())]
+    );
+
+    // the offset is only set prior to callbacks that supply data
+    assert_eq!(h.offsets, h.spans.len());
+}
+
+#[test]
+fn length() {
+    let stream = b"POST / HTTP/1.1\r\n\
+                   Content-Length: 5\r\n\
+                   \r\n\
+                   Hello";
+
+    for index in 0..stream.len() {
+        assert_spans(stream, index);
+    }
+}
+
+#[test]
+fn url_encoded() {
+    let stream = b"POST / HTTP/1.1\r\n\
+                   Content-Type: application/x-www-form-urlencoded\r\n\
+                   Content-Length: 15\r\n\
+                   \r\n\
+                   a=b+c&d=%41%42e";
+
+    for index in 0..stream.len() {
+        assert_spans(stream, index);
+    }
+
+    let h = assert_spans(stream, stream.len());
+
+    // decoded bytes are given the offset of the bytes they were produced from
+    assert_eq!(
+        h.spans.iter()
+               .filter(|x| x.0 == "url_encoded_value")
+               .map(|x| (x.1, x.2.clone()))
+               .collect::<Vec<(usize, Vec<u8>)>>(),
+        vec![(90, b"b".to_vec()),
+             (91, b" ".to_vec()),
+             (92, b"c".to_vec()),
+             (96, b"A".to_vec()),
+             (99, b"B".to_vec()),
+             (102, b"e".to_vec())]
+    );
+}
+
+#[test]
+fn split() {
+    let stream = b"POST /path HTTP/1.1\r\n\
+                   Content-Length: 5\r\n\
+                   \r\n\
+                   Hello";
+
+    // each segment of data that is split across calls to resume() is given its absolute offset
+    let h = assert_spans(stream, 8);
+
+    assert_eq!(
+        h.spans.iter()
+               .filter(|x| x.0 == "url")
+               .cloned()
+               .collect::<Vec<(&str, usize, Vec<u8>)>>(),
+        vec![("url", 5, b"/pa".to_vec()),
+             ("url", 8, b"th".to_vec())]
+    );
+
+    let h = assert_spans(stream, 44);
+
+    assert_eq!(
+        h.spans.iter()
+               .filter(|x| x.0 == "body")
+               .cloned()
+               .collect::<Vec<(&str, usize, Vec<u8>)>>(),
+        vec![("body", 42, b"He".to_vec()),
+             ("body", 44, b"llo".to_vec())]
+    );
+}
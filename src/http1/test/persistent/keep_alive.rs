@@ -0,0 +1,170 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+macro_rules! setup {
+    () => ({
+        let (mut p, mut h) = http1_setup!();
+
+        p.init_persistent();
+
+        (p, h)
+    });
+}
+
+#[test]
+fn http10() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET /a HTTP/1.0\r\n\
+          \r\n\
+          GET /b HTTP/1.0\r\n\
+          \r\n",
+        b"GET /a HTTP/1.0\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        h.message_finished,
+        vec![false]
+    );
+}
+
+#[test]
+fn http10_keep_alive() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET /a HTTP/1.0\r\n\
+          Connection: Keep-Alive\r\n\
+          \r\n\
+          GET /b HTTP/1.0\r\n\
+          \r\n\
+          GET /c HTTP/1.0\r\n\
+          \r\n",
+        b"GET /a HTTP/1.0\r\n\
+          Connection: Keep-Alive\r\n\
+          \r\n\
+          GET /b HTTP/1.0\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.url,
+        b"/a/b"
+    );
+
+    assert_eq!(
+        h.message_finished,
+        vec![true, false]
+    );
+}
+
+#[test]
+fn http11_close() {
+    let (mut p, mut h) = setup!();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"POST /a HTTP/1.1\r\n\
+          Connection: close\r\n\
+          Content-Length: 5\r\n\
+          \r\n\
+          Hello\
+          GET /b HTTP/1.1\r\n\
+          \r\n",
+        b"POST /a HTTP/1.1\r\n\
+          Connection: close\r\n\
+          Content-Length: 5\r\n\
+          \r\n\
+          Hello".len()
+    );
+
+    assert_eq!(
+        &h.url,
+        b"/a"
+    );
+
+    assert_eq!(
+        h.message_finished,
+        vec![false]
+    );
+}
+
+#[test]
+fn message() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET /a HTTP/1.1\r\n\
+          \r\n\
+          GET /b HTTP/1.1\r\n\
+          \r\n",
+        b"GET /a HTTP/1.1\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(h.message_begin, 1);
+
+    assert_eq!(
+        h.message_finished,
+        vec![true]
+    );
+}
+
+#[test]
+fn response() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Length: 2\r\n\
+          \r\n\
+          OK\
+          HTTP/1.1 404 Not Found\r\n\
+          Content-Length: 0\r\n\
+          \r\n",
+        ParserState::StripDetect,
+        b"HTTP/1.1 200 OK\r\n\
+          Content-Length: 2\r\n\
+          \r\n\
+          OK\
+          HTTP/1.1 404 Not Found\r\n\
+          Content-Length: 0\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(h.status_code, 404);
+
+    assert_eq!(
+        h.message_finished,
+        vec![true, true]
+    );
+}
@@ -0,0 +1,396 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::{ Action, Success };
+use http1::*;
+use http1::test::*;
+
+macro_rules! setup {
+    () => ({
+        let (mut p, mut h) = http1_setup!();
+
+        p.init_persistent();
+
+        (p, h)
+    });
+}
+
+#[test]
+fn bodies() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"POST /a HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n\
+          5\r\n\
+          Hello\r\n\
+          0\r\n\
+          \r\n\
+          POST /b HTTP/1.1\r\n\
+          Content-Length: 5\r\n\
+          \r\n\
+          World\
+          GET /c HTTP/1.1\r\n\
+          \r\n",
+        ParserState::StripDetect,
+        b"POST /a HTTP/1.1\r\n\
+          Transfer-Encoding: chunked\r\n\
+          \r\n\
+          5\r\n\
+          Hello\r\n\
+          0\r\n\
+          \r\n\
+          POST /b HTTP/1.1\r\n\
+          Content-Length: 5\r\n\
+          \r\n\
+          World\
+          GET /c HTTP/1.1\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.chunk_data,
+        b"Hello"
+    );
+
+    assert_eq!(
+        &h.body,
+        b"World"
+    );
+
+    assert_eq!(
+        &h.url,
+        b"/a/b/c"
+    );
+
+    assert_eq!(h.message_begin, 3);
+    assert_eq!(h.message_finished.len(), 3);
+}
+
+#[test]
+fn callback_exit() {
+    struct X(usize);
+
+    impl HttpHandler for X {
+        fn on_message_finished(&mut self, _keep_alive: bool) -> Action {
+            self.0 += 1;
+            Action::Pause
+        }
+    }
+
+    let mut h = X(0);
+    let mut p = Parser::new();
+
+    p.init_persistent();
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"GET /a HTTP/1.1\r\n\
+          \r\n\
+          GET /b HTTP/1.1\r\n\
+          \r\n",
+        ParserState::StripDetect,
+        b"GET /a HTTP/1.1\r\n\
+          \r\n".len()
+    );
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"GET /b HTTP/1.1\r\n\
+          \r\n",
+        ParserState::StripDetect,
+        b"GET /b HTTP/1.1\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(h.0, 2);
+}
+
+#[test]
+fn multipart() {
+    let stream = b"POST /a HTTP/1.1\r\n\
+                   Content-Type: multipart/form-data; boundary=XBoundaryX\r\n\
+                   Content-Length: 38\r\n\
+                   \r\n\
+                   --XBoundaryX\r\n\
+                   \r\n\
+                   Data\r\n\
+                   --XBoundaryX--\r\n\
+                   GET /b HTTP/1.1\r\n\
+                   \r\n";
+
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        stream,
+        ParserState::StripDetect,
+        stream.len()
+    );
+
+    assert_eq!(
+        &h.multipart_data,
+        b"Data"
+    );
+
+    assert_eq!(
+        &h.url,
+        b"/a/b"
+    );
+
+    assert_eq!(h.message_finished.len(), 2);
+
+    // the epilogue that follows the last boundary is limited to the content length, even when it
+    // arrives one byte at a time
+    let (mut p, mut h) = setup!();
+
+    for byte in stream.iter() {
+        assert_eq!(p.resume(&mut h, &[*byte]), Ok(Success::Eos(1)));
+    }
+
+    assert_eq!(
+        &h.url,
+        b"/a/b"
+    );
+
+    assert_eq!(h.message_finished.len(), 2);
+}
+
+#[test]
+fn multipart_length_error() {
+    let (mut p, mut h) = setup!();
+
+    // the body is longer than the content length, and the data that follows the content length
+    // must not be parsed as the next message
+    assert_error(
+        &mut p,
+        &mut h,
+        b"POST /a HTTP/1.1\r\n\
+          Content-Type: multipart/form-data; boundary=XBoundaryX\r\n\
+          Content-Length: 10\r\n\
+          \r\n\
+          --XBoundaryX\r\n\
+          \r\n\
+          Data\r\n\
+          --XBoundaryX--\
+          GET /smuggled HTTP/1.1\r\n\
+          \r\n",
+        ErrorKind::MultipartLength
+    );
+
+    assert_eq!(
+        &h.url,
+        b"/a"
+    );
+
+    assert_eq!(h.message_finished.len(), 0);
+}
+
+#[test]
+fn requests() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"GET /a HTTP/1.1\r\n\
+          \r\n\
+          GET /b HTTP/1.1\r\n\
+          \r\n\
+          GET /c HTTP/1.1\r\n\
+          \r\n",
+        ParserState::StripDetect,
+        b"GET /a HTTP/1.1\r\n\
+          \r\n\
+          GET /b HTTP/1.1\r\n\
+          \r\n\
+          GET /c HTTP/1.1\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.url,
+        b"/a/b/c"
+    );
+
+    assert_eq!(h.message_begin, 3);
+
+    assert_eq!(
+        h.message_finished,
+        vec![true, true, true]
+    );
+}
+
+#[test]
+fn split() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"GET /a HTTP/1.1\r\n\
+          \r\n\
+          GET /b HT",
+        ParserState::RequestHttp3,
+        b"GET /a HTTP/1.1\r\n\
+          \r\n\
+          GET /b HT".len()
+    );
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"TP/1.1\r\n\
+          \r\n",
+        ParserState::StripDetect,
+        b"TP/1.1\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.url,
+        b"/a/b"
+    );
+
+    assert_eq!(h.message_finished.len(), 2);
+}
+
+#[test]
+fn url_encoded() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"POST /a HTTP/1.1\r\n\
+          Content-Type: application/x-www-form-urlencoded\r\n\
+          Content-Length: 7\r\n\
+          \r\n\
+          a=b&c=d\
+          POST /b HTTP/1.1\r\n\
+          Content-Type: application/x-www-form-urlencoded\r\n\
+          Content-Length: 3\r\n\
+          \r\n\
+          e=f\
+          GET /c HTTP/1.1\r\n\
+          \r\n",
+        ParserState::StripDetect,
+        b"POST /a HTTP/1.1\r\n\
+          Content-Type: application/x-www-form-urlencoded\r\n\
+          Content-Length: 7\r\n\
+          \r\n\
+          a=b&c=d\
+          POST /b HTTP/1.1\r\n\
+          Content-Type: application/x-www-form-urlencoded\r\n\
+          Content-Length: 3\r\n\
+          \r\n\
+          e=f\
+          GET /c HTTP/1.1\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.url_encoded_name,
+        b"ace"
+    );
+
+    assert_eq!(
+        &h.url,
+        b"/a/b/c"
+    );
+
+    assert_eq!(h.message_finished.len(), 3);
+}
+
+#[test]
+fn multipart_split() {
+    let stream = b"POST /a HTTP/1.1\r\n\
+                   Content-Type: multipart/form-data; boundary=XBoundaryX\r\n\
+                   Content-Length: 38\r\n\
+                   \r\n\
+                   --XBoundaryX\r\n\
+                   \r\n\
+                   Data\r\n\
+                   --XBoundaryX--\r\n\
+                   GET /b HTTP/1.1\r\n\
+                   \r\n";
+
+    // the body ends within the second stream, which also contains the next message
+    for index in 96..134 {
+        let (mut p, mut h) = setup!();
+
+        assert_eq!(p.resume(&mut h, &stream[..index]), Ok(Success::Eos(index)));
+
+        assert_eos(
+            &mut p,
+            &mut h,
+            &stream[index..],
+            ParserState::StripDetect,
+            stream.len() - index
+        );
+
+        assert_eq!(
+            &h.url,
+            b"/a/b"
+        );
+
+        assert_eq!(h.message_finished.len(), 2);
+    }
+}
+
+#[test]
+fn url_encoded_split() {
+    let stream = b"POST /a HTTP/1.1\r\n\
+                   Content-Type: application/x-www-form-urlencoded\r\n\
+                   Content-Length: 7\r\n\
+                   \r\n\
+                   a=b&c=d\
+                   GET /b HTTP/1.1\r\n\
+                   \r\n";
+
+    // the body ends within the second stream, which also contains the next message
+    for index in 88..95 {
+        let (mut p, mut h) = setup!();
+
+        assert_eq!(p.resume(&mut h, &stream[..index]), Ok(Success::Eos(index)));
+
+        assert_eos(
+            &mut p,
+            &mut h,
+            &stream[index..],
+            ParserState::StripDetect,
+            stream.len() - index
+        );
+
+        assert_eq!(
+            &h.url_encoded_name,
+            b"ac"
+        );
+
+        assert_eq!(
+            &h.url,
+            b"/a/b"
+        );
+
+        assert_eq!(h.message_finished.len(), 2);
+    }
+}
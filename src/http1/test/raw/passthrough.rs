@@ -0,0 +1,191 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::{ Action, Success };
+use http1::*;
+
+const MESSAGE: &'static [u8] = b"  \r\n\
+                                 POST /path?query HTTP/1.1\r\n\
+                                 Host: localhost\r\n\
+                                 x-MiXeD-cAsE:    spaced   value\r\n\
+                                 Folded: first\r\n\
+                                 \tsecond\r\n\
+                                 Transfer-Encoding: chunked\r\n\
+                                 \r\n\
+                                 5;name=\"value\"\r\n\
+                                 Hello\r\n\
+                                 0\r\n\
+                                 Checksum: abc\r\n\
+                                 \r\n";
+
+struct X {
+    events: Vec<String>,
+    exit:   bool,
+    raw:    Vec<u8>
+}
+
+impl X {
+    fn new() -> X {
+        X{
+            events: Vec::new(),
+            exit:   false,
+            raw:    Vec::new()
+        }
+    }
+}
+
+impl HttpHandler for X {
+    fn on_header_name(&mut self, name: &[u8]) -> Action {
+        self.events.push(format!("name {}", String::from_utf8_lossy(name)));
+        Action::Continue
+    }
+
+    fn on_raw(&mut self, data: &[u8]) -> Action {
+        self.events.push(format!("raw {}", String::from_utf8_lossy(data)));
+        self.raw.extend_from_slice(data);
+        (!self.exit).into()
+    }
+}
+
+macro_rules! setup {
+    () => ({
+        let mut p = Parser::new();
+
+        p.init_message();
+        p.set_raw_passthrough(true);
+
+        (p, X::new())
+    });
+}
+
+#[test]
+fn callback_exit() {
+    let (mut p, mut h) = setup!();
+
+    h.exit = true;
+
+    match p.resume(&mut h, b"GET / HTTP/1.1\r\n") {
+        Ok(Success::Callback(length)) => {
+            assert!(length > 0);
+
+            assert_eq!(
+                &h.raw[..],
+                &b"GET / HTTP/1.1\r\n"[..length]
+            );
+        },
+        _ => panic!("callback_exit() Ok() match failed")
+    }
+}
+
+#[test]
+fn disabled() {
+    let mut p = Parser::new();
+    let mut h = X::new();
+
+    p.init_message();
+
+    match p.resume(&mut h, MESSAGE) {
+        Ok(Success::Finished(length)) => assert_eq!(length, MESSAGE.len()),
+        _ => panic!("disabled() Ok() match failed")
+    }
+
+    assert!(h.raw.is_empty());
+}
+
+#[test]
+fn entire() {
+    let (mut p, mut h) = setup!();
+
+    match p.resume(&mut h, MESSAGE) {
+        Ok(Success::Finished(length)) => assert_eq!(length, MESSAGE.len()),
+        _ => panic!("entire() Ok() match failed")
+    }
+
+    assert_eq!(
+        &h.raw[..],
+        MESSAGE
+    );
+}
+
+#[test]
+fn interleaved() {
+    let (mut p, mut h) = setup!();
+
+    match p.resume(&mut h, b"GET / HTTP/1.1\r\nHost: localhost\r\nAccept: */*\r\n\r\n") {
+        Ok(Success::Finished(_)) => {},
+        _ => panic!("interleaved() Ok() match failed")
+    }
+
+    let host   = h.events.iter().position(|x| x.starts_with("name h")).unwrap();
+    let accept = h.events.iter().position(|x| x.starts_with("name a")).unwrap();
+
+    // raw data follows the callbacks for the data it contains
+    assert_eq!(
+        h.events[..host].concat().matches("localhost").count(),
+        0
+    );
+
+    assert_eq!(
+        h.events[host..accept].iter()
+                              .filter(|x| x.starts_with("raw "))
+                              .map(|x| &x[4..])
+                              .collect::<String>(),
+        "Host: localhost\r\n"
+    );
+}
+
+#[test]
+fn split() {
+    for index in 0..MESSAGE.len() {
+        let (mut p, mut h) = setup!();
+
+        match p.resume(&mut h, &MESSAGE[..index]) {
+            Ok(Success::Eos(length)) => assert_eq!(length, index),
+            _ => panic!("split() Ok() match failed")
+        }
+
+        match p.resume(&mut h, &MESSAGE[index..]) {
+            Ok(Success::Finished(length)) => assert_eq!(length, MESSAGE.len() - index),
+            _ => panic!("split() Ok() match failed")
+        }
+
+        assert_eq!(
+            &h.raw[..],
+            MESSAGE
+        );
+    }
+}
+
+#[test]
+fn url_encoded() {
+    let (mut p, mut h) = setup!();
+
+    let stream = b"POST / HTTP/1.1\r\n\
+                   Content-Type: application/x-www-form-urlencoded\r\n\
+                   Content-Length: 7\r\n\
+                   \r\n\
+                   a=b&c=d";
+
+    match p.resume(&mut h, stream) {
+        Ok(Success::Finished(length)) => assert_eq!(length, stream.len()),
+        _ => panic!("url_encoded() Ok() match failed")
+    }
+
+    assert_eq!(
+        &h.raw[..],
+        &stream[..]
+    );
+}
@@ -0,0 +1,117 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::{ Action, Success };
+use http1::*;
+
+/// Forwards the raw message, except for the `x-internal` header.
+struct Proxy {
+    drop:    bool,
+    forward: Vec<u8>,
+    head:    bool,
+    line:    Vec<u8>,
+    name:    Vec<u8>
+}
+
+impl Proxy {
+    fn new() -> Proxy {
+        Proxy{
+            drop:    false,
+            forward: Vec::new(),
+            head:    true,
+            line:    Vec::new(),
+            name:    Vec::new()
+        }
+    }
+}
+
+impl HttpHandler for Proxy {
+    fn on_header_name(&mut self, name: &[u8]) -> Action {
+        self.name.extend_from_slice(name);
+        Action::Continue
+    }
+
+    fn on_header_value(&mut self, _: &[u8]) -> Action {
+        // the header name is complete once its value has been located
+        self.drop = self.name == b"x-internal";
+        Action::Continue
+    }
+
+    fn on_headers_finished(&mut self) -> Action {
+        self.head = false;
+        Action::Continue
+    }
+
+    fn on_raw(&mut self, data: &[u8]) -> Action {
+        if !self.head {
+            self.forward.extend_from_slice(data);
+            return Action::Continue;
+        }
+
+        for byte in data {
+            self.line.push(*byte);
+
+            if *byte == b'\n' {
+                if !self.drop {
+                    self.forward.extend_from_slice(&self.line);
+                }
+
+                self.drop = false;
+                self.line.clear();
+                self.name.clear();
+            }
+        }
+
+        Action::Continue
+    }
+}
+
+#[test]
+fn drop_header() {
+    let stream = b"POST / HTTP/1.1\r\n\
+                   Host: localhost\r\n\
+                   X-Internal: secret\r\n\
+                   Content-Length: 5\r\n\
+                   \r\n\
+                   Hello";
+
+    for index in 0..stream.len() {
+        let mut p = Parser::new();
+        let mut h = Proxy::new();
+
+        p.init_message();
+        p.set_raw_passthrough(true);
+
+        match p.resume(&mut h, &stream[..index]) {
+            Ok(Success::Eos(_)) => {},
+            _ => panic!("drop_header() Ok() match failed")
+        }
+
+        match p.resume(&mut h, &stream[index..]) {
+            Ok(Success::Finished(_)) => {},
+            _ => panic!("drop_header() Ok() match failed")
+        }
+
+        assert_eq!(
+            &h.forward[..],
+            &b"POST / HTTP/1.1\r\n\
+               Host: localhost\r\n\
+               Content-Length: 5\r\n\
+               \r\n\
+               Hello"[..]
+        );
+    }
+}
@@ -14,6 +14,7 @@
 // | limitations under the License.                                                                |
 // +-----------------------------------------------------------------------------------------------+
 
+use fsm::Action;
 use http1::*;
 use http1::test::*;
 
@@ -21,8 +22,8 @@ use http1::test::*;
 fn on_method() {
     struct H;
     impl HttpHandler for H {
-        fn on_method(&mut self, _: &[u8]) -> bool {
-            false
+        fn on_method(&mut self, _: &[u8]) -> Action {
+            Action::Pause
         }
     }
 
@@ -42,8 +43,8 @@ fn on_method() {
 fn on_url() {
     struct H;
     impl HttpHandler for H {
-        fn on_url(&mut self, _: &[u8]) -> bool {
-            false
+        fn on_url(&mut self, _: &[u8]) -> Action {
+            Action::Pause
         }
     }
 
@@ -63,8 +64,8 @@ fn on_url() {
 fn on_version() {
     struct H;
     impl HttpHandler for H {
-        fn on_version(&mut self, _: u16, _: u16) -> bool {
-            false
+        fn on_version(&mut self, _: u16, _: u16) -> Action {
+            Action::Pause
         }
     }
 
@@ -14,6 +14,7 @@
 // | limitations under the License.                                                                |
 // +-----------------------------------------------------------------------------------------------+
 
+use fsm::Action;
 use http1::*;
 use http1::test::*;
 use test::*;
@@ -57,8 +58,8 @@ fn allowed() {
 fn callback_exit() {
     struct H;
     impl HttpHandler for H {
-        fn on_method(&mut self, _: &[u8]) -> bool {
-            false
+        fn on_method(&mut self, _: &[u8]) -> Action {
+            Action::Pause
         }
     }
 
@@ -106,7 +107,7 @@ fn not_allowed_error() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::Method(b)
+            ErrorKind::Method(b)
         );
     }
 }
@@ -0,0 +1,289 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Action;
+use http1::*;
+use http1::test::*;
+
+struct X {
+    extension: bool,
+    method:    Vec<u8>
+}
+
+impl X {
+    fn new() -> X {
+        X{
+            extension: false,
+            method:    Vec::new()
+        }
+    }
+}
+
+impl HttpHandler for X {
+    fn on_request_method(&mut self, method: Method) -> Action {
+        if let Method::Extension(_) = method {
+            self.extension = true;
+        }
+
+        self.method.extend_from_slice(method.as_bytes());
+        Action::Continue
+    }
+}
+
+macro_rules! setup {
+    () => ({
+        (Parser::new(), X::new())
+    });
+}
+
+#[test]
+fn allowed() {
+    let (mut p, mut h) = setup!();
+
+    p.set_allowed_methods(&[Method::Get, Method::Extension(b"BREW")]);
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"BREW / ",
+        ParserState::RequestHttp1,
+        b"BREW / ".len()
+    );
+
+    assert_eq!(
+        &h.method,
+        b"BREW"
+    );
+}
+
+#[test]
+fn allowed_fast_path() {
+    let (mut p, mut h) = setup!();
+
+    p.set_allowed_methods(&[Method::Get, Method::Post]);
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"POST / HTTP/1.1\r\n",
+        ParserState::HeaderCr2,
+        b"POST / HTTP/1.1\r\n".len()
+    );
+
+    assert_eq!(
+        &h.method,
+        b"POST"
+    );
+}
+
+#[test]
+fn allowed_reset() {
+    let (mut p, mut h) = setup!();
+
+    p.set_allowed_methods(&[Method::Get]);
+    p.reset();
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"PUT / HTTP/1.1\r\n",
+        ErrorKind::Method(b'P')
+    );
+}
+
+#[test]
+fn callback_exit() {
+    struct H;
+    impl HttpHandler for H {
+        fn on_request_method(&mut self, _: Method) -> Action {
+            Action::Pause
+        }
+    }
+
+    let mut h = H;
+    let mut p = Parser::new();
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n",
+        ParserState::RequestUrl1,
+        b"GET ".len()
+    );
+}
+
+#[test]
+fn extension() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"BREW / HTTP/1.1\r\n",
+        ParserState::HeaderCr2,
+        b"BREW / HTTP/1.1\r\n".len()
+    );
+
+    assert!(h.extension);
+
+    assert_eq!(
+        &h.method,
+        b"BREW"
+    );
+}
+
+#[test]
+fn extension_long_split() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"VERYLONGEXT",
+        ParserState::RequestMethod,
+        b"VERYLONGEXT".len()
+    );
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"ENSIONMETHOD / ",
+        ParserState::RequestHttp1,
+        b"ENSIONMETHOD / ".len()
+    );
+
+    assert!(h.extension);
+
+    assert_eq!(
+        &h.method,
+        b"VERYLONGEXTENSIONMETHOD"
+    );
+}
+
+#[test]
+fn from_bytes() {
+    for method in &[Method::Connect, Method::Copy, Method::Delete, Method::Get, Method::Head,
+                    Method::Lock, Method::Mkcol, Method::Move, Method::Options, Method::Patch,
+                    Method::Post, Method::Pri, Method::Propfind, Method::Put, Method::Query,
+                    Method::Trace, Method::Unlock] {
+        assert_eq!(
+            Method::from_bytes(method.as_bytes()),
+            *method
+        );
+    }
+
+    assert_eq!(
+        Method::from_bytes(b"get"),
+        Method::Extension(b"get")
+    );
+}
+
+#[test]
+fn known() {
+    for method in &[b"CONNECT" as &[u8], b"COPY", b"DELETE", b"GET", b"HEAD", b"LOCK", b"MKCOL",
+                    b"MOVE", b"OPTIONS", b"PATCH", b"POST", b"PRI", b"PROPFIND", b"PUT",
+                    b"QUERY", b"TRACE", b"UNLOCK"] {
+        let (mut p, mut h) = setup!();
+
+        let mut stream = method.to_vec();
+
+        stream.extend_from_slice(b" / HTTP/1.1\r\n");
+
+        assert_eos(
+            &mut p,
+            &mut h,
+            &stream,
+            ParserState::HeaderCr2,
+            stream.len()
+        );
+
+        assert!(!h.extension);
+
+        assert_eq!(
+            &h.method[..],
+            *method
+        );
+    }
+}
+
+#[test]
+fn not_allowed_error() {
+    let (mut p, mut h) = setup!();
+
+    p.set_allowed_methods(&[Method::Get, Method::Head]);
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"DELETE / HTTP/1.1\r\n",
+        ErrorKind::Method(b'D')
+    );
+
+    assert_eq!(
+        h.method.len(),
+        0
+    );
+}
+
+#[test]
+fn not_allowed_split_error() {
+    let (mut p, mut h) = setup!();
+
+    p.set_allowed_methods(&[Method::Get]);
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"PROP",
+        ParserState::RequestMethod,
+        b"PROP".len()
+    );
+
+    assert_error(
+        &mut p,
+        &mut h,
+        b"FIND / HTTP/1.1\r\n",
+        ErrorKind::Method(b'P')
+    );
+}
+
+#[test]
+fn split() {
+    let (mut p, mut h) = setup!();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"UNL",
+        ParserState::RequestMethod,
+        b"UNL".len()
+    );
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"OCK / ",
+        ParserState::RequestHttp1,
+        b"OCK / ".len()
+    );
+
+    assert!(!h.extension);
+
+    assert_eq!(
+        &h.method,
+        b"UNLOCK"
+    );
+}
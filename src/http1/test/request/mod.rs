@@ -16,5 +16,6 @@
 
 mod callback;
 mod method;
+mod method_type;
 mod url;
 mod version;
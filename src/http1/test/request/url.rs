@@ -83,7 +83,7 @@ fn not_allowed_error() {
             &mut p,
             &mut h,
             &[*b],
-            ParserError::Url(*b)
+            ErrorKind::Url(*b)
         );
     }
 }
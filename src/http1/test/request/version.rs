@@ -263,7 +263,7 @@ fn major_not_allowed_error() {
             &mut p,
             &mut h,
             &[*b],
-            ParserError::Version(*b)
+            ErrorKind::Version(*b)
         );
     }
 }
@@ -276,7 +276,7 @@ fn major_overflow_error() {
         &mut p,
         &mut h,
         b"9990",
-        ParserError::Version(b'0')
+        ErrorKind::Version(b'0')
     );
 }
 
@@ -297,7 +297,7 @@ fn minor_not_allowed_error() {
             &mut p,
             &mut h,
             &[*b],
-            ParserError::Version(*b)
+            ErrorKind::Version(*b)
         );
     }
 }
@@ -310,7 +310,7 @@ fn minor_overflow_error() {
         &mut p,
         &mut h,
         b"1.9990",
-        ParserError::Version(b'0')
+        ErrorKind::Version(b'0')
     );
 }
 
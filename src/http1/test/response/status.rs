@@ -100,7 +100,7 @@ fn not_allowed_error() {
             &mut p,
             &mut h,
             &[*b],
-            ParserError::Status(*b)
+            ErrorKind::Status(*b)
         );
     }
 }
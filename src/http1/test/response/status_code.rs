@@ -14,6 +14,7 @@
 // | limitations under the License.                                                                |
 // +-----------------------------------------------------------------------------------------------+
 
+use fsm::Action;
 use http1::*;
 use http1::test::*;
 use test::digit_vec;
@@ -82,8 +83,8 @@ fn allowed() {
 fn callback_exit() {
     struct H;
     impl HttpHandler for H {
-        fn on_status_code(&mut self, _: u16) -> bool {
-            false
+        fn on_status_code(&mut self, _: u16) -> Action {
+            Action::Pause
         }
     }
 
@@ -130,7 +131,7 @@ fn not_allowed_error1() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::StatusCode(b)
+            ErrorKind::StatusCode(b)
         );
     }
 }
@@ -155,7 +156,7 @@ fn not_allowed_error2() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::StatusCode(b)
+            ErrorKind::StatusCode(b)
         );
     }
 }
@@ -180,7 +181,7 @@ fn not_allowed_error3() {
             &mut p,
             &mut h,
             &[b],
-            ParserError::StatusCode(b)
+            ErrorKind::StatusCode(b)
         );
     }
 }
@@ -0,0 +1,245 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Action;
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn callback_exit() {
+    struct X;
+
+    impl HttpHandler for X {
+        fn on_upgrade(&mut self) -> Action {
+            Action::Pause
+        }
+    }
+
+    let mut h = X{};
+    let mut p = Parser::new();
+
+    p.init_head();
+
+    assert_callback(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Connection: upgrade\r\n\
+          Upgrade: websocket\r\n\
+          \r\n",
+        ParserState::Upgraded,
+        b"GET / HTTP/1.1\r\n\
+          Connection: upgrade\r\n\
+          Upgrade: websocket\r\n\
+          \r\n".len()
+    );
+
+    assert_upgrade(
+        &mut p,
+        &mut h,
+        b"\x81\x05Hello",
+        0
+    );
+}
+
+#[test]
+fn connect() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+
+    assert_upgrade(
+        &mut p,
+        &mut h,
+        b"CONNECT server.example.com:80 HTTP/1.1\r\n\
+          Host: server.example.com:80\r\n\
+          \r\n\
+          \x16\x03\x01",
+        b"CONNECT server.example.com:80 HTTP/1.1\r\n\
+          Host: server.example.com:80\r\n\
+          \r\n".len()
+    );
+
+    assert!(h.upgrade);
+}
+
+#[test]
+fn connect_split_method() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+
+    assert_eos(
+        &mut p,
+        &mut h,
+        b"CONN",
+        ParserState::RequestMethod,
+        4
+    );
+
+    assert_upgrade(
+        &mut p,
+        &mut h,
+        b"ECT server.example.com:80 HTTP/1.1\r\n\
+          \r\n",
+        b"ECT server.example.com:80 HTTP/1.1\r\n\
+          \r\n".len()
+    );
+
+    assert_eq!(
+        &h.method,
+        b"CONNECT"
+    );
+}
+
+#[test]
+fn connection_list() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+
+    assert_upgrade(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Connection: keep-alive, Upgrade\r\n\
+          Upgrade: websocket\r\n\
+          \r\n",
+        b"GET / HTTP/1.1\r\n\
+          Connection: keep-alive, Upgrade\r\n\
+          Upgrade: websocket\r\n\
+          \r\n".len()
+    );
+}
+
+#[test]
+fn h2c() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+
+    assert_upgrade(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Host: server.example.com\r\n\
+          Connection: Upgrade, HTTP2-Settings\r\n\
+          Upgrade: h2c\r\n\
+          HTTP2-Settings: AAMAAABkAARAAAAAAAIAAAAA\r\n\
+          \r\n\
+          PRI * HTTP/2.0\r\n",
+        b"GET / HTTP/1.1\r\n\
+          Host: server.example.com\r\n\
+          Connection: Upgrade, HTTP2-Settings\r\n\
+          Upgrade: h2c\r\n\
+          HTTP2-Settings: AAMAAABkAARAAAAAAAIAAAAA\r\n\
+          \r\n".len()
+    );
+
+    assert!(h.upgrade);
+}
+
+#[test]
+fn message() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_upgrade(
+        &mut p,
+        &mut h,
+        b"GET /chat HTTP/1.1\r\n\
+          Connection: Upgrade\r\n\
+          Upgrade: websocket\r\n\
+          Content-Length: 5\r\n\
+          \r\n\
+          Hello",
+        b"GET /chat HTTP/1.1\r\n\
+          Connection: Upgrade\r\n\
+          Upgrade: websocket\r\n\
+          Content-Length: 5\r\n\
+          \r\n".len()
+    );
+
+    assert!(h.body.is_empty());
+}
+
+#[test]
+fn missing_connection() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Upgrade: websocket\r\n\
+          \r\n",
+        b"GET / HTTP/1.1\r\n\
+          Upgrade: websocket\r\n\
+          \r\n".len()
+    );
+
+    assert!(!h.upgrade);
+}
+
+#[test]
+fn missing_upgrade() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          Connection: upgrade\r\n\
+          \r\n",
+        b"GET / HTTP/1.1\r\n\
+          Connection: upgrade\r\n\
+          \r\n".len()
+    );
+
+    assert!(!h.upgrade);
+}
+
+#[test]
+fn reset() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+
+    assert_upgrade(
+        &mut p,
+        &mut h,
+        b"CONNECT server.example.com:80 HTTP/1.1\r\n\
+          \r\n",
+        b"CONNECT server.example.com:80 HTTP/1.1\r\n\
+          \r\n".len()
+    );
+
+    p.reset();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"GET / HTTP/1.1\r\n\
+          \r\n",
+        b"GET / HTTP/1.1\r\n\
+          \r\n".len()
+    );
+}
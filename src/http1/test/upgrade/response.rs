@@ -0,0 +1,116 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+use http1::test::*;
+
+#[test]
+fn connect_established() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+    p.set_request_method(b"CONNECT");
+
+    assert_upgrade(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 Connection Established\r\n\
+          \r\n\
+          \x16\x03\x01",
+        b"HTTP/1.1 200 Connection Established\r\n\
+          \r\n".len()
+    );
+
+    assert!(h.upgrade);
+}
+
+#[test]
+fn connect_refused() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+    p.set_request_method(b"CONNECT");
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 407 Proxy Authentication Required\r\n\
+          \r\n",
+        b"HTTP/1.1 407 Proxy Authentication Required\r\n\
+          \r\n".len()
+    );
+
+    assert!(!h.upgrade);
+}
+
+#[test]
+fn ok() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 OK\r\n\
+          \r\n",
+        b"HTTP/1.1 200 OK\r\n\
+          \r\n".len()
+    );
+
+    assert!(!h.upgrade);
+}
+
+#[test]
+fn reset() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_head();
+    p.set_request_method(b"CONNECT");
+    p.reset();
+
+    assert_finished(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 200 OK\r\n\
+          \r\n",
+        b"HTTP/1.1 200 OK\r\n\
+          \r\n".len()
+    );
+}
+
+#[test]
+fn switching_protocols() {
+    let (mut p, mut h) = http1_setup!();
+
+    p.init_message();
+
+    assert_upgrade(
+        &mut p,
+        &mut h,
+        b"HTTP/1.1 101 Switching Protocols\r\n\
+          Connection: Upgrade\r\n\
+          Upgrade: websocket\r\n\
+          \r\n\
+          \x81\x05Hello",
+        b"HTTP/1.1 101 Switching Protocols\r\n\
+          Connection: Upgrade\r\n\
+          Upgrade: websocket\r\n\
+          \r\n".len()
+    );
+
+    assert!(h.upgrade);
+}
@@ -101,7 +101,7 @@ fn not_allowed_error() {
             &mut p,
             &mut h,
             &[*b],
-            ParserError::UrlEncodedName(*b)
+            ErrorKind::UrlEncodedName(*b)
         );
     }
 }
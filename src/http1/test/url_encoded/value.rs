@@ -119,7 +119,7 @@ fn not_allowed_error() {
             &mut p,
             &mut h,
             &[*b],
-            ParserError::UrlEncodedValue(*b)
+            ErrorKind::UrlEncodedValue(*b)
         );
     }
 }
@@ -0,0 +1,239 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+
+use std::error::Error;
+use std::io::{ self, Write };
+
+struct Closed;
+
+impl Write for Closed {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn request() -> Writer<Vec<u8>> {
+    let mut w = Writer::new(Vec::new());
+
+    w.request_line(b"GET", b"/", (1, 1)).unwrap();
+    w
+}
+
+#[test]
+fn header_name() {
+    let mut w = request();
+
+    match w.header(b"X-Bad:Name", b"value") {
+        Err(WriteError::HeaderName(b':')) => {},
+        _ => panic!()
+    }
+
+    match w.header(b"", b"value") {
+        Err(WriteError::EmptyHeaderName) => {},
+        _ => panic!()
+    }
+
+    match w.header(b"X-\xFF", b"value") {
+        Err(WriteError::HeaderName(0xFF)) => {},
+        _ => panic!()
+    }
+
+    assert_eq!(w.get_ref(), b"GET / HTTP/1.1\r\n");
+}
+
+#[test]
+fn header_value_empty() {
+    let mut w = request();
+
+    match w.header(b"X-Empty", b"") {
+        Err(WriteError::EmptyHeaderValue) => {},
+        _ => panic!()
+    }
+
+    assert_eq!(w.get_ref(), b"GET / HTTP/1.1\r\n");
+}
+
+#[test]
+fn header_value_injection() {
+    let mut w = request();
+
+    match w.header(b"X-Value", b"a\r\nX-Injected: 1") {
+        Err(WriteError::HeaderValue(b'\r')) => {},
+        _ => panic!()
+    }
+
+    match w.header(b"X-Value", b"a\nb") {
+        Err(WriteError::HeaderValue(b'\n')) => {},
+        _ => panic!()
+    }
+
+    match w.header(b"X-Value", b"a\0b") {
+        Err(WriteError::HeaderValue(0)) => {},
+        _ => panic!()
+    }
+
+    assert_eq!(w.get_ref(), b"GET / HTTP/1.1\r\n");
+}
+
+#[test]
+fn header_value_white_space() {
+    let mut w = request();
+
+    match w.header(b"X-Value", b" a") {
+        Err(WriteError::HeaderValue(b' ')) => {},
+        _ => panic!()
+    }
+
+    match w.header(b"X-Value", b"a\t") {
+        Err(WriteError::HeaderValue(b'\t')) => {},
+        _ => panic!()
+    }
+}
+
+#[test]
+fn io() {
+    let mut w = Writer::new(Closed);
+
+    match w.request_line(b"GET", b"/", (1, 1)) {
+        Err(ref error @ WriteError::Io(_)) => {
+            assert!(error.source().is_some());
+            assert_eq!(format!("{}", error), "<WriteError::Io: closed>");
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn method() {
+    let mut w = Writer::new(Vec::new());
+
+    match w.request_line(b"G T", b"/", (1, 1)) {
+        Err(WriteError::Method(b' ')) => {},
+        _ => panic!()
+    }
+
+    match w.request_line(b"", b"/", (1, 1)) {
+        Err(WriteError::EmptyMethod) => {},
+        _ => panic!()
+    }
+
+    assert!(w.get_ref().is_empty());
+}
+
+#[test]
+fn out_of_order() {
+    let mut w = Writer::new(Vec::new());
+
+    match w.header(b"Host", b"localhost") {
+        Err(WriteError::OutOfOrder) => {},
+        _ => panic!()
+    }
+
+    match w.body(b"data") {
+        Err(WriteError::OutOfOrder) => {},
+        _ => panic!()
+    }
+
+    match w.finish_head() {
+        Err(WriteError::OutOfOrder) => {},
+        _ => panic!()
+    }
+
+    w.status_line((1, 1), 204, b"No Content").unwrap();
+    w.finish_head().unwrap();
+
+    match w.header(b"Host", b"localhost") {
+        Err(WriteError::OutOfOrder) => {},
+        _ => panic!()
+    }
+
+    match w.request_line(b"GET", b"/", (1, 1)) {
+        Err(WriteError::OutOfOrder) => {},
+        _ => panic!()
+    }
+}
+
+#[test]
+fn status() {
+    let mut w = Writer::new(Vec::new());
+
+    match w.status_line((1, 1), 200, b"OK\r\n") {
+        Err(WriteError::Status(b'\r')) => {},
+        _ => panic!()
+    }
+
+    match w.status_line((1, 1), 200, b"") {
+        Err(WriteError::EmptyStatus) => {},
+        _ => panic!()
+    }
+
+    match w.status_line((1, 1), 99, b"Low") {
+        Err(WriteError::StatusCode(99)) => {},
+        _ => panic!()
+    }
+
+    match w.status_line((1, 1), 1000, b"High") {
+        Err(WriteError::StatusCode(1000)) => {},
+        _ => panic!()
+    }
+
+    assert!(w.get_ref().is_empty());
+}
+
+#[test]
+fn url() {
+    let mut w = Writer::new(Vec::new());
+
+    match w.request_line(b"GET", b"/a b", (1, 1)) {
+        Err(WriteError::Url(b' ')) => {},
+        _ => panic!()
+    }
+
+    match w.request_line(b"GET", b"/\r\nX-Injected: 1", (1, 1)) {
+        Err(WriteError::Url(b'\r')) => {},
+        _ => panic!()
+    }
+
+    match w.request_line(b"GET", b"", (1, 1)) {
+        Err(WriteError::EmptyUrl) => {},
+        _ => panic!()
+    }
+
+    assert!(w.get_ref().is_empty());
+}
+
+#[test]
+fn version() {
+    let mut w = Writer::new(Vec::new());
+
+    match w.request_line(b"GET", b"/", (1, 10)) {
+        Err(WriteError::Version(10)) => {},
+        _ => panic!()
+    }
+
+    match w.status_line((11, 0), 200, b"OK") {
+        Err(WriteError::Version(11)) => {},
+        _ => panic!()
+    }
+
+    assert!(w.get_ref().is_empty());
+}
@@ -0,0 +1,103 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+
+#[test]
+fn head() {
+    let mut w = Writer::new(Vec::new());
+
+    w.request_line(b"GET", b"/index.html?a=1", (1, 1)).unwrap();
+    w.header(b"Host", b"localhost").unwrap();
+    w.header(b"Accept", b"text/html,\t*/*;q=0.8").unwrap();
+    w.finish_head().unwrap();
+
+    let stream = w.into_inner();
+
+    assert_eq!(
+        &stream[..],
+        &b"GET /index.html?a=1 HTTP/1.1\r\n\
+           Host: localhost\r\n\
+           Accept: text/html,\t*/*;q=0.8\r\n\
+           \r\n"[..]
+    );
+
+    match RequestHead::parse(&stream) {
+        Ok(Parsed::Complete(head)) => {
+            assert_eq!(head.method(), b"GET");
+            assert_eq!(head.url(), b"/index.html?a=1");
+            assert_eq!(head.version(), (1, 1));
+            assert_eq!(head.headers(), &[(&b"Host"[..], &b"localhost"[..]),
+                                         (&b"Accept"[..], &b"text/html,\t*/*;q=0.8"[..])]);
+            assert_eq!(head.length(), stream.len());
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn message() {
+    let mut w = Writer::new(Vec::new());
+
+    w.request_line(b"POST", b"/form", (1, 0)).unwrap();
+    w.header(b"Content-Length", b"9").unwrap();
+    w.body(b"Hello").unwrap();
+    w.body(b" Bob").unwrap();
+
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    match p.resume(&mut c, w.get_ref()) {
+        Ok(_) => {},
+        _ => panic!()
+    }
+
+    let request = c.take_request().unwrap();
+
+    assert_eq!(request.method(), b"POST");
+    assert_eq!(request.url(), b"/form");
+    assert_eq!(request.version(), (1, 0));
+    assert_eq!(request.body(), b"Hello Bob");
+}
+
+#[test]
+fn round_trip() {
+    let mut w = Writer::new(Vec::new());
+
+    w.request_line(b"GET", b"/", (1, 1)).unwrap();
+    w.header(b"X-Short", b"1").unwrap();
+    w.header(b"X-Tab", b"a\tb").unwrap();
+    w.header(b"X-Obsolete", b"\xC3\xA9t\xC3\xA9").unwrap();
+    w.finish_head().unwrap();
+
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    match p.resume(&mut c, w.get_ref()) {
+        Ok(_) => {},
+        _ => panic!()
+    }
+
+    let request = c.take_request().unwrap();
+
+    assert_eq!(request.headers().get(b"x-short"), Some(&b"1"[..]));
+    assert_eq!(request.headers().get(b"x-tab"), Some(&b"a\tb"[..]));
+    assert_eq!(request.headers().get(b"x-obsolete"), Some(&b"\xC3\xA9t\xC3\xA9"[..]));
+}
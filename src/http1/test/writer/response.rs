@@ -0,0 +1,137 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use http1::*;
+
+#[test]
+fn head() {
+    let mut w = Writer::new(Vec::new());
+
+    w.status_line((1, 1), 404, b"Not Found").unwrap();
+    w.header(b"Content-Length", b"0").unwrap();
+    w.finish_head().unwrap();
+
+    let stream = w.into_inner();
+
+    assert_eq!(
+        &stream[..],
+        &b"HTTP/1.1 404 Not Found\r\n\
+           Content-Length: 0\r\n\
+           \r\n"[..]
+    );
+
+    match ResponseHead::parse(&stream) {
+        Ok(Parsed::Complete(head)) => {
+            assert_eq!(head.version(), (1, 1));
+            assert_eq!(head.status_code(), 404);
+            assert_eq!(head.status(), b"Not Found");
+            assert_eq!(head.header(b"content-length"), Some(&b"0"[..]));
+            assert_eq!(head.length(), stream.len());
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn message() {
+    let mut w = Writer::new(Vec::new());
+
+    w.status_line((1, 1), 200, b"OK").unwrap();
+    w.header(b"Content-Type", b"text/plain; charset=utf-8").unwrap();
+    w.header(b"Content-Length", b"13").unwrap();
+    w.body(b"Hello, world!").unwrap();
+
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    match p.resume(&mut c, w.get_ref()) {
+        Ok(_) => {},
+        _ => panic!()
+    }
+
+    let response = c.take_response().unwrap();
+
+    assert_eq!(response.status_code(), 200);
+    assert_eq!(response.status(), b"OK");
+    assert_eq!(response.headers().get(b"content-type"), Some(&b"text/plain; charset=utf-8"[..]));
+    assert_eq!(response.body(), b"Hello, world!");
+}
+
+#[test]
+fn obsolete_text() {
+    let mut w = Writer::new(Vec::new());
+
+    w.status_line((1, 1), 200, b"\xC3\xA9t\xC3\xA9").unwrap();
+
+    assert_eq!(w.get_ref(), b"HTTP/1.1 200 \xC3\xA9t\xC3\xA9\r\n");
+}
+
+#[test]
+fn quoted_value() {
+    let mut w = Writer::new(Vec::new());
+
+    w.status_line((1, 1), 200, b"OK").unwrap();
+    w.header(b"ETag", b"\"abc\"").unwrap();
+    w.header(b"Content-Type", b"text/plain; charset=\"utf-8\"").unwrap();
+    w.header(b"Content-Length", b"0").unwrap();
+    w.finish_head().unwrap();
+
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    match p.resume(&mut c, w.get_ref()) {
+        Ok(_) => {},
+        _ => panic!()
+    }
+
+    let response = c.take_response().unwrap();
+
+    assert_eq!(response.headers().get(b"etag"), Some(&b"\"abc\""[..]));
+    assert_eq!(
+        response.headers().get(b"content-type"),
+        Some(&b"text/plain; charset=\"utf-8\""[..])
+    );
+}
+
+#[test]
+fn round_trip() {
+    let mut w = Writer::new(Vec::new());
+
+    w.status_line((1, 1), 200, b"\xC3\xA9t\xC3\xA9").unwrap();
+    w.header(b"X-Short", b"1").unwrap();
+    w.header(b"Content-Length", b"0").unwrap();
+    w.finish_head().unwrap();
+
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    match p.resume(&mut c, w.get_ref()) {
+        Ok(_) => {},
+        _ => panic!()
+    }
+
+    let response = c.take_response().unwrap();
+
+    assert_eq!(response.status_code(), 200);
+    assert_eq!(response.status(), b"\xC3\xA9t\xC3\xA9");
+    assert_eq!(response.headers().get(b"x-short"), Some(&b"1"[..]));
+}
@@ -0,0 +1,534 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+//! HTTP 1.x message writing.
+
+use byte::is_token;
+
+use std::{ error, fmt };
+use std::io::{ self, Write };
+
+/// Writer errors.
+pub enum WriteError {
+    /// Multipart data contains the boundary.
+    BoundaryInData,
+
+    /// Invalid chunk extension name on byte `u8`.
+    ChunkExtensionName(u8),
+
+    /// Invalid chunk extension value on byte `u8`.
+    ChunkExtensionValue(u8),
+
+    /// Chunk extension name is empty.
+    EmptyChunkExtensionName,
+
+    /// Header name is empty.
+    EmptyHeaderName,
+
+    /// Header value is empty.
+    EmptyHeaderValue,
+
+    /// Request method is empty.
+    EmptyMethod,
+
+    /// Status is empty.
+    EmptyStatus,
+
+    /// Request URL is empty.
+    EmptyUrl,
+
+    /// Trailer that is not allowed to be sent as a trailer, such as `Content-Length`.
+    ForbiddenTrailer,
+
+    /// Invalid header name on byte `u8`.
+    HeaderName(u8),
+
+    /// Invalid header value on byte `u8`.
+    HeaderValue(u8),
+
+    /// Writing to the underlying writer has failed.
+    Io(io::Error),
+
+    /// Invalid request method on byte `u8`.
+    Method(u8),
+
+    /// Part of the message has been written out of order, such as a header after the body.
+    OutOfOrder,
+
+    /// Invalid status on byte `u8`.
+    Status(u8),
+
+    /// Status code is not a three digit number.
+    StatusCode(u16),
+
+    /// Invalid URL on byte `u8`.
+    Url(u8),
+
+    /// HTTP version number is not a single digit.
+    Version(u16)
+}
+
+impl WriteError {
+    /// Format this for debug and display purposes.
+    fn format(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            WriteError::BoundaryInData => {
+                write!(
+                    formatter,
+                    "<WriteError::BoundaryInData>"
+                )
+            },
+            WriteError::ChunkExtensionName(byte) => {
+                write!(
+                    formatter,
+                    "<WriteError::ChunkExtensionName: {}>",
+                    byte
+                )
+            },
+            WriteError::ChunkExtensionValue(byte) => {
+                write!(
+                    formatter,
+                    "<WriteError::ChunkExtensionValue: {}>",
+                    byte
+                )
+            },
+            WriteError::EmptyChunkExtensionName => {
+                write!(
+                    formatter,
+                    "<WriteError::EmptyChunkExtensionName>"
+                )
+            },
+            WriteError::EmptyHeaderName => {
+                write!(
+                    formatter,
+                    "<WriteError::EmptyHeaderName>"
+                )
+            },
+            WriteError::EmptyHeaderValue => {
+                write!(
+                    formatter,
+                    "<WriteError::EmptyHeaderValue>"
+                )
+            },
+            WriteError::EmptyMethod => {
+                write!(
+                    formatter,
+                    "<WriteError::EmptyMethod>"
+                )
+            },
+            WriteError::EmptyStatus => {
+                write!(
+                    formatter,
+                    "<WriteError::EmptyStatus>"
+                )
+            },
+            WriteError::EmptyUrl => {
+                write!(
+                    formatter,
+                    "<WriteError::EmptyUrl>"
+                )
+            },
+            WriteError::ForbiddenTrailer => {
+                write!(
+                    formatter,
+                    "<WriteError::ForbiddenTrailer>"
+                )
+            },
+            WriteError::HeaderName(byte) => {
+                write!(
+                    formatter,
+                    "<WriteError::HeaderName: {}>",
+                    byte
+                )
+            },
+            WriteError::HeaderValue(byte) => {
+                write!(
+                    formatter,
+                    "<WriteError::HeaderValue: {}>",
+                    byte
+                )
+            },
+            WriteError::Io(ref error) => {
+                write!(
+                    formatter,
+                    "<WriteError::Io: {}>",
+                    error
+                )
+            },
+            WriteError::Method(byte) => {
+                write!(
+                    formatter,
+                    "<WriteError::Method: {}>",
+                    byte
+                )
+            },
+            WriteError::OutOfOrder => {
+                write!(
+                    formatter,
+                    "<WriteError::OutOfOrder>"
+                )
+            },
+            WriteError::Status(byte) => {
+                write!(
+                    formatter,
+                    "<WriteError::Status: {}>",
+                    byte
+                )
+            },
+            WriteError::StatusCode(code) => {
+                write!(
+                    formatter,
+                    "<WriteError::StatusCode: {}>",
+                    code
+                )
+            },
+            WriteError::Url(byte) => {
+                write!(
+                    formatter,
+                    "<WriteError::Url: {}>",
+                    byte
+                )
+            },
+            WriteError::Version(number) => {
+                write!(
+                    formatter,
+                    "<WriteError::Version: {}>",
+                    number
+                )
+            }
+        }
+    }
+}
+
+impl fmt::Debug for WriteError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.format(formatter)
+    }
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.format(formatter)
+    }
+}
+
+impl error::Error for WriteError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            WriteError::Io(ref error) => Some(error),
+            _                         => None
+        }
+    }
+}
+
+impl From<io::Error> for WriteError {
+    fn from(error: io::Error) -> WriteError {
+        WriteError::Io(error)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Part of the message that is written next.
+#[derive(Clone,Copy,Debug,PartialEq)]
+enum Position {
+    /// Body, after the head has been finished.
+    Body,
+
+    /// Headers, after the request line or status line.
+    Headers,
+
+    /// Request line or status line.
+    StartLine
+}
+
+/// Verify that `version` is a valid HTTP version.
+fn check_version(version: (u16, u16)) -> Result<(), WriteError> {
+    if version.0 > 9 {
+        Err(WriteError::Version(version.0))
+    } else if version.1 > 9 {
+        Err(WriteError::Version(version.1))
+    } else {
+        Ok(())
+    }
+}
+
+/// Verify that each byte of `field` is field content, which is horizontal tab, space, visible 7-bit
+/// bytes and obsolete text, and that it neither begins nor ends with white space, since parsing
+/// strips it.
+fn check_field(field: &[u8]) -> Option<u8> {
+    if let Some(byte) = field.first() {
+        if *byte == b' ' || *byte == b'\t' {
+            return Some(*byte);
+        }
+    }
+
+    if let Some(byte) = field.last() {
+        if *byte == b' ' || *byte == b'\t' {
+            return Some(*byte);
+        }
+    }
+
+    field.iter().find(|byte| (**byte < 0x20 && **byte != b'\t') || **byte == 0x7F).cloned()
+}
+
+/// Verify that `name` is a token, and that `value` is a valid, non-empty header value.
+pub fn check_header(name: &[u8], value: &[u8]) -> Result<(), WriteError> {
+    if name.is_empty() {
+        return Err(WriteError::EmptyHeaderName);
+    }
+
+    if let Some(byte) = name.iter().find(|byte| !is_token(**byte)) {
+        return Err(WriteError::HeaderName(*byte));
+    }
+
+    if value.is_empty() {
+        return Err(WriteError::EmptyHeaderValue);
+    }
+
+    if let Some(byte) = check_field(value) {
+        return Err(WriteError::HeaderValue(byte));
+    }
+
+    Ok(())
+}
+
+/// HTTP 1.x message writer.
+///
+/// Serializes a request line or status line, headers, and a body into any `std::io::Write`
+/// implementation, such as a `Vec<u8>` or a `TcpStream`. The method, URL, status, and each header
+/// name and value are verified before they're written, so that a written head can always be
+/// parsed, and so that CR and LF can't be injected into the head. Nothing is written when
+/// verification fails.
+///
+/// The writer does not add framing headers. `Content-Length` or `Transfer-Encoding` must be
+/// written along with the other headers, when the message has a body.
+///
+/// # Examples
+///
+/// ```
+/// use http_box::http1::Writer;
+///
+/// let mut writer = Writer::new(Vec::new());
+///
+/// writer.status_line((1, 1), 200, b"OK").unwrap();
+/// writer.header(b"Content-Length", b"5").unwrap();
+/// writer.body(b"Hello").unwrap();
+///
+/// assert_eq!(
+///     writer.into_inner(),
+///     b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nHello"
+/// );
+///
+/// assert!(Writer::new(Vec::new()).request_line(b"GET", b"/\r\nX-Injected: 1", (1, 1)).is_err());
+/// ```
+pub struct Writer<W: Write> {
+    /// Part of the message that is written next.
+    position: Position,
+
+    /// Underlying writer.
+    writer: W
+}
+
+impl<W: Write> Writer<W> {
+    /// Create a new `Writer`.
+    ///
+    /// # Arguments
+    ///
+    /// **`writer`**
+    ///
+    /// The underlying writer.
+    pub fn new(writer: W) -> Writer<W> {
+        Writer{
+            position: Position::StartLine,
+            writer
+        }
+    }
+
+    /// Write body data.
+    ///
+    /// The head is finished prior to writing the first body data. Body data is written as-is,
+    /// and this can be called multiple times.
+    ///
+    /// # Arguments
+    ///
+    /// **`data`**
+    ///
+    /// The body data.
+    pub fn body(&mut self, data: &[u8]) -> Result<(), WriteError> {
+        if self.position == Position::Headers {
+            self.finish_head()?;
+        } else if self.position != Position::Body {
+            return Err(WriteError::OutOfOrder);
+        }
+
+        self.writer.write_all(data)?;
+
+        Ok(())
+    }
+
+    /// Finish the head by writing the empty line that follows the headers.
+    ///
+    /// This is only necessary when the message has no body.
+    pub fn finish_head(&mut self) -> Result<(), WriteError> {
+        if self.position != Position::Headers {
+            return Err(WriteError::OutOfOrder);
+        }
+
+        self.writer.write_all(b"\r\n")?;
+
+        self.position = Position::Body;
+
+        Ok(())
+    }
+
+    /// Retrieve a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Retrieve a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Write a header.
+    ///
+    /// # Arguments
+    ///
+    /// **`name`**
+    ///
+    /// The header name, which must be a token.
+    ///
+    /// **`value`**
+    ///
+    /// The header value, which must not be empty, must not contain control characters other than
+    /// horizontal tab, and must not begin or end with white space.
+    pub fn header(&mut self, name: &[u8], value: &[u8]) -> Result<(), WriteError> {
+        if self.position != Position::Headers {
+            return Err(WriteError::OutOfOrder);
+        }
+
+        check_header(name, value)?;
+
+        self.writer.write_all(name)?;
+        self.writer.write_all(b": ")?;
+        self.writer.write_all(value)?;
+        self.writer.write_all(b"\r\n")?;
+
+        Ok(())
+    }
+
+    /// Consume this, and retrieve the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Write a request line.
+    ///
+    /// # Arguments
+    ///
+    /// **`method`**
+    ///
+    /// The request method, which must be a token.
+    ///
+    /// **`url`**
+    ///
+    /// The request URL, which must contain visible 7-bit bytes only.
+    ///
+    /// **`version`**
+    ///
+    /// The HTTP major and minor version.
+    pub fn request_line(&mut self, method: &[u8], url: &[u8], version: (u16, u16))
+    -> Result<(), WriteError> {
+        if self.position != Position::StartLine {
+            return Err(WriteError::OutOfOrder);
+        }
+
+        if method.is_empty() {
+            return Err(WriteError::EmptyMethod);
+        }
+
+        if let Some(byte) = method.iter().find(|byte| !is_token(**byte)) {
+            return Err(WriteError::Method(*byte));
+        }
+
+        if url.is_empty() {
+            return Err(WriteError::EmptyUrl);
+        }
+
+        if let Some(byte) = url.iter().find(|byte| !is_visible_7bit!(**byte)) {
+            return Err(WriteError::Url(*byte));
+        }
+
+        check_version(version)?;
+
+        self.writer.write_all(method)?;
+        self.writer.write_all(b" ")?;
+        self.writer.write_all(url)?;
+        write!(self.writer, " HTTP/{}.{}\r\n", version.0, version.1)?;
+
+        self.position = Position::Headers;
+
+        Ok(())
+    }
+
+    /// Write a status line.
+    ///
+    /// # Arguments
+    ///
+    /// **`version`**
+    ///
+    /// The HTTP major and minor version.
+    ///
+    /// **`status_code`**
+    ///
+    /// The three digit status code.
+    ///
+    /// **`status`**
+    ///
+    /// The reason phrase, which must not be empty, must not contain control characters other than
+    /// horizontal tab, and must not begin or end with white space.
+    pub fn status_line(&mut self, version: (u16, u16), status_code: u16, status: &[u8])
+    -> Result<(), WriteError> {
+        if self.position != Position::StartLine {
+            return Err(WriteError::OutOfOrder);
+        }
+
+        check_version(version)?;
+
+        if !(100..=999).contains(&status_code) {
+            return Err(WriteError::StatusCode(status_code));
+        }
+
+        if status.is_empty() {
+            return Err(WriteError::EmptyStatus);
+        }
+
+        if let Some(byte) = check_field(status) {
+            return Err(WriteError::Status(byte));
+        }
+
+        write!(self.writer, "HTTP/{}.{} {} ", version.0, version.1, status_code)?;
+
+        self.writer.write_all(status)?;
+        self.writer.write_all(b"\r\n")?;
+
+        self.position = Position::Headers;
+
+        Ok(())
+    }
+}
@@ -16,9 +16,14 @@
 
 //! HTTP 2.x callback trait.
 
+use fsm::Action;
+
 /// Type that handles HTTP/2.x parser events.
+///
+/// `E` is the error type that callbacks abort parsing with, which is surfaced as
+/// `ParserError::Handler`.
 #[allow(unused_variables)]
-pub trait HttpHandler {
+pub trait HttpHandler<E = ()> {
     /// Callback that is executed when a data frame has been located.
     ///
     /// *Note:* This may be executed multiple times in order to supply the entire segment.
@@ -35,10 +40,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_data(&mut self, data: &[u8], finished: bool) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_data(&mut self, data: &[u8], finished: bool) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a new frame format has been located.
@@ -63,11 +69,12 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
     fn on_frame_format(&mut self, payload_length: u32, frame_type: u8, flags: u8, stream_id: u32)
-    -> bool {
-        true
+    -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a go away frame has been located.
@@ -84,10 +91,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_go_away(&mut self, stream_id: u32, error_code: u32) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_go_away(&mut self, stream_id: u32, error_code: u32) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when go away frame debug data has been located.
@@ -106,10 +114,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_go_away_debug_data(&mut self, data: &[u8], finished: bool) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_go_away_debug_data(&mut self, data: &[u8], finished: bool) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a headers frame has been located.
@@ -130,10 +139,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_headers(&mut self, exclusive: bool, stream_id: u32, weight: u8) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_headers(&mut self, exclusive: bool, stream_id: u32, weight: u8) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a headers frame fragment has been located.
@@ -152,10 +162,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_headers_fragment(&mut self, fragment: &[u8], finished: bool) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_headers_fragment(&mut self, fragment: &[u8], finished: bool) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a ping frame has been located.
@@ -174,10 +185,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_ping(&mut self, data: &[u8], finished: bool) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_ping(&mut self, data: &[u8], finished: bool) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a priority frame has been located.
@@ -198,10 +210,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_priority(&mut self, exclusive: bool, stream_id: u32, weight: u8) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_priority(&mut self, exclusive: bool, stream_id: u32, weight: u8) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a push promise frame has been located.
@@ -214,10 +227,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_push_promise(&mut self, stream_id: u32) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_push_promise(&mut self, stream_id: u32) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a rst stream frame has been located.
@@ -230,10 +244,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_rst_stream(&mut self, error_code: u32) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_rst_stream(&mut self, error_code: u32) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a settings frame has been located.
@@ -250,10 +265,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_settings(&mut self, id: u16, value: u32) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_settings(&mut self, id: u16, value: u32) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when an unsupported frame has been located.
@@ -272,10 +288,11 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_unsupported(&mut self, data: &[u8], finished: bool) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_unsupported(&mut self, data: &[u8], finished: bool) -> Action<E> {
+        Action::Continue
     }
 
     /// Callback that is executed when a window update frame has been located.
@@ -288,9 +305,10 @@ pub trait HttpHandler {
     ///
     /// **Returns:**
     ///
-    /// `true` when parsing should continue, `false` to exit the parser function prematurely with
-    /// [`Success::Callback`](../fsm/enum.Success.html#variant.Callback).
-    fn on_window_update(&mut self, size_increment: u32) -> bool {
-        true
+    /// `Action::Continue` when parsing should continue, `Action::Pause` to exit the parser function
+    /// prematurely with [`Success::Callback`](../fsm/enum.Success.html#variant.Callback), or
+    /// `Action::Abort` to stop parsing with `ParserError::Handler`.
+    fn on_window_update(&mut self, size_increment: u32) -> Action<E> {
+        Action::Continue
     }
 }
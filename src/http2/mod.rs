@@ -33,6 +33,6 @@ pub use http2::flags::Flags;
 pub use http2::frame_format::FrameFormat;
 pub use http2::frame_type::FrameType;
 pub use http2::http_handler::HttpHandler;
-pub use http2::parser::Parser;
+pub use http2::parser::{ ErrorKind, Parser, ParserError };
 pub use http2::parser_state::ParserState;
 pub use http2::setting::Setting;
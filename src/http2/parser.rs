@@ -18,9 +18,11 @@
 
 #![allow(dead_code)]
 
-use fsm::{ ParserValue, Success };
+use excerpt::{ excerpt, write_excerpt };
+use fsm::{ Action, ParserValue, Success };
 use http2::flags::{ FL_PADDED, FL_PRIORITY };
-use http2::frame_type::{ FR_CONTINUATION,
+use http2::frame_type::{ FrameType,
+                         FR_CONTINUATION,
                          FR_DATA,
                          FR_GO_AWAY,
                          FR_HEADERS,
@@ -34,7 +36,7 @@ use http2::http_handler::HttpHandler;
 use http2::parser_state::ParserState;
 
 use byte_slice::ByteStream;
-use std::fmt;
+use std::{ error, fmt };
 
 // -------------------------------------------------------------------------------------------------
 // MACROS
@@ -86,6 +88,8 @@ macro_rules! payload_length {
 }
 
 /// Parse payload data.
+///
+/// An empty payload is finished without waiting for more data.
 macro_rules! parse_payload_data {
     ($parser:expr, $handler:expr, $context:expr, $callback:ident) => ({
         if bs_available!($context) >= actual_length!($parser) as usize {
@@ -100,19 +104,21 @@ macro_rules! parse_payload_data {
                 set_state!($parser, FrameLength1, frame_length1);
             }
 
-            if $handler.$callback(bs_slice!($context), true) {
+            if proceed!($handler.$callback(bs_slice!($context), true)) {
                 transition!($parser, $context);
             } else {
                 exit_callback!($parser, $context);
             }
         }
 
+        exit_if_eos!($parser, $context);
+
         // collect remaining slice
         dec_payload_length!($parser, bs_available!($context) as u32);
 
         bs_jump!($context, bs_available!($context));
 
-        if $handler.$callback(bs_slice!($context), false) {
+        if proceed!($handler.$callback(bs_slice!($context), false)) {
             exit_eos!($parser, $context);
         } else {
             exit_callback!($parser, $context);
@@ -144,40 +150,197 @@ macro_rules! read_u32 {
 
 // -------------------------------------------------------------------------------------------------
 
-/// Parser error messages.
+/// Parser error kinds.
+///
+/// `E` is the error type of the handler, which aborts parsing with `Action::Abort`.
 #[derive(Clone,Copy,PartialEq)]
-pub enum ParserError {
+pub enum ErrorKind<E = ()> {
     /// Parsing has failed.
-    Dead
+    Dead,
+
+    /// Handler aborted parsing with error `E`.
+    Handler(E),
+
+    /// Input ended within the payload, or the header, of a frame.
+    ///
+    /// # Arguments
+    ///
+    /// **(1)**: The frame type.
+    ///
+    /// **(2)**: The amount of bytes missing from the frame.
+    TruncatedFrame(FrameType, usize),
+
+    /// Input ended within a frame header, before the frame type was parsed.
+    ///
+    /// # Arguments
+    ///
+    /// **(1)**: The amount of bytes missing from the frame header.
+    TruncatedFrameHeader(usize)
 }
 
-impl ParserError {
+impl<E: fmt::Debug> ErrorKind<E> {
     /// Format this for debug and display purposes.
     fn format(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            ParserError::Dead => {
-                write!(formatter, "<ParserError::Dead>")
+            ErrorKind::Dead => {
+                write!(formatter, "<ErrorKind::Dead>")
+            },
+            ErrorKind::Handler(ref error) => {
+                write!(formatter, "<ErrorKind::Handler: {:?}>", error)
+            },
+            ErrorKind::TruncatedFrame(frame_type, missing) => {
+                write!(formatter, "<ErrorKind::TruncatedFrame: {}, {}>", frame_type, missing)
             },
+            ErrorKind::TruncatedFrameHeader(missing) => {
+                write!(formatter, "<ErrorKind::TruncatedFrameHeader: {}>", missing)
+            }
         }
     }
 }
 
-impl fmt::Debug for ParserError {
+impl<E: fmt::Debug> fmt::Debug for ErrorKind<E> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         self.format(formatter)
     }
 }
 
-impl fmt::Display for ParserError {
+impl<E: fmt::Debug> fmt::Display for ErrorKind<E> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         self.format(formatter)
     }
 }
 
+impl<E: fmt::Debug> error::Error for ErrorKind<E> {
+}
+
+/// Parser error.
+///
+/// Along with the kind of error, this carries the absolute offset of the stream at which the error
+/// occurred, the state the parser was in, and an excerpt of the bytes surrounding the offset. The
+/// `Display` implementation shows the excerpt, with a caret beneath the offset.
+#[derive(Clone,PartialEq)]
+pub struct ParserError<E = ()> {
+    /// Bytes surrounding the offset.
+    excerpt: Vec<u8>,
+
+    /// Index of the offset within the excerpt.
+    excerpt_index: usize,
+
+    /// Kind of error.
+    kind: ErrorKind<E>,
+
+    /// Absolute stream offset.
+    offset: usize,
+
+    /// Parser state in which the error occurred.
+    state: ParserState
+}
+
+impl<E> ParserError<E> {
+    /// Create a new `ParserError`.
+    ///
+    /// # Arguments
+    ///
+    /// **`kind`**
+    ///
+    /// The kind of error.
+    ///
+    /// **`state`**
+    ///
+    /// The parser state in which the error occurred.
+    ///
+    /// **`stream`**
+    ///
+    /// The stream of data that was being parsed.
+    ///
+    /// **`index`**
+    ///
+    /// The index within `stream` at which the error occurred.
+    ///
+    /// **`stream_offset`**
+    ///
+    /// The absolute offset of `stream`.
+    pub fn new(kind: ErrorKind<E>, state: ParserState, stream: &[u8], index: usize,
+               stream_offset: usize) -> ParserError<E> {
+        let (excerpt, excerpt_index) = excerpt(stream, index);
+
+        ParserError{
+            excerpt,
+            excerpt_index,
+            kind,
+            offset: stream_offset + index,
+            state
+        }
+    }
+
+    /// Retrieve the bytes surrounding the offset.
+    pub fn excerpt(&self) -> &[u8] {
+        &self.excerpt
+    }
+
+    /// Retrieve the index of the offset within the excerpt.
+    pub fn excerpt_index(&self) -> usize {
+        self.excerpt_index
+    }
+
+    /// Consume this, and retrieve the kind of error.
+    pub fn into_kind(self) -> ErrorKind<E> {
+        self.kind
+    }
+
+    /// Retrieve the kind of error.
+    pub fn kind(&self) -> &ErrorKind<E> {
+        &self.kind
+    }
+
+    /// Retrieve the absolute stream offset at which the error occurred.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Retrieve the parser state in which the error occurred.
+    pub fn state(&self) -> ParserState {
+        self.state
+    }
+}
+
+impl<E: fmt::Debug> fmt::Debug for ParserError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "<ParserError: {:?}, offset: {}, state: {:?}>",
+            self.kind,
+            self.offset,
+            self.state
+        )
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for ParserError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} at offset {} in state {:?}",
+            self.kind,
+            self.offset,
+            self.state
+        )?;
+
+        write_excerpt(formatter, &self.excerpt, self.excerpt_index)
+    }
+}
+
+impl<E: fmt::Debug> error::Error for ParserError<E> {
+}
+
 // -------------------------------------------------------------------------------------------------
 
+/// State function.
+type StateFunction<'a, T, E> = fn(&mut Parser<'a, T, E>, &mut T, &mut ByteStream)
+                               -> Result<ParserValue, ErrorKind<E>>;
+
 /// HTTP 2.x parser.
-pub struct Parser<'a, T: HttpHandler + 'a> {
+pub struct Parser<'a, T: HttpHandler<E> + 'a, E = ()> {
     /// Bit data that stores parser state details.
     bit_data32a: u32,
 
@@ -193,22 +356,29 @@ pub struct Parser<'a, T: HttpHandler + 'a> {
     /// Total byte count processed.
     byte_count: usize,
 
+    /// Absolute offset at which the current frame ends.
+    frame_end: usize,
+
+    /// Type of the current frame.
+    frame_type: u8,
+
     /// Current state.
     state: ParserState,
 
     /// Current state function.
-    state_function: fn(&mut Parser<'a, T>, &mut T, &mut ByteStream)
-    -> Result<ParserValue, ParserError>
+    state_function: StateFunction<'a, T, E>
 }
 
-impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
+impl<'a, E, T: HttpHandler<E> + 'a> Parser<'a, T, E> {
     /// Create a new `Parser`.
-    pub fn new() -> Parser<'a, T> {
+    pub fn new() -> Parser<'a, T, E> {
         Parser{ bit_data32a:    0,
                 bit_data32b:    0,
                 bit_data16a:    0,
                 bit_data16b:    0,
                 byte_count:     0,
+                frame_end:      0,
+                frame_type:     0,
                 state:          ParserState::FrameLength1,
                 state_function: Parser::frame_length1 }
     }
@@ -223,9 +393,79 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
         self.byte_count
     }
 
+    /// Signal the end of input, such as when the connection has been closed.
+    ///
+    /// When the input ends on a frame boundary, `Success::Finished` is returned. Otherwise a frame
+    /// has been truncated, and an `ErrorKind::TruncatedFrame` error is returned with the frame
+    /// type and the amount of bytes missing from the frame, or an
+    /// `ErrorKind::TruncatedFrameHeader` error is returned with the amount of bytes missing from
+    /// the frame header when the input ended before the frame type.
+    ///
+    /// # Arguments
+    ///
+    /// **`handler`**
+    ///
+    /// The handler implementation.
+    pub fn finish(&mut self, handler: &mut T) -> Result<Success, ParserError<E>> {
+        match self.resume(handler, &[]) {
+            Ok(Success::Eos(_)) => {
+                // callback states that don't require data have been executed
+            },
+            other => {
+                return other;
+            }
+        }
+
+        // amount of frame header bytes that have been parsed
+        let header_length = match self.state {
+            ParserState::FrameLength1   => 0,
+            ParserState::FrameLength2   => 1,
+            ParserState::FrameLength3   => 2,
+            ParserState::FrameType      => 3,
+            ParserState::FrameFlags     => 4,
+            ParserState::FrameStreamId1 => 5,
+            ParserState::FrameStreamId2 => 6,
+            ParserState::FrameStreamId3 => 7,
+            ParserState::FrameStreamId4 => 8,
+            _                           => 9
+        };
+
+        // the input ended between frames, which includes the end of a frame with no payload that
+        // is still waiting within its payload state
+        if header_length == 0 || (header_length == 9 && self.frame_end == self.byte_count) {
+            self.state          = ParserState::Finished;
+            self.state_function = Parser::finished;
+
+            return Ok(Success::Finished(0));
+        }
+
+        let kind = if header_length < 4 {
+            ErrorKind::TruncatedFrameHeader(9 - header_length)
+        } else if header_length < 9 {
+            ErrorKind::TruncatedFrame(
+                FrameType::from_u8((self.bit_data32a & 0xFF) as u8),
+                9 - header_length + payload_length!(self) as usize
+            )
+        } else {
+            ErrorKind::TruncatedFrame(
+                FrameType::from_u8(self.frame_type),
+                self.frame_end - self.byte_count
+            )
+        };
+
+        let error = ParserError::new(kind, self.state, &[], 0, self.byte_count);
+
+        self.state          = ParserState::Dead;
+        self.state_function = Parser::dead;
+
+        Err(error)
+    }
+
     /// Reset `Parser` to its initial state.
     pub fn reset(&mut self) {
         self.byte_count     = 0;
+        self.frame_end      = 0;
+        self.frame_type     = 0;
         self.state          = ParserState::FrameLength1;
         self.state_function = Parser::frame_length1;
 
@@ -252,11 +492,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
     ///
     /// The stream of data to be parsed.
     #[inline]
-    pub fn resume(&mut self, mut handler: &mut T, stream: &[u8]) -> Result<Success, ParserError> {
+    pub fn resume(&mut self, handler: &mut T, stream: &[u8])
+    -> Result<Success, ParserError<E>> {
         let mut context = ByteStream::new(stream);
 
         loop {
-            match (self.state_function)(self, &mut handler, &mut context) {
+            match (self.state_function)(self, handler, &mut context) {
                 Ok(ParserValue::Continue) => {
                 },
                 Ok(ParserValue::Exit(success)) => {
@@ -268,7 +509,15 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
                     return Ok(success);
                 },
-                Err(error) => {
+                Err(kind) => {
+                    let error = ParserError::new(
+                        kind,
+                        self.state,
+                        stream,
+                        context.stream_index,
+                        self.byte_count
+                    );
+
                     self.byte_count     += context.stream_index;
                     self.state           = ParserState::Dead;
                     self.state_function  = Parser::dead;
@@ -290,7 +539,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn frame_length1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.reset_bit_data();
@@ -320,7 +569,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn frame_length2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32a |= (get_u8!(context) as u32) << 16;
@@ -335,7 +584,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn frame_length3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32a |= (get_u8!(context) as u32) << 8;
@@ -350,7 +599,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn frame_type(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32a |= get_u8!(context) as u32;
@@ -365,7 +614,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn frame_flags(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data16a = get_u8!(context) as u16;
@@ -380,7 +629,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn frame_stream_id1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         if bs_available!(context) >= 4 {
@@ -408,7 +657,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn frame_stream_id2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b = (get_u8!(context) as u32) << 16;
@@ -423,7 +672,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn frame_stream_id3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b = (get_u8!(context) as u32) << 8;
@@ -438,7 +687,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn frame_stream_id4(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= get_u8!(context) as u32;
@@ -453,7 +702,10 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn frame_format_end(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
+        self.frame_end  = self.byte_count + context.stream_index + payload_length!(self) as usize;
+        self.frame_type = (self.bit_data32a & 0xFF) as u8;
+
         // match frame type
         match (self.bit_data32a & 0xFF) as u8 {
             FR_DATA => {
@@ -522,12 +774,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             }
         }
 
-        if handler.on_frame_format(
+        if proceed!(handler.on_frame_format(
             payload_length!(self),
             (self.bit_data32a & 0xFF) as u8,
             self.bit_data16a as u8,
             self.bit_data32b & 0x7FFFFFFF
-        ) {
+        )) {
             self.bit_data16a  = 0;
             self.bit_data32a &= 0xFFFFFF00;
             self.bit_data32b  = 0;
@@ -544,7 +796,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn frame_padding(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         if bs_available!(context) >= payload_length!(self) as usize {
@@ -573,7 +825,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn data_pad_length(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32a |= get_u8!(context) as u32;
@@ -590,9 +842,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn data_data(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        exit_if_eos!(self, context);
-
+    -> Result<ParserValue, ErrorKind<E>> {
         parse_payload_data!(
             self,
             handler,
@@ -607,7 +857,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn go_away_stream_id1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         if bs_available!(context) >= 4 {
@@ -635,7 +885,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn go_away_stream_id2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 16;
@@ -650,7 +900,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn go_away_stream_id3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 8;
@@ -665,7 +915,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn go_away_stream_id4(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= get_u8!(context) as u32;
@@ -680,7 +930,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn go_away_error_code1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         if bs_available!(context) >= 4 {
@@ -709,7 +959,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn go_away_error_code2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data16a |= get_u8!(context) as u16;
@@ -724,7 +974,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn go_away_error_code3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data16b |= (get_u8!(context) as u16) << 8;
@@ -739,7 +989,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn go_away_error_code4(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data16b |= get_u8!(context) as u16;
@@ -754,7 +1004,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn go_away_callback(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         dec_payload_length!(self, 8);
 
         if payload_length!(self) > 0 {
@@ -763,10 +1013,10 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
             set_state!(self, FrameLength1, frame_length1);
         }
 
-        if handler.on_go_away(
+        if proceed!(handler.on_go_away(
             self.bit_data32b & 0x7FFFFFFF,
             (self.bit_data16a as u32) << 16 | self.bit_data16b as u32
-        ) {
+        )) {
             transition!(self, context);
         } else {
             exit_callback!(self, context);
@@ -775,9 +1025,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn go_away_debug_data(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        exit_if_eos!(self, context);
-
+    -> Result<ParserValue, ErrorKind<E>> {
         parse_payload_data!(
             self,
             handler,
@@ -792,7 +1040,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn headers_pad_length_with_priority(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32a |= get_u8!(context) as u32;
@@ -809,7 +1057,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn headers_pad_length_without_priority(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32a |= get_u8!(context) as u32;
@@ -826,7 +1074,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn headers_stream_id1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         if bs_available!(context) >= 4 {
@@ -854,7 +1102,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn headers_stream_id2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 16;
@@ -869,7 +1117,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn headers_stream_id3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 8;
@@ -884,7 +1132,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn headers_stream_id4(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= get_u8!(context) as u32;
@@ -899,7 +1147,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn headers_weight(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data16a = (get_u8!(context) as u16) << 8;
@@ -917,12 +1165,12 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn headers_callback(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        if handler.on_headers(
+    -> Result<ParserValue, ErrorKind<E>> {
+        if proceed!(handler.on_headers(
             self.bit_data32b >> 31 == 1,
             self.bit_data32b & 0x7FFFFFFF,
             (self.bit_data16a >> 8) as u8
-        ) {
+        )) {
             transition!(
                 self,
                 context,
@@ -941,9 +1189,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn headers_fragment(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        exit_if_eos!(self, context);
-
+    -> Result<ParserValue, ErrorKind<E>> {
         parse_payload_data!(
             self,
             handler,
@@ -958,9 +1204,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn ping_data(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        exit_if_eos!(self, context);
-
+    -> Result<ParserValue, ErrorKind<E>> {
         parse_payload_data!(
             self,
             handler,
@@ -975,7 +1219,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn priority_stream_id1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         if bs_available!(context) >= 4 {
@@ -1003,7 +1247,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn priority_stream_id2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 16;
@@ -1018,7 +1262,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn priority_stream_id3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 8;
@@ -1033,7 +1277,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn priority_stream_id4(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= get_u8!(context) as u32;
@@ -1048,14 +1292,14 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn priority_weight(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
-        if handler.on_priority(
+        if proceed!(handler.on_priority(
             (self.bit_data32b >> 31) == 1,
             self.bit_data32b & 0x7FFFFFFF,
             get_u8!(context)
-        ) {
+        )) {
             transition!(
                 self,
                 context,
@@ -1078,7 +1322,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn push_promise_pad_length(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32a |= get_u8!(context) as u32;
@@ -1095,7 +1339,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn push_promise_stream_id1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         if bs_available!(context) >= 4 {
@@ -1123,7 +1367,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn push_promise_stream_id2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 16;
@@ -1138,7 +1382,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn push_promise_stream_id3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 8;
@@ -1153,7 +1397,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn push_promise_stream_id4(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= get_u8!(context) as u32;
@@ -1168,13 +1412,13 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn push_promise_callback(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         // decrease payload by stream id (4)
         dec_payload_length!(self, 4);
 
-        if handler.on_push_promise(self.bit_data32b) {
+        if proceed!(handler.on_push_promise(self.bit_data32b)) {
             transition!(
                 self,
                 context,
@@ -1197,7 +1441,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn rst_stream_error_code1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         if bs_available!(context) >= 4 {
@@ -1225,7 +1469,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn rst_stream_error_code2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 16;
@@ -1240,7 +1484,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn rst_stream_error_code3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 8;
@@ -1255,7 +1499,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn rst_stream_error_code4(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= get_u8!(context) as u32;
@@ -1270,8 +1514,8 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn rst_stream_callback(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        if handler.on_rst_stream(self.bit_data32b) {
+    -> Result<ParserValue, ErrorKind<E>> {
+        if proceed!(handler.on_rst_stream(self.bit_data32b)) {
             transition!(
                 self,
                 context,
@@ -1294,7 +1538,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn settings_id1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         if bs_available!(context) >= 2 {
@@ -1322,7 +1566,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn settings_id2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data16a |= get_u8!(context) as u16;
@@ -1337,7 +1581,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn settings_value1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         if bs_available!(context) >= 4 {
@@ -1365,7 +1609,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn settings_value2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 16;
@@ -1380,7 +1624,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn settings_value3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 8;
@@ -1395,7 +1639,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn settings_value4(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= get_u8!(context) as u32;
@@ -1410,8 +1654,8 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn settings_callback(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        if handler.on_settings(self.bit_data16a, self.bit_data32b) {
+    -> Result<ParserValue, ErrorKind<E>> {
+        if proceed!(handler.on_settings(self.bit_data16a, self.bit_data32b)) {
             transition!(
                 self,
                 context,
@@ -1434,7 +1678,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn window_update_increment1(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         if bs_available!(context) >= 4 {
@@ -1462,7 +1706,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn window_update_increment2(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 16;
@@ -1477,7 +1721,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn window_update_increment3(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= (get_u8!(context) as u32) << 8;
@@ -1492,7 +1736,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn window_update_increment4(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32b |= get_u8!(context) as u32;
@@ -1507,8 +1751,8 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn window_update_callback(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        if handler.on_window_update(self.bit_data32b) {
+    -> Result<ParserValue, ErrorKind<E>> {
+        if proceed!(handler.on_window_update(self.bit_data32b)) {
             transition!(
                 self,
                 context,
@@ -1531,7 +1775,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn unsupported_pad_length(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_if_eos!(self, context);
 
         self.bit_data32a |= get_u8!(context) as u32;
@@ -1548,9 +1792,7 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn unsupported_data(&mut self, handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
-        exit_if_eos!(self, context);
-
+    -> Result<ParserValue, ErrorKind<E>> {
         parse_payload_data!(
             self,
             handler,
@@ -1565,13 +1807,13 @@ impl<'a, T: HttpHandler + 'a> Parser<'a, T> {
 
     #[inline]
     fn dead(&mut self, _handler: &mut T, _context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_error!(Dead);
     }
 
     #[inline]
     fn finished(&mut self, _handler: &mut T, context: &mut ByteStream)
-    -> Result<ParserValue, ParserError> {
+    -> Result<ParserValue, ErrorKind<E>> {
         exit_finished!(self, context);
     }
 }
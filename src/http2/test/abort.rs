@@ -0,0 +1,149 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+// | Author: Sean Kerr <sean@code-box.org>                                                         |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::{ Action, Success };
+use http2::{ ErrorKind,
+             HttpHandler,
+             Parser,
+             ParserError,
+             ParserState };
+
+#[derive(Clone,Copy,Debug,PartialEq)]
+struct FlowControl(u32);
+
+struct X {
+    max_data: usize
+}
+
+impl HttpHandler<FlowControl> for X {
+    fn on_data(&mut self, data: &[u8], _finished: bool) -> Action<FlowControl> {
+        if data.len() > self.max_data {
+            Action::Abort(FlowControl(data.len() as u32))
+        } else {
+            Action::Continue
+        }
+    }
+
+    fn on_window_update(&mut self, size_increment: u32) -> Action<FlowControl> {
+        if size_increment == 0 {
+            Action::Abort(FlowControl(0))
+        } else {
+            Action::Continue
+        }
+    }
+}
+
+fn data_frame(data: &[u8]) -> Vec<u8> {
+    let mut v = Vec::new();
+
+    // frame payload length and type
+    pack_u32!(v, ((data.len() as u32) << 8) | 0x0);
+
+    // frame flags
+    pack_u8!(v, 0);
+
+    // frame reserved bit and stream id
+    pack_u32!(v, 1);
+
+    pack_bytes!(v, data);
+
+    v
+}
+
+fn window_update_frame(size_increment: u32) -> Vec<u8> {
+    let mut v = Vec::new();
+
+    // frame payload length and type
+    pack_u32!(v, (4 << 8) | 0x8);
+
+    // frame flags
+    pack_u8!(v, 0);
+
+    // frame reserved bit and stream id
+    pack_u32!(v, 0);
+
+    // increment size
+    pack_u32!(v, size_increment);
+
+    v
+}
+
+#[test]
+fn abort_data() {
+    let mut h = X{ max_data: 4 };
+    let mut p = Parser::new();
+
+    match p.resume(&mut h, &data_frame(b"Data")) {
+        Ok(Success::Eos(13)) => {},
+        _ => panic!()
+    }
+
+    match p.resume(&mut h, &data_frame(b"Data!")).map_err(ParserError::into_kind) {
+        Err(ErrorKind::Handler(FlowControl(5))) => {},
+        _ => panic!()
+    }
+
+    assert_eq!(p.state(), ParserState::Dead);
+
+    match p.resume(&mut h, &data_frame(b"Data")).map_err(ParserError::into_kind) {
+        Err(ErrorKind::Dead) => {},
+        _ => panic!()
+    }
+}
+
+#[test]
+fn abort_window_update() {
+    let mut h = X{ max_data: 4 };
+    let mut p = Parser::new();
+
+    match p.resume(&mut h, &window_update_frame(0)).map_err(ParserError::into_kind) {
+        Err(ErrorKind::Handler(FlowControl(0))) => {},
+        _ => panic!()
+    }
+
+    assert_eq!(p.state(), ParserState::Dead);
+}
+
+#[test]
+fn format() {
+    assert_eq!(
+        format!("{:?}", ErrorKind::Handler(FlowControl(0))),
+        "<ErrorKind::Handler: FlowControl(0)>"
+    );
+}
+
+#[test]
+fn offset() {
+    let mut h = X{ max_data: 4 };
+    let mut p = Parser::new();
+
+    match p.resume(&mut h, &data_frame(b"Data")) {
+        Ok(Success::Eos(13)) => {},
+        _ => panic!()
+    }
+
+    match p.resume(&mut h, &data_frame(b"Data!")) {
+        Err(error) => {
+            assert_eq!(error.kind(), &ErrorKind::Handler(FlowControl(5)));
+            assert_eq!(error.offset(), 27);
+            assert_eq!(error.excerpt_index(), 14);
+            assert_eq!(&error.excerpt()[9..], b"Data!");
+        },
+        _ => panic!()
+    }
+}
@@ -0,0 +1,203 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+// | Author: Sean Kerr <sean@code-box.org>                                                         |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Success;
+use http2::{ ErrorKind,
+             FrameType,
+             Parser,
+             ParserError,
+             ParserState };
+
+use http2::test::*;
+
+fn frame(frame_type: u8, flags: u8, payload: &[u8]) -> Vec<u8> {
+    let mut v = Vec::new();
+
+    // frame payload length and type
+    pack_u32!(v, ((payload.len() as u32) << 8) | frame_type as u32);
+
+    // frame flags
+    pack_u8!(v, flags);
+
+    // frame reserved bit and stream id
+    pack_u32!(v, 1);
+
+    pack_bytes!(v, payload);
+
+    v
+}
+
+#[test]
+fn after_dead() {
+    let mut h = DebugHandler::new();
+    let mut p = Parser::new();
+
+    p.resume(&mut h, &frame(0x0, 0, b"Data")[..11]).unwrap();
+
+    assert!(p.finish(&mut h).is_err());
+
+    assert_eq!(
+        p.finish(&mut h).map_err(ParserError::into_kind),
+        Err(ErrorKind::Dead)
+    );
+}
+
+#[test]
+fn empty() {
+    let mut h = DebugHandler::new();
+    let mut p = Parser::new();
+
+    assert_eq!(p.finish(&mut h), Ok(Success::Finished(0)));
+    assert_eq!(p.state(), ParserState::Finished);
+}
+
+#[test]
+fn frame_boundary() {
+    let mut h = DebugHandler::new();
+    let mut p = Parser::new();
+    let mut v = frame(0x0, 0, b"Data");
+
+    v.extend_from_slice(&frame(0x4, 0, b"\x00\x01\x00\x00\x10\x00"));
+    v.extend_from_slice(&frame(0x8, 0, b"\x00\x00\x00\x01"));
+    v.extend_from_slice(&frame(0x0, 0x1, b""));
+
+    assert_eq!(p.resume(&mut h, &v), Ok(Success::Eos(v.len())));
+    assert!(h.data_data_finished);
+    assert_eq!(p.finish(&mut h), Ok(Success::Finished(0)));
+    assert_eq!(p.state(), ParserState::Finished);
+}
+
+#[test]
+fn settings_ack() {
+    let mut h = DebugHandler::new();
+    let mut p = Parser::new();
+    let v     = b"\x00\x00\x00\x04\x01\x00\x00\x00\x00";
+
+    assert_eq!(p.resume(&mut h, v), Ok(Success::Eos(v.len())));
+    assert_eq!(p.finish(&mut h), Ok(Success::Finished(0)));
+    assert_eq!(p.state(), ParserState::Finished);
+}
+
+#[test]
+fn truncated_frame_header() {
+    let mut h = DebugHandler::new();
+    let mut p = Parser::new();
+
+    assert_eq!(
+        p.resume(&mut h, &frame(0x0, 0, b"Data")[..2]),
+        Ok(Success::Eos(2))
+    );
+
+    match p.finish(&mut h) {
+        Err(error) => {
+            assert_eq!(error.kind(), &ErrorKind::TruncatedFrameHeader(7));
+            assert_eq!(error.offset(), 2);
+            assert_eq!(error.state(), ParserState::FrameLength3);
+        },
+        _ => panic!()
+    }
+
+    assert_eq!(p.state(), ParserState::Dead);
+}
+
+#[test]
+fn truncated_frame_header_after_type() {
+    let mut h = DebugHandler::new();
+    let mut p = Parser::new();
+
+    assert_eq!(
+        p.resume(&mut h, &frame(0x0, 0, b"Data")[..6]),
+        Ok(Success::Eos(6))
+    );
+
+    assert_eq!(
+        p.finish(&mut h).map_err(ParserError::into_kind),
+        Err(ErrorKind::TruncatedFrame(FrameType::Data, 7))
+    );
+}
+
+#[test]
+fn truncated_padding() {
+    let mut h = DebugHandler::new();
+    let mut p = Parser::new();
+    let v     = frame(0x0, 0x8, b"\x04DataXXXX");
+
+    assert_eq!(
+        p.resume(&mut h, &v[..v.len() - 3]),
+        Ok(Success::Eos(v.len() - 3))
+    );
+
+    assert_eq!(
+        p.finish(&mut h).map_err(ParserError::into_kind),
+        Err(ErrorKind::TruncatedFrame(FrameType::Data, 3))
+    );
+}
+
+#[test]
+fn truncated_payload() {
+    let mut h = DebugHandler::new();
+    let mut p = Parser::new();
+    let mut v = frame(0x4, 0, b"\x00\x01\x00\x00\x10\x00");
+
+    v.extend_from_slice(&frame(0x7, 0, b"\x00\x00\x00\x01\x00\x00\x00\x00debug"));
+
+    assert_eq!(
+        p.resume(&mut h, &v[..v.len() - 7]),
+        Ok(Success::Eos(v.len() - 7))
+    );
+
+    match p.finish(&mut h) {
+        Err(error) => {
+            assert_eq!(error.kind(), &ErrorKind::TruncatedFrame(FrameType::GoAway, 7));
+            assert_eq!(error.offset(), v.len() - 7);
+
+            assert_eq!(
+                format!("{}", error.kind()),
+                "<ErrorKind::TruncatedFrame: <FrameType::GoAway>, 7>"
+            );
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn truncated_payload_split() {
+    let mut h = DebugHandler::new();
+    let mut p = Parser::new();
+    let v     = frame(0x0, 0, b"Hello, world!");
+
+    for byte in v[..15].iter() {
+        assert_eq!(p.resume(&mut h, &[*byte]), Ok(Success::Eos(1)));
+    }
+
+    assert_eq!(
+        p.finish(&mut h).map_err(ParserError::into_kind),
+        Err(ErrorKind::TruncatedFrame(FrameType::Data, 7))
+    );
+}
+
+#[test]
+fn unsupported_empty() {
+    let mut h = DebugHandler::new();
+    let mut p = Parser::new();
+    let v     = frame(0xFF, 0, b"");
+
+    assert_eq!(p.resume(&mut h, &v), Ok(Success::Eos(v.len())));
+    assert_eq!(p.finish(&mut h), Ok(Success::Finished(0)));
+    assert_eq!(p.state(), ParserState::Finished);
+}
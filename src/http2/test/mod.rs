@@ -16,6 +16,7 @@
 // | Author: Sean Kerr <sean@code-box.org>                                                         |
 // +-----------------------------------------------------------------------------------------------+
 
+use fsm::Action;
 use http2::*;
 
 macro_rules! pack_bytes {
@@ -178,7 +179,7 @@ impl DebugHandler {
 }
 
 impl HttpHandler for DebugHandler {
-    fn on_data(&mut self, data: &[u8], finished: bool) -> bool {
+    fn on_data(&mut self, data: &[u8], finished: bool) -> Action {
         println!(
             "on_data [{}, {}]: {:?}",
             data.len(),
@@ -188,11 +189,11 @@ impl HttpHandler for DebugHandler {
 
         self.data_data.extend_from_slice(data);
         self.data_data_finished = finished;
-        true
+        Action::Continue
     }
 
     fn on_frame_format(&mut self, payload_length: u32, frame_type: u8, flags: u8, stream_id: u32)
-    -> bool {
+    -> Action {
         println!(
             "on_frame_format: payload_length={}, frame_type={}, flags={}, stream_id={}",
             payload_length,
@@ -205,10 +206,10 @@ impl HttpHandler for DebugHandler {
         self.frame_payload_length = payload_length;
         self.frame_stream_id      = stream_id;
         self.frame_type           = frame_type;
-        true
+        Action::Continue
     }
 
-    fn on_go_away(&mut self, stream_id: u32, error_code: u32) -> bool {
+    fn on_go_away(&mut self, stream_id: u32, error_code: u32) -> Action {
         println!(
             "on_go_away: stream_id={}, error_code={}",
             stream_id,
@@ -217,10 +218,10 @@ impl HttpHandler for DebugHandler {
 
         self.go_away_error_code = error_code;
         self.go_away_stream_id  = stream_id;
-        true
+        Action::Continue
     }
 
-    fn on_go_away_debug_data(&mut self, data: &[u8], finished: bool) -> bool {
+    fn on_go_away_debug_data(&mut self, data: &[u8], finished: bool) -> Action {
         println!(
             "on_go_away_debug_data [{}, {}]: {:?}",
             data.len(),
@@ -230,10 +231,10 @@ impl HttpHandler for DebugHandler {
 
         self.go_away_debug_data.extend_from_slice(data);
         self.go_away_debug_data_finished = finished;
-        true
+        Action::Continue
     }
 
-    fn on_headers(&mut self, exclusive: bool, stream_id: u32, weight: u8) -> bool {
+    fn on_headers(&mut self, exclusive: bool, stream_id: u32, weight: u8) -> Action {
         println!(
             "on_headers: exclusive={}, stream_id={}, weight={}",
             exclusive,
@@ -244,10 +245,10 @@ impl HttpHandler for DebugHandler {
         self.headers_exclusive = exclusive;
         self.headers_stream_id = stream_id;
         self.headers_weight    = weight;
-        true
+        Action::Continue
     }
 
-    fn on_headers_fragment(&mut self, fragment: &[u8], finished: bool) -> bool {
+    fn on_headers_fragment(&mut self, fragment: &[u8], finished: bool) -> Action {
         println!(
             "on_headers_fragment [{}, {}]: {:?}",
             fragment.len(),
@@ -257,10 +258,10 @@ impl HttpHandler for DebugHandler {
 
         self.headers_data.extend_from_slice(fragment);
         self.headers_data_finished = finished;
-        true
+        Action::Continue
     }
 
-    fn on_ping(&mut self, data: &[u8], finished: bool) -> bool {
+    fn on_ping(&mut self, data: &[u8], finished: bool) -> Action {
         println!(
             "on_ping [{}, {}]: {:?}",
             data.len(),
@@ -270,10 +271,10 @@ impl HttpHandler for DebugHandler {
 
         self.ping_data.extend_from_slice(data);
         self.ping_data_finished = finished;
-        true
+        Action::Continue
     }
 
-    fn on_priority(&mut self, exclusive: bool, stream_id: u32, weight: u8) -> bool {
+    fn on_priority(&mut self, exclusive: bool, stream_id: u32, weight: u8) -> Action {
         println!(
             "on_priority: exclusive={}, stream_id={}, weight={}",
             exclusive,
@@ -284,30 +285,30 @@ impl HttpHandler for DebugHandler {
         self.priority_exclusive = exclusive;
         self.priority_stream_id = stream_id;
         self.priority_weight    = weight;
-        true
+        Action::Continue
     }
 
-    fn on_push_promise(&mut self, stream_id: u32) -> bool {
+    fn on_push_promise(&mut self, stream_id: u32) -> Action {
         println!(
             "on_push_promise: stream_id={}",
             stream_id
         );
 
         self.push_promise_stream_id = stream_id;
-        true
+        Action::Continue
     }
 
-    fn on_rst_stream(&mut self, error_code: u32) -> bool {
+    fn on_rst_stream(&mut self, error_code: u32) -> Action {
         println!(
             "on_rst_stream: error_code={}",
             error_code
         );
 
         self.rst_stream_error_code = error_code;
-        true
+        Action::Continue
     }
 
-    fn on_settings(&mut self, id: u16, value: u32) -> bool {
+    fn on_settings(&mut self, id: u16, value: u32) -> Action {
         println!(
             "on_settings: id={}, value={}",
             id,
@@ -316,10 +317,10 @@ impl HttpHandler for DebugHandler {
 
         self.settings_id    = id;
         self.settings_value = value;
-        true
+        Action::Continue
     }
 
-    fn on_unsupported(&mut self, data: &[u8], finished: bool) -> bool {
+    fn on_unsupported(&mut self, data: &[u8], finished: bool) -> Action {
         println!(
             "on_unsupported [{}, {}]: {:?}",
             data.len(),
@@ -329,25 +330,27 @@ impl HttpHandler for DebugHandler {
 
         self.unsupported_data.extend_from_slice(data);
         self.unsupported_data_finished = finished;
-        true
+        Action::Continue
     }
 
-    fn on_window_update(&mut self, size_increment: u32) -> bool {
+    fn on_window_update(&mut self, size_increment: u32) -> Action {
         println!(
             "on_window_update: size_increment={}",
             size_increment
         );
 
         self.window_update_size_increment = size_increment;
-        true
+        Action::Continue
     }
 }
 
 // -------------------------------------------------------------------------------------------------
 
 // test mods
+mod abort;
 mod continuation;
 mod data;
+mod finish;
 mod frame_format;
 mod go_away;
 mod headers;
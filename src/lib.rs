@@ -26,6 +26,7 @@ pub mod fsm;
 mod collect;
 
 pub mod byte;
+mod excerpt;
 pub mod http1;
 pub mod http2;
 pub mod util;
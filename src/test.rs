@@ -37,24 +37,24 @@ pub fn header_field_vec() -> Vec<u8> {
 }
 
 pub fn non_control_vec() -> Vec<u8> {
-    (0..255).filter(|&x| x > 0x1F && x != 0x7F).collect::<Vec<u8>>()
+    (0..=255).filter(|&x| x > 0x1F && x != 0x7F).collect::<Vec<u8>>()
 }
 
 pub fn non_digit_vec() -> Vec<u8> {
-    (0..255).filter(|&x| !is_digit!(x)).collect::<Vec<u8>>()
+    (0..=255).filter(|&x| !is_digit!(x)).collect::<Vec<u8>>()
 }
 
 pub fn non_token_vec() -> Vec<u8> {
-    (0..255).filter(|&x| !is_token(x))
-            .collect::<Vec<u8>>()
+    (0..=255).filter(|&x| !is_token(x))
+             .collect::<Vec<u8>>()
 }
 
 pub fn non_visible_7bit_vec() -> Vec<u8> {
-    (0..255).filter(|&x| is_not_visible_7bit!(x)).collect::<Vec<u8>>()
+    (0..=255).filter(|&x| is_not_visible_7bit!(x)).collect::<Vec<u8>>()
 }
 
 pub fn quoted_header_field_vec() -> Vec<u8> {
-    (0..255).filter(|&x| is_quoted_header_field(x)).collect::<Vec<u8>>()
+    (0..=255).filter(|&x| is_quoted_header_field(x)).collect::<Vec<u8>>()
 }
 
 pub fn token_vec() -> Vec<u8> {
@@ -16,9 +16,10 @@
 
 use byte_slice::ByteStream;
 
-use std::fmt;
+use std::{ error, fmt };
 
 /// Decoding errors.
+#[derive(Clone,Copy,PartialEq)]
 pub enum DecodeError {
     /// Invalid byte.
     Byte(u8),
@@ -61,6 +62,9 @@ impl fmt::Display for DecodeError {
     }
 }
 
+impl error::Error for DecodeError {
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// Decode URL encoded data.
@@ -0,0 +1,295 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use std::{ error, fmt };
+use std::fmt::Write;
+
+/// Encoding errors.
+#[derive(Clone,Copy,PartialEq)]
+pub enum EncodeError {
+    /// Empty name.
+    EmptyName
+}
+
+impl EncodeError {
+    /// Format this for debug and display purposes.
+    fn format(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EncodeError::EmptyName => {
+                write!(
+                    formatter,
+                    "<EncodeError::EmptyName>"
+                )
+            }
+        }
+    }
+}
+
+impl fmt::Debug for EncodeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.format(formatter)
+    }
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.format(formatter)
+    }
+}
+
+impl error::Error for EncodeError {
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Percent-encode sets, as described by the
+/// [WHATWG URL Standard](https://url.spec.whatwg.org/#percent-encoded-bytes).
+///
+/// Each set also encodes `%` and `+`, since `decode()` supplies `+` as a space, as well as every
+/// byte that isn't visible 7-bit, so that encoded data can always be decoded. Only UTF-8 data is
+/// encoded, so the decoded data is always a valid `String`.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum EncodeSet {
+    /// The `application/x-www-form-urlencoded` percent-encode set, which leaves alphanumerics,
+    /// `*`, `-`, `.`, and `_` as-is, and encodes space as `+`.
+    Form,
+
+    /// The path percent-encode set, which leaves `/` as-is.
+    Path,
+
+    /// The path percent-encode set along with `/`, for a single path segment.
+    PathSegment,
+
+    /// The query percent-encode set.
+    Query
+}
+
+impl EncodeSet {
+    /// Indicates that `byte` is percent-encoded.
+    pub fn contains(&self, byte: u8) -> bool {
+        if !is_visible_7bit!(byte) || byte == b'%' || byte == b'+' {
+            return true;
+        }
+
+        match *self {
+            EncodeSet::Form => {
+                !(byte.is_ascii_alphanumeric()
+                  || byte == b'*' || byte == b'-' || byte == b'.' || byte == b'_')
+            },
+            EncodeSet::Path => {
+                is_query(byte) || is_path(byte)
+            },
+            EncodeSet::PathSegment => {
+                is_query(byte) || is_path(byte) || byte == b'/'
+            },
+            EncodeSet::Query => {
+                is_query(byte)
+            }
+        }
+    }
+}
+
+/// Indicates that visible 7-bit `byte` is within the path percent-encode set, but not within the
+/// query percent-encode set.
+fn is_path(byte: u8) -> bool {
+    byte == b'?' || byte == b'^' || byte == b'`' || byte == b'{' || byte == b'}'
+}
+
+/// Indicates that visible 7-bit `byte` is within the query percent-encode set.
+fn is_query(byte: u8) -> bool {
+    byte == b'"' || byte == b'#' || byte == b'<' || byte == b'>'
+}
+
+/// Append the encoded `data` to `encoded`.
+///
+/// When `pair` is `true`, the bytes that separate names and values are encoded as well.
+fn encode_into(encoded: &mut String, data: &str, set: EncodeSet, pair: bool) {
+    for byte in data.as_bytes() {
+        if *byte == b' ' && set == EncodeSet::Form {
+            encoded.push('+');
+        } else if set.contains(*byte)
+               || (pair && (*byte == b'&' || *byte == b';' || *byte == b'=')) {
+            // writing to a string cannot fail
+            let _ = write!(encoded, "%{:02X}", byte);
+        } else {
+            encoded.push(*byte as char);
+        }
+    }
+}
+
+/// Encode data.
+///
+/// # Arguments
+///
+/// **`data`**
+///
+/// The data.
+///
+/// **`set`**
+///
+/// The percent-encode set.
+///
+/// # Returns
+///
+/// **`String`**
+///
+/// The encoded string.
+///
+/// # Examples
+///
+/// ```
+/// use http_box::util::{ self, EncodeSet };
+///
+/// assert_eq!(util::encode("fancy url/encoded data", EncodeSet::Form),
+///            "fancy+url%2Fencoded+data");
+///
+/// assert_eq!(util::encode("/fancy url/encoded data", EncodeSet::Path),
+///            "/fancy%20url/encoded%20data");
+/// ```
+pub fn encode(data: &str, set: EncodeSet) -> String {
+    let mut encoded = String::with_capacity(data.len());
+
+    encode_into(&mut encoded, data, set, false);
+    encoded
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// URL encoder.
+///
+/// This allows you to build URL encoded data, such as an `application/x-www-form-urlencoded`
+/// body or a query string, by appending `(name, value)` pairs. Names and values are encoded with
+/// the `EncodeSet::Form` set by default, and `&`, `;`, and `=` are always encoded, so that
+/// the encoded data can be decoded by `QueryIterator`, or by a `Parser` that has been initialized
+/// with `Parser::init_url_encoded()`.
+///
+/// # Examples
+///
+/// ```
+/// use http_box::util::{ EncodeSet, UrlEncoder };
+///
+/// let mut encoder = UrlEncoder::new();
+///
+/// encoder.append("name", "Bob Smith").unwrap()
+///        .append("tags", "a&b").unwrap();
+///
+/// assert_eq!(encoder.as_str(), "name=Bob+Smith&tags=a%26b");
+///
+/// let mut encoder = UrlEncoder::with_encode_set(EncodeSet::Query);
+///
+/// encoder.append("q", "a+b c").unwrap();
+///
+/// assert_eq!(encoder.as_str(), "q=a%2Bb%20c");
+/// ```
+pub struct UrlEncoder {
+    /// Encoded data.
+    encoded: String,
+
+    /// Percent-encode set.
+    set: EncodeSet
+}
+
+impl UrlEncoder {
+    /// Create a new `UrlEncoder` that uses the `EncodeSet::Form` set.
+    pub fn new() -> UrlEncoder {
+        UrlEncoder::with_encode_set(EncodeSet::Form)
+    }
+
+    /// Create a new `UrlEncoder` that uses `set`.
+    ///
+    /// # Arguments
+    ///
+    /// **`set`**
+    ///
+    /// The percent-encode set.
+    pub fn with_encode_set(set: EncodeSet) -> UrlEncoder {
+        UrlEncoder{
+            encoded: String::new(),
+            set
+        }
+    }
+
+    /// Append a `(name, value)` pair.
+    ///
+    /// # Arguments
+    ///
+    /// **`name`**
+    ///
+    /// The name, which must not be empty.
+    ///
+    /// **`value`**
+    ///
+    /// The value.
+    ///
+    /// # Errors
+    ///
+    /// - [`EncodeError::EmptyName`](enum.EncodeError.html#variant.EmptyName)
+    pub fn append<N, V>(&mut self, name: N, value: V) -> Result<&mut Self, EncodeError>
+    where N: AsRef<str>, V: AsRef<str> {
+        if name.as_ref().is_empty() {
+            // URL encoded data cannot begin with `=`, and a pair without a name is meaningless
+            return Err(EncodeError::EmptyName);
+        }
+
+        if !self.encoded.is_empty() {
+            self.encoded.push('&');
+        }
+
+        encode_into(&mut self.encoded, name.as_ref(), self.set, true);
+
+        self.encoded.push('=');
+
+        encode_into(&mut self.encoded, value.as_ref(), self.set, true);
+
+        Ok(self)
+    }
+
+    /// Retrieve the encoded data.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.encoded.as_bytes()
+    }
+
+    /// Retrieve the encoded data.
+    pub fn as_str(&self) -> &str {
+        &self.encoded
+    }
+
+    /// Remove all pairs.
+    pub fn clear(&mut self) {
+        self.encoded.clear();
+    }
+
+    /// Consume this, and retrieve the encoded data.
+    pub fn into_string(self) -> String {
+        self.encoded
+    }
+
+    /// Indicates that no pairs have been appended.
+    pub fn is_empty(&self) -> bool {
+        self.encoded.is_empty()
+    }
+
+    /// Retrieve the encoded length.
+    pub fn len(&self) -> usize {
+        self.encoded.len()
+    }
+}
+
+impl Default for UrlEncoder {
+    fn default() -> UrlEncoder {
+        UrlEncoder::new()
+    }
+}
@@ -17,9 +17,10 @@
 use byte::{ is_header_field, is_quoted_header_field, is_token };
 
 use byte_slice::ByteStream;
-use std::fmt;
+use std::{ error, fmt };
 
 /// Field errors.
+#[derive(Clone,Copy,PartialEq)]
 pub enum FieldError {
     /// Invalid field name.
     Name(u8),
@@ -62,6 +63,9 @@ impl fmt::Display for FieldError {
     }
 }
 
+impl error::Error for FieldError {
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// Header field iterator.
@@ -237,12 +241,7 @@ impl<'a> Iterator for FieldIterator<'a> {
                                     submit_name_value!(self)
                                 );
 
-                                if bs_available!(self.context) == 0 {
-                                    submit_name_value!(self);
-                                }
-
-                                bs_next!(self.context);
-
+                                // the byte following the linear space has been consumed
                                 if self.context.byte == self.delimiter {
                                     submit_name_value!(self);
                                 }
@@ -78,6 +78,7 @@ macro_rules! submit_name_value {
 // -------------------------------------------------------------------------------------------------
 
 mod decode;
+mod encode;
 mod field;
 mod query;
 
@@ -85,5 +86,6 @@ mod query;
 mod test;
 
 pub use util::decode::{ DecodeError, decode };
+pub use util::encode::{ EncodeError, EncodeSet, UrlEncoder, encode };
 pub use util::field::{ FieldError, FieldIterator };
 pub use util::query::{ QueryError, QueryIterator };
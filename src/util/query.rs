@@ -15,9 +15,10 @@
 // +-----------------------------------------------------------------------------------------------+
 
 use byte_slice::ByteStream;
-use std::fmt;
+use std::{ error, fmt };
 
 /// Query errors.
+#[derive(Clone,Copy,PartialEq)]
 pub enum QueryError {
     /// Invalid query name.
     Name(u8),
@@ -60,6 +61,9 @@ impl fmt::Display for QueryError {
     }
 }
 
+impl error::Error for QueryError {
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// Query iterator.
@@ -200,11 +204,13 @@ impl<'a> Iterator for QueryIterator<'a> {
                                 self.context,
                                 QueryError::Name
                             ));
-                        } else if bs_has_bytes!(self.context, 1) {
-                            bs_next!(self.context);
-                        }
+                        } else {
+                            if bs_has_bytes!(self.context, 1) {
+                                bs_next!(self.context);
+                            }
 
-                        submit_error!(self, QueryError::Name);
+                            submit_error!(self, QueryError::Name);
+                        }
                     },
                     b'+' => {
                         self.name.push(b' ');
@@ -0,0 +1,177 @@
+// +-----------------------------------------------------------------------------------------------+
+// | Copyright 2016 Sean Kerr                                                                      |
+// |                                                                                               |
+// | Licensed under the Apache License, Version 2.0 (the "License");                               |
+// | you may not use this file except in compliance with the License.                              |
+// | You may obtain a copy of the License at                                                       |
+// |                                                                                               |
+// |  http://www.apache.org/licenses/LICENSE-2.0                                                   |
+// |                                                                                               |
+// | Unless required by applicable law or agreed to in writing, software                           |
+// | distributed under the License is distributed on an "AS IS" BASIS,                             |
+// | WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.                      |
+// | See the License for the specific language governing permissions and                           |
+// | limitations under the License.                                                                |
+// +-----------------------------------------------------------------------------------------------+
+
+use fsm::Success;
+use http1::{ Collector, Parser };
+use util::*;
+
+const SETS: [EncodeSet; 4] = [
+    EncodeSet::Form,
+    EncodeSet::Path,
+    EncodeSet::PathSegment,
+    EncodeSet::Query
+];
+
+fn all_chars() -> String {
+    (0..128).map(|x| x as u8 as char).chain("\u{E9}\u{20AC}\u{1F600}".chars()).collect()
+}
+
+#[test]
+fn encode_form() {
+    assert_eq!(
+        encode("Az09*-._ ~!'()&=+%;/", EncodeSet::Form),
+        "Az09*-._+%7E%21%27%28%29%26%3D%2B%25%3B%2F"
+    );
+}
+
+#[test]
+fn encode_path() {
+    assert_eq!(
+        encode("/a b/?#<>\"^`{}+%~!&=;", EncodeSet::Path),
+        "/a%20b/%3F%23%3C%3E%22%5E%60%7B%7D%2B%25~!&=;"
+    );
+
+    assert_eq!(
+        encode("/a b/", EncodeSet::PathSegment),
+        "%2Fa%20b%2F"
+    );
+}
+
+#[test]
+fn encode_query() {
+    assert_eq!(
+        encode("a=b c&d/?#<>\"^`{}+%~!", EncodeSet::Query),
+        "a=b%20c&d/?%23%3C%3E%22^`{}%2B%25~!"
+    );
+}
+
+#[test]
+fn encode_non_visible() {
+    for set in SETS.iter() {
+        assert_eq!(
+            encode("\x00\r\n\x7F\u{E9}", *set),
+            "%00%0D%0A%7F%C3%A9"
+        );
+    }
+}
+
+#[test]
+fn encode_decode() {
+    for set in SETS.iter() {
+        match decode(encode(&all_chars(), *set).as_bytes()) {
+            Ok(s) => assert_eq!(s, all_chars()),
+            _ => panic!()
+        }
+    }
+}
+
+#[test]
+fn url_encoder() {
+    let mut e = UrlEncoder::new();
+
+    assert!(e.is_empty());
+
+    e.append("name", "Bob Smith").unwrap()
+     .append("a&b;c=d", "1+1=2 %").unwrap()
+     .append("empty", "").unwrap();
+
+    assert_eq!(
+        e.as_str(),
+        "name=Bob+Smith&a%26b%3Bc%3Dd=1%2B1%3D2+%25&empty="
+    );
+
+    assert_eq!(e.len(), e.as_bytes().len());
+
+    e.clear();
+
+    assert!(e.is_empty());
+
+    let mut e = UrlEncoder::with_encode_set(EncodeSet::Query);
+
+    e.append("path", "/a b/?").unwrap().append("x", "a&b").unwrap();
+
+    assert_eq!(
+        e.into_string(),
+        "path=/a%20b/?&x=a%26b"
+    );
+}
+
+#[test]
+fn url_encoder_empty_name_error() {
+    let mut e = UrlEncoder::new();
+
+    assert_eq!(e.append("", "value").err(), Some(EncodeError::EmptyName));
+    assert!(e.is_empty());
+
+    e.append("name", "value").unwrap();
+
+    assert_eq!(e.append("", "value").err(), Some(EncodeError::EmptyName));
+    assert_eq!(e.as_str(), "name=value");
+}
+
+#[test]
+fn url_encoder_query_iterator() {
+    let pairs: Vec<(String, String)> = vec![
+        ("name".to_string(), "Bob Smith".to_string()),
+        ("a&b;c=d".to_string(), "1+1=2 %".to_string()),
+        ("chars".to_string(), all_chars()),
+        ("empty".to_string(), String::new())
+    ];
+
+    for set in SETS.iter() {
+        let mut e = UrlEncoder::with_encode_set(*set);
+
+        for &(ref name, ref value) in &pairs {
+            e.append(name, value).unwrap();
+        }
+
+        let decoded: Vec<(String, String)> =
+            QueryIterator::new(e.as_bytes())
+            .map(|(name, value)| (name, value.unwrap()))
+            .collect();
+
+        assert_eq!(decoded, pairs);
+    }
+}
+
+#[test]
+fn url_encoder_parser() {
+    let mut e = UrlEncoder::new();
+
+    e.append("name", "Bob Smith").unwrap()
+     .append("a&b;c=d", "1+1=2 %").unwrap()
+     .append("chars", all_chars()).unwrap();
+
+    let mut c = Collector::new();
+    let mut p = Parser::new();
+
+    p.init_url_encoded();
+    p.set_length(e.len());
+
+    match p.resume(&mut c, e.as_bytes()) {
+        Ok(Success::Finished(length)) => assert_eq!(length, e.len()),
+        _ => panic!()
+    }
+
+    c.finish();
+
+    assert_eq!(
+        c.take_body().unwrap().fields(),
+        &[(b"name".to_vec(), b"Bob Smith".to_vec()),
+          (b"a&b;c=d".to_vec(), b"1+1=2 %".to_vec()),
+          (b"chars".to_vec(), all_chars().into_bytes())]
+    );
+}
@@ -214,6 +214,22 @@ fn quoted() {
     }
 }
 
+#[test]
+fn quoted_followed_by_field() {
+    for field in [&b"name=\"a\"; filename=\"C:\\\\b\""[..],
+                  &b"name=\"a\";filename=\"C:\\\\b\""[..],
+                  &b"name=\"a\" \t; filename=\"C:\\\\b\" "[..]].iter() {
+        let fields: Vec<(String, Option<String>)> =
+            FieldIterator::new(field, b';', false).collect();
+
+        assert_eq!(
+            fields,
+            vec![("name".to_string(), Some("a".to_string())),
+                 ("filename".to_string(), Some("C:\\b".to_string()))]
+        );
+    }
+}
+
 #[test]
 fn value_error() {
     let mut error = None;
@@ -15,5 +15,6 @@
 // +-----------------------------------------------------------------------------------------------+
 
 mod decode;
+mod encode;
 mod field_iterator;
 mod query_iterator;
@@ -22,7 +22,11 @@ fn hex() {
         b"field%201=value%201&field%202=value%202&field%203"
     );
 
+    let mut count = 0;
+
     for (n, (name, value)) in iter.enumerate() {
+        count += 1;
+
         if n == 0 {
             assert_eq!(
                 name,
@@ -55,6 +59,8 @@ fn hex() {
             );
         }
     }
+
+    assert_eq!(count, 3);
 }
 
 #[test]
@@ -1,6 +1,6 @@
 extern crate http_box;
 
-use http_box::fsm::Success;
+use http_box::fsm::{ Action, Success };
 use http_box::http1::{ HttpHandler,
                        Parser,
                        State };
@@ -19,7 +19,7 @@ struct ChunkEncodedHandler {
     pub count:      usize,
     pub data:       Vec<u8>,
     pub extensions: Vec<u8>,
-    pub length:     usize,
+    pub length:     u64,
     pub name_buf:   Vec<u8>,
     pub state:      State,
     pub trailers:   HashMap<String, String>,
@@ -59,52 +59,52 @@ impl ChunkEncodedHandler {
 }
 
 impl HttpHandler for ChunkEncodedHandler {
-    fn on_chunk_begin(&mut self) -> bool {
+    fn on_chunk_begin(&mut self) -> Action {
         self.count += 1;
 
         if self.count > 1 {
             // we found a new piece of data, and it's not the first one, so force an exit
             // so we can compare
-            false
+            Action::Pause
         } else {
             // first piece of data, continue as normal
-            true
+            Action::Continue
         }
     }
 
-    fn on_chunk_data(&mut self, data: &[u8]) -> bool {
+    fn on_chunk_data(&mut self, data: &[u8]) -> Action {
         self.data.extend_from_slice(data);
 
-        true
+        Action::Continue
     }
 
-    fn on_chunk_extension_finished(&mut self) -> bool {
-        true
+    fn on_chunk_extension_finished(&mut self) -> Action {
+        Action::Continue
     }
 
-    fn on_chunk_extension_name(&mut self, name: &[u8]) -> bool {
+    fn on_chunk_extension_name(&mut self, name: &[u8]) -> Action {
         self.extensions.extend_from_slice(name);
 
-        true
+        Action::Continue
     }
 
-    fn on_chunk_extension_value(&mut self, value: &[u8]) -> bool {
+    fn on_chunk_extension_value(&mut self, value: &[u8]) -> Action {
         self.extensions.extend_from_slice(value);
 
-        true
+        Action::Continue
     }
 
-    fn on_chunk_extensions_finished(&mut self) -> bool {
-        true
+    fn on_chunk_extensions_finished(&mut self) -> Action {
+        Action::Continue
     }
 
-    fn on_chunk_length(&mut self, length: usize) -> bool {
+    fn on_chunk_length(&mut self, length: u64) -> Action {
         self.length = length;
 
-        true
+        Action::Continue
     }
 
-    fn on_header_name(&mut self, name: &[u8]) -> bool {
+    fn on_header_name(&mut self, name: &[u8]) -> Action {
         if self.state == State::HeaderValue {
             self.flush_trailer();
         }
@@ -112,20 +112,20 @@ impl HttpHandler for ChunkEncodedHandler {
         self.name_buf.extend_from_slice(name);
 
         self.state = State::HeaderName;
-        true
+        Action::Continue
     }
 
-    fn on_header_value(&mut self, value: &[u8]) -> bool {
+    fn on_header_value(&mut self, value: &[u8]) -> Action {
         self.value_buf.extend_from_slice(value);
 
         self.state = State::HeaderValue;
-        true
+        Action::Continue
     }
 
-    fn on_headers_finished(&mut self) -> bool {
+    fn on_headers_finished(&mut self) -> Action {
         self.flush_trailer();
 
-        true
+        Action::Continue
     }
 }
 
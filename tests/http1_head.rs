@@ -1,5 +1,6 @@
 extern crate http_box;
 
+use http_box::fsm::Action;
 use http_box::http1::{ HttpHandler,
                        Parser,
                        State };
@@ -57,7 +58,7 @@ impl Handler {
 }
 
 impl HttpHandler for Handler {
-    fn on_header_name(&mut self, name: &[u8]) -> bool {
+    fn on_header_name(&mut self, name: &[u8]) -> Action {
         if self.state == State::HeaderValue {
             self.flush_header();
         }
@@ -65,39 +66,39 @@ impl HttpHandler for Handler {
         self.name_buf.extend_from_slice(name);
 
         self.state = State::HeaderName;
-        true
+        Action::Continue
     }
 
-    fn on_header_value(&mut self, value: &[u8]) -> bool {
+    fn on_header_value(&mut self, value: &[u8]) -> Action {
         self.value_buf.extend_from_slice(value);
 
         self.state = State::HeaderValue;
-        true
+        Action::Continue
     }
 
-    fn on_headers_finished(&mut self) -> bool {
+    fn on_headers_finished(&mut self) -> Action {
         self.flush_header();
 
-        true
+        Action::Continue
     }
 
-    fn on_method(&mut self, method: &[u8]) -> bool {
+    fn on_method(&mut self, method: &[u8]) -> Action {
         self.method.extend_from_slice(method);
 
-        true
+        Action::Continue
     }
 
-    fn on_url(&mut self, url: &[u8]) -> bool {
+    fn on_url(&mut self, url: &[u8]) -> Action {
         self.url.extend_from_slice(url);
 
-        true
+        Action::Continue
     }
 
-    fn on_version(&mut self, major: u16, minor: u16) -> bool {
+    fn on_version(&mut self, major: u16, minor: u16) -> Action {
         self.version_major = major;
         self.version_minor = minor;
 
-        true
+        Action::Continue
     }
 }
 
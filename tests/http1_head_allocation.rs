@@ -0,0 +1,73 @@
+extern crate http_box;
+
+use http_box::http1::{ RequestHead,
+                       ResponseHead };
+
+use std::alloc::{ GlobalAlloc,
+                  Layout,
+                  System };
+use std::sync::atomic::{ AtomicUsize,
+                         Ordering };
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations<F: FnOnce()>(function: F) -> usize {
+    let start = ALLOCATIONS.load(Ordering::SeqCst);
+
+    function();
+
+    ALLOCATIONS.load(Ordering::SeqCst) - start
+}
+
+// all parsing is tested within a single test, because allocations are counted across threads
+#[test]
+fn head_parsing_does_not_allocate() {
+    let request = b"POST /form HTTP/1.1\r\n\
+                    Host: localhost\r\n\
+                    Connection: keep-alive, Upgrade\r\n\
+                    Content-Length: 11\r\n\
+                    Content-Type: application/x-www-form-urlencoded\r\n\
+                    Trailer: Expires\r\n\
+                    Upgrade: websocket\r\n\
+                    \r\n";
+
+    assert_eq!(allocations(|| { RequestHead::parse(request).unwrap(); }), 0);
+
+    let request = b"EXTENSION / HTTP/1.1\r\n\
+                    Transfer-Encoding: gzip, chunked\r\n\
+                    \r\n";
+
+    assert_eq!(allocations(|| { RequestHead::parse(request).unwrap(); }), 0);
+
+    let response = b"HTTP/1.1 200 OK\r\n\
+                     Connection: close\r\n\
+                     Content-Length: 5\r\n\
+                     Content-Length: 5\r\n\
+                     Content-Type: text/plain\r\n\
+                     \r\n";
+
+    assert_eq!(allocations(|| { ResponseHead::parse(response).unwrap(); }), 0);
+}
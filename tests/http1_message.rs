@@ -0,0 +1,98 @@
+extern crate http_box;
+
+use http_box::fsm::{ Action, Success };
+use http_box::http1::{ HttpHandler,
+                       Parser };
+
+use std::fs::File;
+use std::io::prelude::*;
+
+struct MessageHandler {
+    pub body_finished:    bool,
+    pub headers_finished: usize,
+    pub multipart_count:  usize,
+    pub multipart_length: usize
+}
+
+impl MessageHandler {
+    pub fn new() -> MessageHandler {
+        MessageHandler{
+            body_finished:    false,
+            headers_finished: 0,
+            multipart_count:  0,
+            multipart_length: 0
+        }
+    }
+}
+
+impl HttpHandler for MessageHandler {
+    fn on_body_finished(&mut self) -> Action {
+        self.body_finished = true;
+
+        Action::Continue
+    }
+
+    fn on_headers_finished(&mut self) -> Action {
+        self.headers_finished += 1;
+
+        Action::Continue
+    }
+
+    fn on_multipart_begin(&mut self) -> Action {
+        self.multipart_count += 1;
+
+        Action::Continue
+    }
+
+    fn on_multipart_data(&mut self, data: &[u8]) -> Action {
+        self.multipart_length += data.len();
+
+        Action::Continue
+    }
+}
+
+#[test]
+fn message_multipart() {
+    let mut d = Vec::new();
+
+    File::open("tests/http1_data/multipart.dat").unwrap().read_to_end(&mut d);
+
+    let mut h = MessageHandler::new();
+    let mut p = Parser::new();
+
+    p.init_message();
+
+    // parse head and body in pieces
+    let mut s = d.as_slice();
+
+    loop {
+        let length = if s.len() > 4096 { 4096 } else { s.len() };
+
+        match p.resume(&mut h, &s[..length]) {
+            Ok(Success::Eos(length)) => {
+                s = &s[length..];
+            },
+            Ok(Success::Finished(_)) => {
+                break;
+            },
+            _ => panic!()
+        }
+    }
+
+    assert!(h.body_finished);
+
+    assert_eq!(
+        h.headers_finished,
+        5
+    );
+
+    assert_eq!(
+        h.multipart_count,
+        4
+    );
+
+    assert_eq!(
+        h.multipart_length,
+        3 + 8 + 62260 + 38310
+    );
+}
@@ -1,6 +1,6 @@
 extern crate http_box;
 
-use http_box::fsm::Success;
+use http_box::fsm::{ Action, Success };
 use http_box::http1::{ HttpHandler,
                        Parser,
                        State };
@@ -52,7 +52,7 @@ impl HeadHandler {
 }
 
 impl HttpHandler for HeadHandler {
-    fn on_header_name(&mut self, name: &[u8]) -> bool {
+    fn on_header_name(&mut self, name: &[u8]) -> Action {
         if self.state == State::HeaderValue {
             self.flush_header();
         }
@@ -60,20 +60,20 @@ impl HttpHandler for HeadHandler {
         self.name_buf.extend_from_slice(name);
 
         self.state = State::HeaderName;
-        true
+        Action::Continue
     }
 
-    fn on_header_value(&mut self, value: &[u8]) -> bool {
+    fn on_header_value(&mut self, value: &[u8]) -> Action {
         self.value_buf.extend_from_slice(value);
 
         self.state = State::HeaderValue;
-        true
+        Action::Continue
     }
 
-    fn on_headers_finished(&mut self) -> bool {
+    fn on_headers_finished(&mut self) -> Action {
         self.flush_header();
 
-        true
+        Action::Continue
     }
 }
 
@@ -127,7 +127,7 @@ impl MultipartHandler {
 }
 
 impl HttpHandler for MultipartHandler {
-    fn on_header_name(&mut self, name: &[u8]) -> bool {
+    fn on_header_name(&mut self, name: &[u8]) -> Action {
         if self.state == State::HeaderValue {
             self.flush_header();
         }
@@ -135,39 +135,39 @@ impl HttpHandler for MultipartHandler {
         self.name_buf.extend_from_slice(name);
 
         self.state = State::HeaderName;
-        true
+        Action::Continue
     }
 
-    fn on_header_value(&mut self, value: &[u8]) -> bool {
+    fn on_header_value(&mut self, value: &[u8]) -> Action {
         self.value_buf.extend_from_slice(value);
 
         self.state = State::HeaderValue;
-        true
+        Action::Continue
     }
 
-    fn on_headers_finished(&mut self) -> bool {
+    fn on_headers_finished(&mut self) -> Action {
         self.flush_header();
 
-        true
+        Action::Continue
     }
 
-    fn on_multipart_begin(&mut self) -> bool {
+    fn on_multipart_begin(&mut self) -> Action {
         self.count += 1;
 
         if self.count > 1 {
             // we found a new piece of data, and it's not the first one, so force an exit
             // so we can compare
-            false
+            Action::Pause
         } else {
             // first piece of data, continue as normal
-            true
+            Action::Continue
         }
     }
 
-    fn on_multipart_data(&mut self, data: &[u8]) -> bool {
+    fn on_multipart_data(&mut self, data: &[u8]) -> Action {
         self.data.extend_from_slice(data);
 
-        true
+        Action::Continue
     }
 }
 
@@ -1,6 +1,6 @@
 extern crate http_box;
 
-use http_box::fsm::Success;
+use http_box::fsm::{ Action, Success };
 use http_box::http1::{ HttpHandler,
                        Parser,
                        State };
@@ -55,13 +55,13 @@ impl UrlEncodedHandler {
 }
 
 impl HttpHandler for UrlEncodedHandler {
-    fn on_body_finished(&mut self) -> bool {
+    fn on_body_finished(&mut self) -> Action {
         self.flush_parameter();
 
-        true
+        Action::Continue
     }
 
-    fn on_url_encoded_name(&mut self, name: &[u8]) -> bool {
+    fn on_url_encoded_name(&mut self, name: &[u8]) -> Action {
         if self.state == State::UrlEncodedValue {
             self.flush_parameter();
         }
@@ -69,14 +69,14 @@ impl HttpHandler for UrlEncodedHandler {
         self.name_buf.extend_from_slice(name);
 
         self.state = State::UrlEncodedName;
-        true
+        Action::Continue
     }
 
-    fn on_url_encoded_value(&mut self, value: &[u8]) -> bool {
+    fn on_url_encoded_value(&mut self, value: &[u8]) -> Action {
         self.value_buf.extend_from_slice(value);
 
         self.state = State::UrlEncodedValue;
-        true
+        Action::Continue
     }
 }
 